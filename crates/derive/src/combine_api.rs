@@ -98,6 +98,7 @@ impl cgo::Service for API {
                     key_ptr,
                     field_ptrs,
                     uuid_placeholder_ptr,
+                    assert_formats,
                 } = combine_api::Config::decode(data)?;
                 tracing::debug!(
                     ?schema_index_memptr,
@@ -105,6 +106,7 @@ impl cgo::Service for API {
                     ?key_ptr,
                     ?field_ptrs,
                     ?uuid_placeholder_ptr,
+                    ?assert_formats,
                     "configure",
                 );
 
@@ -121,9 +123,12 @@ impl cgo::Service for API {
                     return Err(Error::EmptyKey);
                 }
 
+                let mut validator = Validator::new(schema_index);
+                validator.set_assert_formats(assert_formats);
+
                 self.state = Some(State {
                     combiner: Combiner::new(schema, key_ptrs.into()),
-                    validator: Validator::new(schema_index),
+                    validator,
                     fields: field_ptrs.iter().map(Pointer::from).collect(),
                     uuid_placeholder_ptr,
                     stats: CombineStats::default(),
@@ -188,14 +193,23 @@ pub fn drain_combiner(
         uuid_placeholder_ptr,
         "drain_combiner",
     );
-    for (doc, fully_reduced) in combiner.drain_entries(uuid_placeholder_ptr) {
+    for combiner::DrainedEntry {
+        doc,
+        fully_reduced,
+        deleted,
+    } in combiner.drain_entries(uuid_placeholder_ptr)
+    {
         // Send serialized document.
         let begin = arena.len();
         let w: &mut Vec<u8> = &mut *arena;
         serde_json::to_writer(w, &doc).expect("encoding cannot fail");
         // Only here do we know the actual length of the document in its serialized form.
         stats.increment((arena.len() - begin) as u64);
-        if fully_reduced {
+        // Only fully reduced tombstones are drained as deletions. Partially combined
+        // tombstones are documents which later reductions continue to honor.
+        if fully_reduced && deleted {
+            cgo::send_bytes(Code::DrainedDeletedDocument as u32, begin, arena, out);
+        } else if fully_reduced {
             cgo::send_bytes(Code::DrainedReducedDocument as u32, begin, arena, out);
         } else {
             cgo::send_bytes(Code::DrainedCombinedDocument as u32, begin, arena, out);
@@ -234,7 +248,10 @@ pub fn drain_combiner(
 
 #[cfg(test)]
 pub mod test {
-    use super::{super::test::build_min_max_sum_schema, Code, Error, API};
+    use super::{
+        super::test::{build_min_max_sum_schema, build_schema},
+        Code, Error, API,
+    };
     use prost::Message;
     use protocol::{
         cgo::Service,
@@ -264,6 +281,7 @@ pub mod test {
                 key_ptr: vec!["/key".to_owned()],
                 field_ptrs: vec!["/min".to_owned(), "/max".to_owned()],
                 uuid_placeholder_ptr: "/foo".to_owned(),
+                assert_formats: false,
             },
             &mut arena,
             &mut out,
@@ -322,6 +340,75 @@ pub mod test {
         ));
     }
 
+    #[test]
+    fn test_combine_drains_deletions() {
+        let (index, schema_url) = build_schema(json!({
+            "properties": {"key": {"type": "string"}},
+            "required": ["key", "value"],
+            "reduce": {"strategy": "merge", "deletions": true},
+        }));
+
+        let mut svc = API::create();
+        let mut arena = Vec::new();
+        let mut out = Vec::new();
+
+        svc.invoke_message(
+            Code::Configure as u32,
+            combine_api::Config {
+                schema_index_memptr: index as *const doc::SchemaIndex<'static> as u64,
+                schema_uri: schema_url.as_str().to_owned(),
+                key_ptr: vec!["/key".to_owned()],
+                field_ptrs: vec![],
+                uuid_placeholder_ptr: String::new(),
+                assert_formats: false,
+            },
+            &mut arena,
+            &mut out,
+        )
+        .unwrap();
+
+        for (code, doc) in &[
+            (
+                Code::CombineRight,
+                json!({"key": "one", "_meta": {"op": "d"}}),
+            ),
+            (Code::ReduceLeft, json!({"key": "one", "value": 1})),
+            (Code::ReduceLeft, json!({"key": "two", "value": 2})),
+            (
+                Code::CombineRight,
+                json!({"key": "three", "_meta": {"op": "d"}}),
+            ),
+        ] {
+            svc.invoke(
+                *code as u32,
+                serde_json::to_vec(doc).unwrap().as_ref(),
+                &mut arena,
+                &mut out,
+            )
+            .unwrap();
+        }
+        svc.invoke(Code::Drain as u32, &[], &mut arena, &mut out)
+            .unwrap();
+
+        // Expect a document, key, and fields of each key, followed by stats.
+        let codes = out.iter().map(|o| o.code).collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                Code::DrainedDeletedDocument as u32,
+                Code::DrainedKey as u32,
+                Code::DrainedFields as u32,
+                Code::DrainedCombinedDocument as u32,
+                Code::DrainedKey as u32,
+                Code::DrainedFields as u32,
+                Code::DrainedReducedDocument as u32,
+                Code::DrainedKey as u32,
+                Code::DrainedFields as u32,
+                Code::Stats as u32,
+            ]
+        );
+    }
+
     #[test]
     fn test_combine_empty_key() {
         let (index, schema_url) = build_min_max_sum_schema();
@@ -339,6 +426,7 @@ pub mod test {
                     key_ptr: vec![],
                     field_ptrs: vec![],
                     uuid_placeholder_ptr: String::new(),
+                    assert_formats: false,
                 },
                 &mut arena,
                 &mut out,
@@ -27,6 +27,22 @@ struct KeyedDoc {
     key: Rc<[Pointer]>,
     doc: Value,
     fully_reduced: bool,
+    // Is |doc| a deletion tombstone, or (if not fully reduced) a document
+    // which replaces those which preceded a tombstone?
+    deleted: bool,
+}
+
+/// DrainedEntry is a document drained from the Combiner.
+#[derive(Debug, PartialEq)]
+pub struct DrainedEntry {
+    pub doc: Value,
+    /// Is this document fully reduced (it was reduced with a left-hand document)?
+    pub fully_reduced: bool,
+    /// Were prior documents of this key deleted by a tombstone?
+    /// If so, the document is either that tombstone or (if not fully reduced)
+    /// one which replaces, rather than reduces into, documents which preceded
+    /// the tombstone. A fully reduced document is deleted only if it's a tombstone.
+    pub deleted: bool,
 }
 
 // KeyedDoc is ordered on its document's extracted key.
@@ -79,43 +95,36 @@ impl Combiner {
             key: self.key.clone(),
             doc: lhs,
             fully_reduced: false,
+            deleted: false,
         };
 
-        let rhs = match self.entries.take(&lookup) {
+        let (lhs, rhs) = match self.entries.take(&lookup) {
             Some(entry) if entry.fully_reduced => {
                 return Err(Error::AlreadyFullyReduced(lookup.doc))
             }
-            Some(entry) => Some(entry.doc),
-            None => None,
+            // The right-hand document replaces, rather than reduces into, the left-hand one.
+            Some(entry) if entry.deleted => (None, entry.doc),
+            Some(entry) => (Some(lookup.doc), entry.doc),
+            None => (None, lookup.doc),
         };
 
-        let lhs = Validation::validate(validator, &self.schema, lookup.doc)?
-            .ok()
-            .map_err(Error::PreReduceValidation)?;
-
-        let reduced = if let Some(rhs) = rhs {
-            let lhs = Some(lhs.0.document);
-
-            // Validate RHS (again) to gather annotations. Note that it must have already
-            // validated in order to have been in the Combiner.
-            let rhs = Validation::validate(validator, &self.schema, rhs)
-                .unwrap()
-                .ok()
-                .unwrap();
-
-            reduce::reduce(lhs, rhs, true)?
-        } else {
-            reduce::reduce(None, lhs, true)?
+        let lhs = match lhs {
+            Some(lhs) if !self.is_tombstone(&lhs, validator) => Some(
+                Validation::validate(validator, &self.schema, lhs)?
+                    .ok()
+                    .map_err(Error::PreReduceValidation)?
+                    .0
+                    .document,
+            ),
+            lhs => lhs,
         };
-
-        let reduced = Validation::validate(validator, &self.schema, reduced)?
-            .ok()
-            .map_err(Error::PostReduceValidation)?;
+        let (doc, deleted) = self.reduce(lhs, rhs, true, validator)?;
 
         self.entries.insert(KeyedDoc {
             key: lookup.key,
-            doc: reduced.0.document,
+            doc,
             fully_reduced: true,
+            deleted,
         });
 
         Ok(())
@@ -127,39 +136,70 @@ impl Combiner {
             key: self.key.clone(),
             doc: rhs,
             fully_reduced: false,
+            deleted: false,
         };
 
-        let (lhs, fully_reduced) = match self.entries.take(&lookup) {
-            Some(entry) => (Some(entry.doc), entry.fully_reduced),
-            None => (None, false),
+        let (lhs, fully_reduced, deleted) = match self.entries.take(&lookup) {
+            Some(entry) => (Some(entry.doc), entry.fully_reduced, entry.deleted),
+            None => (None, false, false),
         };
+        let (doc, tombstone) = self.reduce(lhs, lookup.doc, fully_reduced, validator)?;
+
+        self.entries.insert(KeyedDoc {
+            key: lookup.key,
+            doc,
+            fully_reduced,
+            // A document which follows a tombstone must continue to replace the documents
+            // which preceded it, unless there can be no such documents.
+            deleted: tombstone || (deleted && !fully_reduced),
+        });
+
+        Ok(())
+    }
+
+    // Reduce |rhs| into |lhs|, returning the reduced document and whether it's a tombstone.
+    // Tombstones aren't validated, as the schema needn't permit documents having only
+    // a key and `_meta`. A tombstone discards |lhs|, and is itself the reduced document.
+    fn reduce(
+        &self,
+        lhs: Option<Value>,
+        rhs: Value,
+        prune: bool,
+        validator: &mut Validator,
+    ) -> Result<(Value, bool), Error> {
+        if self.is_tombstone(&rhs, validator) {
+            return Ok((rhs, true));
+        }
 
-        let rhs = Validation::validate(validator, &self.schema, lookup.doc)?
+        let rhs = Validation::validate(validator, &self.schema, rhs)?
             .ok()
             .map_err(Error::PreReduceValidation)?;
 
-        let reduced = reduce::reduce(lhs, rhs, fully_reduced)?;
+        let reduced = reduce::reduce(lhs, rhs, prune)?;
 
         let reduced = Validation::validate(validator, &self.schema, reduced)?
             .ok()
             .map_err(Error::PostReduceValidation)?;
 
-        self.entries.insert(KeyedDoc {
-            key: lookup.key,
-            doc: reduced.0.document,
-            fully_reduced,
-        });
+        Ok((reduced.0.document, false))
+    }
 
-        Ok(())
+    fn is_tombstone(&self, doc: &Value, validator: &Validator) -> bool {
+        reduce::is_tombstone(doc)
+            && reduce::deletions_enabled_by(validator.schema_index(), &self.schema)
     }
 
     // Drain all entries of the Combiner. If the UUID placeholder JSON pointer is non-empty,
     // then UUID_PLACEHOLDER is inserted into returned documents at the specified location.
     // If the document shape is incompatible with the pointer, it's returned unmodified.
+    //
+    // A partially combined document which replaces the documents preceding a tombstone
+    // is drained after a tombstone of its key, so that later reductions of the
+    // drained documents continue to discard those preceding documents.
     pub fn drain_entries(
         &mut self,
         uuid_placeholder_ptr: &str,
-    ) -> impl Iterator<Item = (Value, bool)> {
+    ) -> impl Iterator<Item = DrainedEntry> {
         let uuid_placeholder = match uuid_placeholder_ptr {
             "" => None,
             s => Some(Pointer::from(s)),
@@ -167,13 +207,36 @@ impl Combiner {
 
         std::mem::take(&mut self.entries)
             .into_iter()
-            .map(move |mut kd| {
-                if let Some(uuid_ptr) = &uuid_placeholder {
-                    if let Some(uuid_value) = uuid_ptr.create(&mut kd.doc) {
-                        *uuid_value = Value::String(UUID_PLACEHOLDER.to_owned());
-                    }
-                }
-                (kd.doc, kd.fully_reduced)
+            .flat_map(move |kd| {
+                let KeyedDoc {
+                    key,
+                    doc,
+                    fully_reduced,
+                    deleted,
+                } = kd;
+
+                let tombstone = if deleted && !fully_reduced && !reduce::is_tombstone(&doc) {
+                    Some(tombstone_of(&key, &doc))
+                } else {
+                    None
+                };
+
+                tombstone
+                    .into_iter()
+                    .chain(std::iter::once(doc))
+                    .map(|mut doc| {
+                        if let Some(uuid_ptr) = &uuid_placeholder {
+                            if let Some(uuid_value) = uuid_ptr.create(&mut doc) {
+                                *uuid_value = Value::String(UUID_PLACEHOLDER.to_owned());
+                            }
+                        }
+                        DrainedEntry {
+                            doc,
+                            fully_reduced,
+                            deleted,
+                        }
+                    })
+                    .collect::<Vec<_>>()
             })
     }
 
@@ -181,7 +244,7 @@ impl Combiner {
     pub fn into_entries(
         mut self,
         uuid_placeholder_ptr: &str,
-    ) -> impl Iterator<Item = (Value, bool)> {
+    ) -> impl Iterator<Item = DrainedEntry> {
         self.drain_entries(uuid_placeholder_ptr)
     }
 
@@ -190,6 +253,21 @@ impl Combiner {
     }
 }
 
+// Build a deletion tombstone having the key of |doc|.
+fn tombstone_of(key: &[Pointer], doc: &Value) -> Value {
+    let mut tombstone = Value::Object(Default::default());
+
+    for ptr in key.iter() {
+        if let (Some(value), Some(slot)) = (ptr.query(doc), ptr.create(&mut tombstone)) {
+            *slot = value.clone();
+        }
+    }
+    if let Some(op) = Pointer::from(reduce::OP_POINTER).create(&mut tombstone) {
+        *op = Value::String(reduce::OP_DELETE.to_owned());
+    }
+    tombstone
+}
+
 impl std::fmt::Debug for Combiner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Combiner")
@@ -209,7 +287,10 @@ impl std::fmt::Debug for Combiner {
 
 #[cfg(test)]
 mod test {
-    use super::{super::test::build_min_max_sum_schema, *};
+    use super::{
+        super::test::{build_min_max_sum_schema, build_schema},
+        *,
+    };
     use serde_json::json;
 
     #[test]
@@ -258,20 +339,129 @@ mod test {
         assert_eq!(
             combiner.into_entries("/foo").collect::<Vec<_>>(),
             vec![
-                (
-                    json!({"foo": UUID_PLACEHOLDER, "key": ["key", "one"], "min": 3, "max": 5.5, "lww": 1}),
-                    true
-                ),
-                (
-                    json!({"foo": UUID_PLACEHOLDER, "key": ["key", "three"], "min": 6, "max": 6.6, "lww": 5}),
-                    false
-                ),
-                (
-                    json!({"foo": UUID_PLACEHOLDER, "key": ["key", "two"], "min": 2, "max": 4.4, "lww": 3}),
-                    true
-                ),
+                DrainedEntry {
+                    doc: json!({"foo": UUID_PLACEHOLDER, "key": ["key", "one"], "min": 3, "max": 5.5, "lww": 1}),
+                    fully_reduced: true,
+                    deleted: false,
+                },
+                DrainedEntry {
+                    doc: json!({"foo": UUID_PLACEHOLDER, "key": ["key", "three"], "min": 6, "max": 6.6, "lww": 5}),
+                    fully_reduced: false,
+                    deleted: false,
+                },
+                DrainedEntry {
+                    doc: json!({"foo": UUID_PLACEHOLDER, "key": ["key", "two"], "min": 2, "max": 4.4, "lww": 3}),
+                    fully_reduced: true,
+                    deleted: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tombstones() {
+        // Tombstones aren't valid documents of the schema, and aren't validated.
+        let (schema_index, schema) = build_schema(json!({
+            "properties": {
+                "key": {"type": "string"},
+                "sum": {"reduce": {"strategy": "sum"}},
+            },
+            "required": ["key", "sum"],
+            "additionalProperties": false,
+            "reduce": {"strategy": "merge", "deletions": true},
+        }));
+        let key: Vec<Pointer> = vec!["/key".into()];
+        let key: Rc<[Pointer]> = key.into();
+
+        let mut validator = Validator::new(schema_index);
+        let mut combiner = Combiner::new(schema.clone(), key.clone());
+
+        // Key "a" is deleted after a partial combine, and its stored document is discarded.
+        combiner
+            .combine_right(json!({"key": "a", "sum": 1}), &mut validator)
+            .unwrap();
+        combiner
+            .combine_right(json!({"key": "a", "_meta": {"op": "d"}}), &mut validator)
+            .unwrap();
+        combiner
+            .reduce_left(json!({"key": "a", "sum": 10}), &mut validator)
+            .unwrap();
+
+        // Key "b" is deleted and then re-created, so its stored document is also discarded.
+        combiner
+            .combine_right(json!({"key": "b", "_meta": {"op": "d"}}), &mut validator)
+            .unwrap();
+        combiner
+            .combine_right(json!({"key": "b", "sum": 2}), &mut validator)
+            .unwrap();
+        combiner
+            .combine_right(json!({"key": "b", "sum": 3}), &mut validator)
+            .unwrap();
+        combiner
+            .reduce_left(json!({"key": "b", "sum": 10}), &mut validator)
+            .unwrap();
+
+        // Key "c" is re-created after a deletion, but isn't reduced with a stored document.
+        combiner
+            .combine_right(json!({"key": "c", "_meta": {"op": "d"}}), &mut validator)
+            .unwrap();
+        combiner
+            .combine_right(json!({"key": "c", "sum": 4}), &mut validator)
+            .unwrap();
+
+        // Key "d" is deleted, and isn't reduced with a stored document.
+        combiner
+            .combine_right(json!({"key": "d", "sum": 5}), &mut validator)
+            .unwrap();
+        combiner
+            .combine_right(json!({"key": "d", "_meta": {"op": "d"}}), &mut validator)
+            .unwrap();
+
+        // Key "c" is drained as a tombstone followed by its replacement.
+        assert_eq!(
+            combiner.into_entries("/uuid").collect::<Vec<_>>(),
+            vec![
+                DrainedEntry {
+                    doc: json!({"key": "a", "uuid": UUID_PLACEHOLDER, "_meta": {"op": "d"}}),
+                    fully_reduced: true,
+                    deleted: true,
+                },
+                DrainedEntry {
+                    doc: json!({"key": "b", "uuid": UUID_PLACEHOLDER, "sum": 5}),
+                    fully_reduced: true,
+                    deleted: false,
+                },
+                DrainedEntry {
+                    doc: json!({"key": "c", "uuid": UUID_PLACEHOLDER, "_meta": {"op": "d"}}),
+                    fully_reduced: false,
+                    deleted: true,
+                },
+                DrainedEntry {
+                    doc: json!({"key": "c", "uuid": UUID_PLACEHOLDER, "sum": 4}),
+                    fully_reduced: false,
+                    deleted: true,
+                },
+                DrainedEntry {
+                    doc: json!({"key": "d", "uuid": UUID_PLACEHOLDER, "_meta": {"op": "d"}}),
+                    fully_reduced: false,
+                    deleted: true,
+                },
             ]
         );
+
+        // Without the `deletions` option, tombstones are validated like any other document.
+        let (schema_index, schema) = build_schema(json!({
+            "properties": {"sum": {"reduce": {"strategy": "sum"}}},
+            "required": ["sum"],
+            "reduce": {"strategy": "merge"},
+        }));
+        let mut validator = Validator::new(schema_index);
+        let mut combiner = Combiner::new(schema, key);
+
+        assert!(matches!(
+            combiner.combine_right(json!({"key": "a", "_meta": {"op": "d"}}), &mut validator),
+            Err(Error::PreReduceValidation(_))
+        ));
     }
 
     #[test]
@@ -129,7 +129,11 @@ pub mod test {
                 }
             }
         });
+        build_schema(schema)
+    }
 
+    // Build a test fixture of the given schema.
+    pub fn build_schema(schema: serde_json::Value) -> (&'static doc::SchemaIndex<'static>, Url) {
         let uri = Url::parse("https://example/schema").unwrap();
         let scm: doc::Schema = json::schema::build::build_schema(uri.clone(), &schema).unwrap();
         let scm = Box::leak(Box::new(scm));
@@ -83,4 +83,5 @@ impl<'sm, 'v> super::Valid<'sm, 'v> {
     }
 }
 
-static DEFAULT_STRATEGY: &reduce::Strategy = &reduce::Strategy::LastWriteWins;
+static DEFAULT_STRATEGY: &reduce::Strategy =
+    &reduce::Strategy::LastWriteWins(reduce::LastWriteWins { key: Vec::new() });
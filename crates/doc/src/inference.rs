@@ -86,13 +86,18 @@ pub enum Reduction {
     Unset,
 
     Append,
+    DdSketch,
     FirstWriteWins,
+    HyperLogLog,
     LastWriteWins,
     Maximize,
     Merge,
     Minimize,
     Set,
+    Stats,
     Sum,
+    SumExact,
+    TopN,
 
     // Multiple concrete strategies may apply at the location.
     Multiple,
@@ -159,13 +164,18 @@ impl From<&reduce::Strategy> for Reduction {
 
         match s {
             Strategy::Append => Reduction::Append,
+            Strategy::DdSketch(_) => Reduction::DdSketch,
             Strategy::FirstWriteWins => Reduction::FirstWriteWins,
-            Strategy::LastWriteWins => Reduction::LastWriteWins,
+            Strategy::HyperLogLog(_) => Reduction::HyperLogLog,
+            Strategy::LastWriteWins(_) => Reduction::LastWriteWins,
             Strategy::Maximize(_) => Reduction::Maximize,
             Strategy::Minimize(_) => Reduction::Minimize,
             Strategy::Set(_) => Reduction::Set,
-            Strategy::Sum => Reduction::Sum,
+            Strategy::Stats(_) => Reduction::Stats,
+            Strategy::Sum(sum) if sum.mode == reduce::SumMode::Exact => Reduction::SumExact,
+            Strategy::Sum(_) => Reduction::Sum,
             Strategy::Merge(_) => Reduction::Merge,
+            Strategy::TopN(_) => Reduction::TopN,
         }
     }
 }
@@ -572,6 +582,7 @@ impl Shape {
             matches!(
                 kw,
                 Keyword::Application(Application::Ref(_), _)
+                | Keyword::Application(Application::DynamicRef(_), _)
                 | Keyword::Application(Application::Def{ .. }, _)
                 | Keyword::Application(Application::Definition{ .. }, _)
                 | Keyword::Annotation(Annotation::Core(CoreAnnotation::Default(_)))
@@ -663,7 +674,8 @@ impl Shape {
                     shape.array.additional =
                         Some(Box::new(Shape::infer_inner(schema, index, visited)));
                 }
-                Keyword::Application(Application::Items { index: Some(i) }, schema) => {
+                Keyword::Application(Application::Items { index: Some(i) }, schema)
+                | Keyword::Application(Application::PrefixItems { index: i }, schema) => {
                     shape.array.tuple.extend(
                         std::iter::repeat(Shape::default()).take(1 + i - shape.array.tuple.len()),
                     );
@@ -754,7 +766,11 @@ impl Shape {
 
         for kw in &schema.kw {
             match kw {
-                Keyword::Application(Application::Ref(uri), _) => {
+                // A $dynamicRef is resolved statically, as its initial target.
+                // This is the same as a $ref unless the schema is extended
+                // through a $dynamicAnchor of another resource.
+                Keyword::Application(Application::Ref(uri), _)
+                | Keyword::Application(Application::DynamicRef(uri), _) => {
                     let mut referent = if visited.iter().any(|u| u.as_str() == uri.as_str()) {
                         Shape::default() // Don't re-visit this location.
                     } else if let Some(schema) = index.fetch(uri) {
@@ -1236,12 +1252,26 @@ pub enum Error {
     ChildWithoutParentReduction(String),
     #[error("{0} has 'sum' reduction strategy, restricted to numbers, but has types {1:?}")]
     SumNotNumber(String, types::Set),
+    #[error(
+        "{0} has exact 'sum' reduction strategy, restricted to numbers & strings, but has types {1:?}"
+    )]
+    SumExactNotNumberOrString(String, types::Set),
     #[error(
         "{0} has 'merge' reduction strategy, restricted to objects & arrays, but has types {1:?}"
     )]
     MergeNotObjectOrArray(String, types::Set),
     #[error("{0} has 'set' reduction strategy, restricted to objects, but has types {1:?}")]
     SetNotObject(String, types::Set),
+    #[error("{0} has 'topN' reduction strategy, restricted to arrays, but has types {1:?}")]
+    TopNNotArray(String, types::Set),
+    #[error(
+        "{0} has 'hyperLogLog' reduction strategy, restricted to objects, but has types {1:?}"
+    )]
+    HyperLogLogNotObject(String, types::Set),
+    #[error("{0} has 'ddSketch' reduction strategy, restricted to objects, but has types {1:?}")]
+    DdSketchNotObject(String, types::Set),
+    #[error("{0} has 'stats' reduction strategy, restricted to objects, but has types {1:?}")]
+    StatsNotObject(String, types::Set),
     #[error(
         "{0} location's parent has 'set' reduction strategy, restricted to 'add'/'remove'/'intersect' properties"
     )]
@@ -1307,6 +1337,14 @@ impl Shape {
                 self.type_,
             ));
         }
+        if matches!(self.reduction, Reduction::SumExact)
+            && self.type_ - (types::INT_OR_FRAC | types::STRING) != types::INVALID
+        {
+            out.push(Error::SumExactNotNumberOrString(
+                loc.pointer_str().to_string(),
+                self.type_,
+            ));
+        }
         if matches!(self.reduction, Reduction::Merge)
             && self.type_ - (types::OBJECT | types::ARRAY) != types::INVALID
         {
@@ -1315,6 +1353,31 @@ impl Shape {
                 self.type_,
             ));
         }
+        if matches!(self.reduction, Reduction::TopN) && self.type_ - types::ARRAY != types::INVALID
+        {
+            out.push(Error::TopNNotArray(
+                loc.pointer_str().to_string(),
+                self.type_,
+            ));
+        }
+        if matches!(self.reduction, Reduction::DdSketch) && self.type_ != types::OBJECT {
+            out.push(Error::DdSketchNotObject(
+                loc.pointer_str().to_string(),
+                self.type_,
+            ));
+        }
+        if matches!(self.reduction, Reduction::Stats) && self.type_ != types::OBJECT {
+            out.push(Error::StatsNotObject(
+                loc.pointer_str().to_string(),
+                self.type_,
+            ));
+        }
+        if matches!(self.reduction, Reduction::HyperLogLog) && self.type_ != types::OBJECT {
+            out.push(Error::HyperLogLogNotObject(
+                loc.pointer_str().to_string(),
+                self.type_,
+            ));
+        }
         if matches!(self.reduction, Reduction::Set) {
             if self.type_ != types::OBJECT {
                 out.push(Error::SetNotObject(
@@ -2220,6 +2283,12 @@ mod test {
             sum-wrong-type:
                 reduce: {strategy: sum}
                 type: [number, string]
+            sum-exact:
+                reduce: {strategy: sum, mode: exact}
+                type: [number, string]
+            sum-exact-wrong-type:
+                reduce: {strategy: sum, mode: exact}
+                type: [boolean, string]
 
             must-exist-but-cannot: false
             may-not-exist: false
@@ -2249,6 +2318,18 @@ mod test {
             merge-wrong-type:
                 reduce: {strategy: merge}
                 type: boolean
+            top-n-wrong-type:
+                reduce: {strategy: topN, limit: 3}
+                type: object
+            dd-sketch-wrong-type:
+                reduce: {strategy: ddSketch}
+                type: number
+            stats-wrong-type:
+                reduce: {strategy: stats}
+                type: array
+            hll-wrong-type:
+                reduce: {strategy: hyperLogLog}
+                type: [object, string]
 
         required: [must-exist-but-cannot, nested-obj-or-string, nested-array, nested-array-or-string]
 
@@ -2286,11 +2367,22 @@ mod test {
                 Error::DigitInvalidProperty("/123".to_owned()),
                 Error::ImpossibleMustExist("/must-exist-but-cannot".to_owned()),
                 Error::ImpossibleMustExist("/nested-array/1".to_owned()),
+                Error::SumExactNotNumberOrString(
+                    "/sum-exact-wrong-type".to_owned(),
+                    types::BOOLEAN | types::STRING
+                ),
                 Error::SumNotNumber(
                     "/sum-wrong-type".to_owned(),
                     types::INT_OR_FRAC | types::STRING
                 ),
+                Error::DdSketchNotObject("/dd-sketch-wrong-type".to_owned(), types::INT_OR_FRAC),
+                Error::HyperLogLogNotObject(
+                    "/hll-wrong-type".to_owned(),
+                    types::OBJECT | types::STRING
+                ),
                 Error::MergeNotObjectOrArray("/merge-wrong-type".to_owned(), types::BOOLEAN),
+                Error::StatsNotObject("/stats-wrong-type".to_owned(), types::ARRAY),
+                Error::TopNNotArray("/top-n-wrong-type".to_owned(), types::OBJECT),
                 Error::ChildWithoutParentReduction("/*/nested-sum".to_owned()),
             ]
         );
@@ -2481,6 +2573,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_dynamic_ref() {
+        let shape = shape_from(
+            r#"
+                $schema: https://json-schema.org/draft/2020-12/schema
+                $defs:
+                    elem:
+                        $dynamicAnchor: elem
+                        type: string
+                        maxLength: 10
+                properties:
+                    items:
+                        type: array
+                        items: { $dynamicRef: '#elem' }
+                "#,
+        );
+
+        // A $dynamicRef resolves to its static target, as does a $ref.
+        let (elem, _) = shape.locate(&"/items/0".into()).unwrap();
+        assert_eq!(
+            elem,
+            &Shape {
+                type_: types::STRING,
+                provenance: Provenance::Reference(
+                    Url::parse("http://example/schema#elem").unwrap()
+                ),
+                string: StringShape {
+                    max_length: Some(10),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        );
+    }
+
     fn shape_from(case: &str) -> Shape {
         let url = url::Url::parse("http://example/schema").unwrap();
         let schema: Value = serde_yaml::from_str(case).unwrap();
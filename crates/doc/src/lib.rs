@@ -70,6 +70,7 @@ impl<'sm, 'v> Validation<'sm, 'v> {
         // Repeat the validation, but this time with FullContext for better error generation.
         let mut full_validator =
             json::validator::Validator::<Annotation, FullContext>::new(validator.schema_index());
+        full_validator.set_assert_formats(validator.assert_formats());
         full_validator.prepare(schema).unwrap();
         let full_span = json::de::walk(&document, &mut full_validator).unwrap();
 
@@ -77,26 +78,197 @@ impl<'sm, 'v> Validation<'sm, 'v> {
         assert!(full_validator.invalid());
         assert_eq!(span, full_span);
 
-        Err(FailedValidation {
-            document,
-            basic_output: json::validator::build_basic_output(full_validator.outcomes()),
-        })
+        let errors = full_validator
+            .outcomes()
+            .iter()
+            .filter(|(outcome, _)| outcome.is_error())
+            .map(|(outcome, ctx)| ValidationError::new(&document, outcome, ctx))
+            .collect();
+
+        Err(FailedValidation { document, errors })
     }
 }
 
+/// FailedValidation is a document which failed validation, and its validation errors.
 #[derive(Debug, serde::Serialize)]
 pub struct FailedValidation {
     pub document: serde_json::Value,
-    pub basic_output: serde_json::Value,
+    pub errors: Vec<ValidationError>,
+}
+
+/// ValidationError is a single failed constraint of a FailedValidation.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationError {
+    /// JSON pointer of the invalid location within the document.
+    pub instance_location: String,
+    /// Location of the failed keyword, relative to the validated schema.
+    pub keyword_location: String,
+    /// Canonical URI of the schema having the failed keyword.
+    pub absolute_keyword_location: String,
+    /// Description of the constraint which the document failed to satisfy.
+    pub expected: String,
+    /// Invalid value of the document, as JSON which is truncated if long.
+    pub actual: String,
+}
+
+impl ValidationError {
+    /// Maximum length of a rendered `actual` value, beyond which it's truncated.
+    pub const MAX_ACTUAL_LEN: usize = 64;
+
+    fn new(
+        document: &serde_json::Value,
+        outcome: &json::validator::Outcome<Annotation>,
+        ctx: &FullContext,
+    ) -> Self {
+        use itertools::Itertools;
+        use json::{schema::Validation as V, validator::Outcome};
+
+        let instance = Pointer::from_str(&ctx.instance_ptr).query(document);
+
+        let mut actual = match instance {
+            Some(value) => value.to_string(),
+            None => "<missing>".to_string(),
+        };
+        if let Some((ind, _)) = actual.char_indices().nth(Self::MAX_ACTUAL_LEN) {
+            actual.truncate(ind);
+            actual.push_str("...");
+        }
+
+        // Required properties are reported only if they're actually missing.
+        let missing = |props: &[String]| -> Vec<String> {
+            props
+                .iter()
+                .filter(|p| instance.and_then(|v| v.get(p.as_str())).is_none())
+                .cloned()
+                .collect()
+        };
+        let expected = match outcome {
+            Outcome::Invalid(V::Required { props, .. }) => format!(
+                "must have required properties {:?}",
+                missing(props).iter().format(", ")
+            ),
+            Outcome::Invalid(V::DependentRequired { if_, then_, .. }) => format!(
+                "must have properties {:?} when property {:?} is present",
+                missing(then_).iter().format(", "),
+                if_
+            ),
+            _ => outcome.to_string(),
+        };
+
+        let keyword_location = match outcome.keyword() {
+            Some(keyword) => format!("{}/{}", ctx.keyword_location, keyword),
+            None => ctx.keyword_location.clone(),
+        };
+
+        Self {
+            instance_location: ctx.instance_ptr.clone(),
+            keyword_location,
+            absolute_keyword_location: ctx.canonical_uri.clone(),
+            expected,
+            actual,
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = if self.instance_location.is_empty() {
+            "document root"
+        } else {
+            &self.instance_location
+        };
+        write!(
+            f,
+            "{}: {}, but found {}\n    schema: {} ({})",
+            location,
+            self.expected,
+            self.actual,
+            self.keyword_location,
+            self.absolute_keyword_location,
+        )
+    }
 }
 
 impl std::fmt::Display for FailedValidation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        serde_json::json!({
-            "document": &self.document,
-            "basic_output": &self.basic_output,
-        })
-        .fmt(f)
+        write!(f, "{} validation error(s):", self.errors.len())?;
+        for err in &self.errors {
+            write!(f, "\n  {}", err)?;
+        }
+        Ok(())
     }
 }
 impl std::error::Error for FailedValidation {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_failed_validation_report() {
+        let curi = url::Url::parse("http://example/schema").unwrap();
+        let schema = json!({
+            "properties": {
+                "int": {"type": "integer"},
+                "str": {"type": "string", "maxLength": 3},
+                "date": {"type": "string", "format": "date"},
+                "nested": {"$ref": "#/$defs/nested"},
+            },
+            "required": ["int", "str", "missing"],
+            "dependentRequired": {"int": ["str", "other"]},
+            "$defs": {
+                "nested": {
+                    "items": {"minimum": 10}
+                },
+            },
+        });
+        let schema: Schema = json::schema::build::build_schema(curi.clone(), &schema).unwrap();
+
+        let mut index = SchemaIndexBuilder::new();
+        index.add(&schema).unwrap();
+        index.verify_references().unwrap();
+        let index = index.into_index();
+
+        let mut validator = Validator::new(&index);
+        validator.set_assert_formats(true);
+
+        let document = json!({
+            "int": "not an int",
+            "str": "a long string which is truncated when it's reported as the actual value",
+            "date": "2021-02-30",
+            "nested": [12, 5],
+        });
+        let err = Validation::validate(&mut validator, &curi, document)
+            .unwrap()
+            .ok()
+            .map(|_| ())
+            .unwrap_err();
+
+        insta::assert_snapshot!(err.to_string(), @r###"
+        6 validation error(s):
+          /date: must be a valid "date", but found "2021-02-30"
+            schema: #/properties/date/format (http://example/schema#/properties/date)
+          /int: must be of type "integer", but found "not an int"
+            schema: #/properties/int/type (http://example/schema#/properties/int)
+          /nested/1: must be at least 10, but found 5
+            schema: #/properties/nested/$ref/items/minimum (http://example/schema#/$defs/nested/items)
+          /str: must be at most 3 characters long, but found "a long string which is truncated when it's reported as the actu...
+            schema: #/properties/str/maxLength (http://example/schema#/properties/str)
+          document root: must have properties "other" when property "int" is present, but found {"date":"2021-02-30","int":"not an int","nested":[12,5],"str":"a...
+            schema: #/dependentRequired (http://example/schema)
+          document root: must have required properties "missing", but found {"date":"2021-02-30","int":"not an int","nested":[12,5],"str":"a...
+            schema: #/required (http://example/schema)
+        "###);
+        insta::assert_json_snapshot!(err.errors[0], @r###"
+        {
+          "instanceLocation": "/date",
+          "keywordLocation": "#/properties/date/format",
+          "absoluteKeywordLocation": "http://example/schema#/properties/date",
+          "expected": "must be a valid \"date\"",
+          "actual": "\"2021-02-30\""
+        }
+        "###);
+    }
+}
@@ -0,0 +1,415 @@
+use super::{reduce_accumulator, Cursor, Error, Reducer, Result};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use super::strategy::DdSketch;
+
+/// Sketch is a DDSketch of observed numbers, from which quantiles may be
+/// estimated to within a configured relative accuracy.
+///
+/// Each non-zero number is counted within a logarithmically-sized bin,
+/// having index `ceil(ln(|x|) / ln(gamma))` where
+/// `gamma = (1 + accuracy) / (1 - accuracy)`. Bins of positive and negative
+/// numbers are tracked separately, and zeros have their own count.
+///
+/// Sketches merge by summing the counts of each bin, and by taking the
+/// minimum and maximum of observed extremes. Merges are therefore exactly
+/// associative and commutative: any grouping of partial reductions produces
+/// the same sketch as a single, full reduction.
+///
+/// The total count of a Sketch always fits in a u64: adds and merges which
+/// would exceed it fail, leaving the Sketch unmodified.
+#[derive(Debug, Default, PartialEq)]
+pub struct Sketch {
+    positive: BTreeMap<i32, u64>,
+    negative: BTreeMap<i32, u64>,
+    zero: u64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Sketch {
+    /// Add |value| to the sketch. Returns None if the total count would overflow.
+    pub fn add(&mut self, gamma: f64, value: f64) -> Option<()> {
+        self.count().checked_add(1)?;
+
+        if value > 0.0 {
+            *self.positive.entry(index(gamma, value)).or_default() += 1;
+        } else if value < 0.0 {
+            *self.negative.entry(index(gamma, -value)).or_default() += 1;
+        } else {
+            self.zero += 1;
+        }
+        self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        self.max = Some(self.max.map_or(value, |m| m.max(value)));
+        Some(())
+    }
+
+    /// Merge |other| into the sketch. Returns None if the total count would overflow.
+    pub fn merge(&mut self, other: Sketch) -> Option<()> {
+        // Every bin is bounded by the total, so only the total must be checked.
+        self.count().checked_add(other.count())?;
+
+        for (index, count) in other.positive {
+            *self.positive.entry(index).or_default() += count;
+        }
+        for (index, count) in other.negative {
+            *self.negative.entry(index).or_default() += count;
+        }
+        self.zero += other.zero;
+
+        if let Some(value) = other.min {
+            self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        }
+        if let Some(value) = other.max {
+            self.max = Some(self.max.map_or(value, |m| m.max(value)));
+        }
+        Some(())
+    }
+
+    pub fn count(&self) -> u64 {
+        self.checked_count().expect("sketch count fits in u64")
+    }
+
+    fn checked_count(&self) -> Option<u64> {
+        self.positive
+            .values()
+            .chain(self.negative.values())
+            .try_fold(self.zero, |total, count| total.checked_add(*count))
+    }
+
+    /// Estimate the value at quantile |q|, in the range [0, 1].
+    /// Returns None if the sketch is empty.
+    pub fn quantile(&self, gamma: f64, q: f64) -> Option<f64> {
+        let (min, max) = (self.min?, self.max?);
+        let rank = (q * (self.count() - 1) as f64).round() as u64;
+
+        // Extremes are tracked exactly.
+        if rank == 0 {
+            return Some(min);
+        } else if rank == self.count() - 1 {
+            return Some(max);
+        }
+
+        // Walk bins in ascending order of the values they represent:
+        // negative bins of decreasing magnitude, then zeros, then positive
+        // bins of increasing magnitude.
+        let bins = self
+            .negative
+            .iter()
+            .rev()
+            .map(|(index, count)| (-value(gamma, *index), *count))
+            .chain(std::iter::once((0.0, self.zero)))
+            .chain(
+                self.positive
+                    .iter()
+                    .map(|(index, count)| (value(gamma, *index), *count)),
+            );
+
+        let mut seen = 0;
+        for (estimate, count) in bins {
+            seen += count;
+            if seen > rank {
+                return Some(estimate.max(min).min(max));
+            }
+        }
+        unreachable!("rank is less than the sketch count")
+    }
+
+    fn to_bins(bins: &BTreeMap<i32, u64>) -> Value {
+        Value::Array(
+            bins.iter()
+                .map(|(index, count)| Value::Array(vec![Value::from(*index), Value::from(*count)]))
+                .collect(),
+        )
+    }
+
+    fn from_bins(value: &Value) -> Option<BTreeMap<i32, u64>> {
+        let mut bins = BTreeMap::new();
+
+        for bin in value.as_array()? {
+            match bin.as_array().map(Vec::as_slice) {
+                Some([index, count]) => {
+                    let index = i32::try_from(index.as_i64()?).ok()?;
+                    let bin: &mut u64 = bins.entry(index).or_default();
+                    *bin = bin.checked_add(count.as_u64()?)?;
+                }
+                _ => return None,
+            }
+        }
+        Some(bins)
+    }
+}
+
+/// Index of the bin which holds positive |value|.
+fn index(gamma: f64, value: f64) -> i32 {
+    (value.ln() / gamma.ln()).ceil() as i32
+}
+
+/// Representative value of the bin having |index|, which is within the
+/// relative accuracy of every value which maps to the bin.
+fn value(gamma: f64, index: i32) -> f64 {
+    2.0 * gamma.powi(index) / (gamma + 1.0)
+}
+
+impl DdSketch {
+    fn gamma(&self) -> f64 {
+        (1.0 + self.relative_accuracy) / (1.0 - self.relative_accuracy)
+    }
+
+    /// Parse a sketch instance. Instances are objects which may have
+    /// "add" (an array of numbers), "positive" and "negative" (arrays of
+    /// [index, count] bins), "zeroCount", "min", and "max" properties.
+    /// Derived "count" and "quantiles" properties are ignored.
+    fn parse(&self, instance: Value) -> Result<Sketch> {
+        let (mut sketch, adds) = Self::parse_parts(instance).ok_or(Error::DdSketchWrongType)?;
+        // A sketch having counts must also have extremes, and vice versa.
+        let count = sketch.checked_count().ok_or(Error::DdSketchCountOverflow)?;
+        if (count == 0) != (sketch.min.is_none() || sketch.max.is_none()) {
+            return Err(Error::DdSketchWrongType);
+        }
+
+        let gamma = self.gamma();
+        for value in adds {
+            sketch
+                .add(gamma, value)
+                .ok_or(Error::DdSketchCountOverflow)?;
+        }
+        Ok(sketch)
+    }
+
+    /// Parse the sketch and the added numbers of an instance.
+    fn parse_parts(instance: Value) -> Option<(Sketch, Vec<f64>)> {
+        let mut sketch = Sketch::default();
+        let mut adds = Vec::new();
+
+        for (prop, value) in instance.as_object()? {
+            match (prop.as_ref(), value) {
+                ("add", Value::Array(items)) => {
+                    for item in items {
+                        adds.push(item.as_f64()?);
+                    }
+                }
+                ("positive", bins) => sketch.positive = Sketch::from_bins(bins)?,
+                ("negative", bins) => sketch.negative = Sketch::from_bins(bins)?,
+                ("zeroCount", count) => sketch.zero = count.as_u64()?,
+                ("min", value) => sketch.min = Some(value.as_f64()?),
+                ("max", value) => sketch.max = Some(value.as_f64()?),
+                ("count", Value::Number(_)) | ("quantiles", Value::Object(_)) => (),
+                _ => return None,
+            }
+        }
+        Some((sketch, adds))
+    }
+
+    fn to_value(&self, sketch: Sketch) -> Value {
+        let gamma = self.gamma();
+        let mut out = Map::new();
+
+        let mut quantiles = Map::new();
+        for q in &self.quantiles {
+            if let Some(v) = sketch.quantile(gamma, *q) {
+                quantiles.insert(q.to_string(), Value::from(v));
+            }
+        }
+
+        out.insert("count".to_string(), Value::from(sketch.count()));
+        if let (Some(min), Some(max)) = (sketch.min, sketch.max) {
+            out.insert("min".to_string(), Value::from(min));
+            out.insert("max".to_string(), Value::from(max));
+        }
+        out.insert("negative".to_string(), Sketch::to_bins(&sketch.negative));
+        out.insert("positive".to_string(), Sketch::to_bins(&sketch.positive));
+        out.insert("quantiles".to_string(), Value::Object(quantiles));
+        out.insert("zeroCount".to_string(), Value::from(sketch.zero));
+
+        Value::Object(out)
+    }
+}
+
+impl Reducer for DdSketch {
+    fn reduce(&self, cur: Cursor) -> Result<Value> {
+        let sketch = reduce_accumulator(cur, Sketch::default(), |sketch, instance| {
+            sketch
+                .merge(self.parse(instance)?)
+                .ok_or(Error::DdSketchCountOverflow)
+        })?;
+        Ok(self.to_value(sketch))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::*;
+    use super::*;
+
+    #[test]
+    fn test_quantiles_are_within_relative_accuracy() {
+        let gamma = (1.0 + 0.02) / (1.0 - 0.02);
+        let mut sketch = Sketch::default();
+
+        let mut values: Vec<f64> = (0..10_000)
+            .map(|i| ((i * 7919) % 10_000) as f64 / 10.0 - 200.0)
+            .collect();
+        for v in values.iter() {
+            sketch.add(gamma, *v).unwrap();
+        }
+        values.sort_by(|l, r| l.partial_cmp(r).unwrap());
+
+        for &q in &[0.0, 0.01, 0.25, 0.5, 0.9, 0.99, 1.0] {
+            let expect = values[(q * (values.len() - 1) as f64).round() as usize];
+            let actual = sketch.quantile(gamma, q).unwrap();
+
+            assert!(
+                (actual - expect).abs() <= 0.02 * expect.abs(),
+                "q {} expect {} actual {}",
+                q,
+                expect,
+                actual
+            );
+        }
+        assert_eq!(sketch.quantile(gamma, 0.0), Some(-200.0));
+        assert_eq!(sketch.quantile(gamma, 1.0), Some(799.9));
+        assert_eq!(Sketch::default().quantile(gamma, 0.5), None);
+    }
+
+    #[test]
+    fn test_merges_are_associative() {
+        let gamma = (1.0 + 0.01) / (1.0 - 0.01);
+        let values = (0..300)
+            .map(|i| (i as f64 - 100.0) * 1.5)
+            .collect::<Vec<_>>();
+
+        let sketch = |values: &[f64]| {
+            let mut sketch = Sketch::default();
+            for v in values {
+                sketch.add(gamma, *v).unwrap();
+            }
+            sketch
+        };
+        let full = sketch(&values);
+
+        // ((a, b), c)
+        let mut left = sketch(&values[..50]);
+        left.merge(sketch(&values[50..200])).unwrap();
+        left.merge(sketch(&values[200..])).unwrap();
+        // (a, (b, c))
+        let mut right = sketch(&values[50..200]);
+        right.merge(sketch(&values[200..])).unwrap();
+        let mut outer = sketch(&values[..50]);
+        outer.merge(right).unwrap();
+
+        assert_eq!(full, left);
+        assert_eq!(full, outer);
+    }
+
+    #[test]
+    fn test_merges_which_overflow_are_rejected() {
+        let sketch = |count: u64, zero: u64| Sketch {
+            positive: vec![(3, count)].into_iter().collect(),
+            negative: BTreeMap::new(),
+            zero,
+            min: Some(0.0),
+            max: Some(1.0),
+        };
+
+        // Merges up to the maximum count succeed.
+        let mut lhs = sketch(u64::MAX - 11, 1);
+        lhs.merge(sketch(5, 5)).unwrap();
+        assert_eq!(lhs, sketch(u64::MAX - 6, 6));
+
+        // Merges beyond it fail, and leave the sketch unmodified.
+        assert_eq!(lhs.merge(sketch(1, 0)), None);
+        assert_eq!(lhs.merge(sketch(0, 1)), None);
+        assert_eq!(lhs.add(1.1, 1.0), None);
+        assert_eq!(lhs, sketch(u64::MAX - 6, 6));
+    }
+
+    #[test]
+    fn test_reduction() {
+        // Representative value of the bin which holds 10.
+        let bin_19 = value((1.0 + 0.0625) / (1.0 - 0.0625), 19);
+
+        run_reduce_cases(
+            json!({
+                "reduce": {
+                    "strategy": "ddSketch",
+                    "relativeAccuracy": 0.0625,
+                    "quantiles": [0.5, 1],
+                }
+            }),
+            vec![
+                Partial {
+                    rhs: json!({"add": [10, 0]}),
+                    expect: Ok(json!({
+                        "count": 2,
+                        "min": 0.0,
+                        "max": 10.0,
+                        "negative": [],
+                        "positive": [[19, 1]],
+                        "quantiles": {"0.5": 10.0, "1": 10.0},
+                        "zeroCount": 1,
+                    })),
+                },
+                Partial {
+                    rhs: json!({"add": [-3.5, 12, 10]}),
+                    expect: Ok(json!({
+                        "count": 5,
+                        "min": -3.5,
+                        "max": 12.0,
+                        "negative": [[11, 1]],
+                        "positive": [[19, 2], [20, 1]],
+                        "quantiles": {"0.5": bin_19, "1": 12.0},
+                        "zeroCount": 1,
+                    })),
+                },
+                // Sketches of other reductions are merged.
+                Partial {
+                    rhs: json!({
+                        "count": 1,
+                        "min": 2,
+                        "max": 2,
+                        "positive": [[6, 1]],
+                        "quantiles": {},
+                    }),
+                    expect: Ok(json!({
+                        "count": 6,
+                        "min": -3.5,
+                        "max": 12.0,
+                        "negative": [[11, 1]],
+                        "positive": [[6, 1], [19, 2], [20, 1]],
+                        "quantiles": {"0.5": bin_19, "1": 12.0},
+                        "zeroCount": 1,
+                    })),
+                },
+                Partial {
+                    rhs: json!({"add": ["10"]}),
+                    expect: Err(Error::DdSketchWrongType),
+                },
+                Partial {
+                    rhs: json!({"positive": [[1, 1]]}),
+                    expect: Err(Error::DdSketchWrongType),
+                },
+                Partial {
+                    rhs: json!([1, 2]),
+                    expect: Err(Error::DdSketchWrongType),
+                },
+                // Counts which don't fit in a u64 are errors.
+                Partial {
+                    rhs: json!({"min": 1, "max": 2, "positive": [[6, u64::MAX]]}),
+                    expect: Err(Error::DdSketchCountOverflow),
+                },
+                Partial {
+                    rhs: json!({
+                        "min": 1,
+                        "max": 2,
+                        "positive": [[6, u64::MAX]],
+                        "zeroCount": 1,
+                    }),
+                    expect: Err(Error::DdSketchCountOverflow),
+                },
+            ],
+        )
+    }
+}
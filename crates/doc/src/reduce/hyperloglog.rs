@@ -0,0 +1,224 @@
+use super::{reduce_accumulator, Cursor, Error, Reducer, Result};
+use serde_json::{Map, Value};
+
+use super::strategy::HyperLogLog;
+
+/// Registers of a HyperLogLog sketch having 2^precision registers.
+///
+/// Each added item is hashed to 64 bits. The leading `precision` bits of the
+/// hash select a register, and the register is updated to the maximum of its
+/// current value and the 1-based position of the first set bit within the
+/// remaining bits of the hash.
+///
+/// Items are hashed using 64-bit FNV-1a, followed by the `fmix64` finalizer
+/// of MurmurHash3. Strings are hashed over their UTF-8 bytes, and all other
+/// values are hashed over their compact JSON encoding. The hash is part of
+/// the serialized form of a sketch, and must never change.
+///
+/// Within a document, a sketch is serialized as the standard base64 encoding
+/// of its registers, in order, with one byte per register.
+#[derive(Debug, PartialEq)]
+pub struct Registers(Vec<u8>);
+
+impl Registers {
+    pub fn new(precision: u8) -> Self {
+        Self(vec![0; 1 << precision])
+    }
+
+    fn precision(&self) -> u32 {
+        self.0.len().trailing_zeros()
+    }
+
+    pub fn add(&mut self, item: &Value) {
+        let hash = match item {
+            Value::String(s) => hash(s.as_bytes()),
+            _ => hash(&serde_json::to_vec(item).unwrap()),
+        };
+        let precision = self.precision();
+        let index = (hash >> (64 - precision)) as usize;
+        // A sentinel bit bounds the rank when the remaining bits are all zero.
+        let rank = ((hash << precision) | (1 << (precision - 1))).leading_zeros() + 1;
+
+        let register = &mut self.0[index];
+        *register = (*register).max(rank as u8);
+    }
+
+    pub fn union(&mut self, other: &Registers) {
+        for (lhs, rhs) in self.0.iter_mut().zip(other.0.iter()) {
+            *lhs = (*lhs).max(*rhs);
+        }
+    }
+
+    /// Estimate the number of distinct items which have been added.
+    pub fn count(&self) -> u64 {
+        let m = self.0.len() as f64;
+        let alpha = match self.0.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.0.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Use linear counting for small cardinalities, where the raw estimate is biased.
+        let zeros = self.0.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros != 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        base64::encode(&self.0)
+    }
+
+    pub fn decode(precision: u8, encoded: &str) -> Option<Self> {
+        let registers = base64::decode(encoded).ok()?;
+        let max_rank = 64 - precision + 1;
+
+        if registers.len() != 1 << precision || registers.iter().any(|r| *r > max_rank) {
+            None
+        } else {
+            Some(Self(registers))
+        }
+    }
+}
+
+fn hash(bytes: &[u8]) -> u64 {
+    // FNV-1a.
+    let mut h: u64 = 0xcbf29ce484222325;
+    for b in bytes {
+        h ^= *b as u64;
+        h = h.wrapping_mul(0x100000001b3);
+    }
+    // MurmurHash3 fmix64.
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^= h >> 33;
+    h
+}
+
+impl HyperLogLog {
+    /// Fold a sketch instance into |registers|. Instances are objects which
+    /// may have "add" (an array of items), "sketch" (serialized registers),
+    /// and "count" (which is derived, and ignored) properties.
+    fn fold(&self, registers: &mut Registers, instance: Value) -> std::result::Result<(), ()> {
+        let instance = match instance {
+            Value::Object(instance) => instance,
+            _ => return Err(()),
+        };
+        for (prop, value) in instance {
+            match (prop.as_ref(), value) {
+                ("add", Value::Array(items)) => {
+                    for item in items.iter() {
+                        registers.add(item);
+                    }
+                }
+                ("sketch", Value::String(encoded)) => {
+                    let other = Registers::decode(self.precision, &encoded).ok_or(())?;
+                    registers.union(&other);
+                }
+                ("count", Value::Number(_)) => (),
+                _ => return Err(()),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Reducer for HyperLogLog {
+    fn reduce(&self, cur: Cursor) -> Result<Value> {
+        let registers = reduce_accumulator(
+            cur,
+            Registers::new(self.precision),
+            |registers, instance| {
+                self.fold(registers, instance)
+                    .map_err(|()| Error::HyperLogLogWrongType)
+            },
+        )?;
+
+        let mut out = Map::new();
+        out.insert("count".to_string(), Value::from(registers.count()));
+        out.insert("sketch".to_string(), Value::String(registers.encode()));
+        Ok(Value::Object(out))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::*;
+    use super::*;
+
+    #[test]
+    fn test_estimates_are_within_expected_error() {
+        for &(precision, n) in &[(4, 10), (10, 500), (12, 100_000), (14, 20_000)] {
+            let mut registers = Registers::new(precision);
+            for i in 0..n {
+                registers.add(&json!(format!("user-{}", i)));
+                // Duplicates never change the estimate.
+                registers.add(&json!(format!("user-{}", i / 2)));
+            }
+            let expect_error = 1.04 / ((1 << precision) as f64).sqrt();
+            let error = (registers.count() as f64 - n as f64).abs() / n as f64;
+
+            assert!(
+                error < 3.0 * expect_error,
+                "precision {} n {} estimate {}",
+                precision,
+                n,
+                registers.count()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let mut registers = Registers::new(6);
+        for item in &[json!("a"), json!(1), json!({"b": [true, null]})] {
+            registers.add(item);
+        }
+        let encoded = registers.encode();
+        assert_eq!(Registers::decode(6, &encoded), Some(registers));
+        // Sketches of the wrong size are rejected.
+        assert_eq!(Registers::decode(7, &encoded), None);
+        assert_eq!(Registers::decode(6, "not base64!"), None);
+    }
+
+    #[test]
+    fn test_reduction() {
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "hyperLogLog", "precision": 4 } }),
+            vec![
+                Partial {
+                    rhs: json!({"add": ["a", "b"]}),
+                    expect: Ok(json!({"count": 2, "sketch": "AAAAAAAAAQADAAAAAAAAAA=="})),
+                },
+                // Already-added items don't change the sketch.
+                Partial {
+                    rhs: json!({"add": ["b", "a", "b"]}),
+                    expect: Ok(json!({"count": 2, "sketch": "AAAAAAAAAQADAAAAAAAAAA=="})),
+                },
+                Partial {
+                    rhs: json!({"add": ["c"], "sketch": "AQAAAAAAAAAAAAAAAAAAAA=="}),
+                    expect: Ok(json!({"count": 5, "sketch": "AQAAAAAAAQIDAAAAAAAAAA=="})),
+                },
+                Partial {
+                    rhs: json!({"add": "d"}),
+                    expect: Err(Error::HyperLogLogWrongType),
+                },
+                Partial {
+                    rhs: json!({"sketch": "AAAA"}),
+                    expect: Err(Error::HyperLogLogWrongType),
+                },
+                Partial {
+                    rhs: json!({"other": 1}),
+                    expect: Err(Error::HyperLogLogWrongType),
+                },
+            ],
+        )
+    }
+}
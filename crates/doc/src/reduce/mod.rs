@@ -1,12 +1,17 @@
 pub use super::Valid;
+use super::{Annotation, Schema, SchemaIndex};
 use itertools::EitherOrBoth;
+use json::schema::{Application, Keyword};
 pub use json::{schema::types, validator::Context, LocatedItem, LocatedProperty, Location};
 use serde_json::Value;
 
+mod ddsketch;
+mod hyperloglog;
 mod set;
+mod stats;
 mod strategy;
 
-pub use strategy::Strategy;
+pub use strategy::{LastWriteWins, Strategy, SumMode};
 
 type Index<'a> = &'a [(&'a Strategy, u64)];
 
@@ -16,7 +21,7 @@ pub enum Error {
     AppendWrongType,
     #[error("`sum` resulted in numeric overflow")]
     SumNumericOverflow,
-    #[error("'sum' strategy expects numbers")]
+    #[error("'sum' strategy expects numbers, or numeric strings in 'exact' mode")]
     SumWrongType,
     #[error("'merge' strategy expects objects or arrays")]
     MergeWrongType,
@@ -24,6 +29,22 @@ pub enum Error {
         "'set' strategy expects objects having only 'add', 'remove', and 'intersect' properties with consistent object or array types"
     )]
     SetWrongType,
+    #[error("'topN' strategy expects arrays")]
+    TopNWrongType,
+    #[error(
+        "'hyperLogLog' strategy expects objects having only 'add' (an array), 'sketch', and 'count' properties"
+    )]
+    HyperLogLogWrongType,
+    #[error(
+        "'ddSketch' strategy expects objects having only 'add' (an array of numbers) and sketch properties"
+    )]
+    DdSketchWrongType,
+    #[error("'ddSketch' counts overflowed")]
+    DdSketchCountOverflow,
+    #[error(
+        "'stats' strategy expects objects having only 'add' (an array of observations) and accumulator properties"
+    )]
+    StatsWrongType,
 
     #[error("while reducing {:?}", .ptr)]
     WithLocation {
@@ -40,15 +61,71 @@ pub enum Error {
     },
 }
 
+/// Pointer to the change operation of a document, such as is produced by
+/// change-data-capture connectors.
+pub const OP_POINTER: &str = "/_meta/op";
+/// Operation of a document which is a deletion tombstone of its key.
+pub const OP_DELETE: &str = "d";
+
+/// Returns true if the document has the operation of a deletion tombstone.
+/// Tombstones are honored only if enabled by the `deletions` option of the
+/// root `merge` strategy.
+pub fn is_tombstone(doc: &Value) -> bool {
+    matches!(doc.pointer(OP_POINTER), Some(Value::String(op)) if op == OP_DELETE)
+}
+
+/// Returns true if the `deletions` option is enabled by the root `merge`
+/// strategy of schema |uri|. Tombstones needn't be valid documents of their
+/// schema, so the strategy is found by walking the in-place `$ref` and `allOf`
+/// applications of the schema rather than through a validation.
+pub fn deletions_enabled_by(index: &SchemaIndex, uri: &url::Url) -> bool {
+    fn walk(index: &SchemaIndex, schema: &Schema, depth: usize) -> bool {
+        // Bound the walk, as references may be recursive.
+        if depth == 32 {
+            return false;
+        }
+        schema.kw.iter().any(|kw| match kw {
+            Keyword::Annotation(Annotation::Reduce(Strategy::Merge(merge))) => merge.deletions,
+            Keyword::Application(Application::Ref(uri), _) => index
+                .fetch(uri)
+                .map_or(false, |schema| walk(index, schema, depth + 1)),
+            Keyword::Application(Application::AllOf { .. }, schema) => {
+                walk(index, schema, depth + 1)
+            }
+            _ => false,
+        })
+    }
+    index
+        .fetch(uri)
+        .map_or(false, |schema| walk(index, schema, 0))
+}
+
 /// Reduce a RHS document validation into a preceding LHS document.
 /// The RHS validation provides reduction annotation outcomes used in the reduction.
 /// If |prune|, then LHS is the root-most (or left-most) document in the reduction
 /// sequence. Depending on the reduction strategy, additional pruning can be done
 /// in this case (i.e., removing tombstones) that isn't possible in a partial
 /// non-root reduction.
+///
+/// If deletions are enabled by the root `merge` strategy, then a RHS deletion
+/// tombstone discards the LHS, and is itself the reduced result.
+/// A RHS which follows a LHS tombstone is reduced as if there were no LHS.
+/// If the reduction doesn't |prune|, the caller is responsible for tracking
+/// that its result replaces (rather than reduces into) documents which
+/// preceded the tombstone.
 pub fn reduce<'sm, 'v>(lhs: Option<Value>, rhs: Valid<'sm, 'v>, prune: bool) -> Result<Value> {
     let tape = rhs.extract_reduce_annotations();
+    let deletions = deletions_enabled(&tape);
     let tape = &mut tape.as_slice();
+    let rhs = rhs.0.document;
+
+    if deletions && is_tombstone(&rhs) {
+        return Ok(rhs);
+    }
+    let lhs = match lhs {
+        Some(lhs) if deletions && is_tombstone(&lhs) => None,
+        lhs => lhs,
+    };
 
     let reduced = match lhs {
         Some(lhs) => Cursor::Both {
@@ -56,20 +133,25 @@ pub fn reduce<'sm, 'v>(lhs: Option<Value>, rhs: Valid<'sm, 'v>, prune: bool) ->
             loc: Location::Root,
             prune,
             lhs,
-            rhs: rhs.0.document,
+            rhs,
         },
         None => Cursor::Right {
             tape,
             loc: Location::Root,
             prune,
-            rhs: rhs.0.document,
+            rhs,
         },
     }
     .reduce()?;
     assert!(tape.is_empty());
+
     Ok(reduced)
 }
 
+fn deletions_enabled(tape: Index) -> bool {
+    matches!(tape.first(), Some((Strategy::Merge(merge), _)) if merge.deletions)
+}
+
 impl Error {
     fn cursor(cur: Cursor, detail: Error) -> Error {
         let (ptr, lhs_type, rhs_type) = match cur {
@@ -195,6 +277,35 @@ fn reduce_item<'i, 'l, 'a>(
     }
 }
 
+/// Reduce a location holding a mergeable accumulator (such as a sketch) by
+/// folding each of its LHS, if any, and RHS into |acc|. The accumulator
+/// location is a leaf of the reduction, and its sub-locations are skipped.
+/// |fold| returns an Error if a side isn't a valid accumulator, or can't be folded.
+fn reduce_accumulator<A>(
+    cur: Cursor,
+    mut acc: A,
+    mut fold: impl FnMut(&mut A, Value) -> Result<()>,
+) -> Result<A> {
+    let (tape, loc, lhs, rhs) = match cur {
+        Cursor::Both {
+            tape,
+            loc,
+            lhs,
+            rhs,
+            ..
+        } => (tape, loc, Some(lhs), rhs),
+        Cursor::Right { tape, loc, rhs, .. } => (tape, loc, None, rhs),
+    };
+    *tape = &tape[count_nodes(&rhs)..];
+
+    for instance in lhs.into_iter().chain(std::iter::once(rhs)) {
+        if let Err(err) = fold(&mut acc, instance) {
+            return Err(Error::at(loc, err));
+        }
+    }
+    Ok(acc)
+}
+
 fn count_nodes(v: &Value) -> usize {
     match v {
         Value::Bool(_) | Value::Null | Value::String(_) | Value::Number(_) => 1,
@@ -236,6 +347,123 @@ pub mod test {
         assert_eq!(count_nodes(&doc), 11);
     }
 
+    #[test]
+    fn test_tombstones() {
+        let schema = json!({
+            "properties": {
+                "n": {"reduce": {"strategy": "sum"}},
+                "_meta": {
+                    "properties": {
+                        "op": {"reduce": {"strategy": "lastWriteWins"}},
+                    },
+                    "reduce": {"strategy": "merge"},
+                },
+            },
+            "reduce": {"strategy": "merge", "deletions": true},
+        });
+
+        run_reduce_cases(
+            schema.clone(),
+            vec![
+                Partial {
+                    rhs: json!({"k": 1, "n": 1}),
+                    expect: Ok(json!({"k": 1, "n": 1})),
+                },
+                // A tombstone discards the prior document.
+                Partial {
+                    rhs: json!({"k": 1, "_meta": {"op": "d"}}),
+                    expect: Ok(json!({"k": 1, "_meta": {"op": "d"}})),
+                },
+                // A document following a tombstone doesn't reduce into it.
+                Partial {
+                    rhs: json!({"k": 1, "n": 2, "_meta": {"op": "c"}}),
+                    expect: Ok(json!({"k": 1, "n": 2, "_meta": {"op": "c"}})),
+                },
+                Partial {
+                    rhs: json!({"k": 1, "n": 3, "_meta": {"op": "u"}}),
+                    expect: Ok(json!({"k": 1, "n": 5, "_meta": {"op": "u"}})),
+                },
+            ],
+        );
+
+        run_reduce_cases(
+            schema.clone(),
+            vec![
+                Full {
+                    rhs: json!({"k": 1, "n": 1}),
+                    expect: Ok(json!({"k": 1, "n": 1})),
+                },
+                Full {
+                    rhs: json!({"k": 1, "_meta": {"op": "d"}}),
+                    expect: Ok(json!({"k": 1, "_meta": {"op": "d"}})),
+                },
+                Full {
+                    rhs: json!({"k": 1, "n": 2}),
+                    expect: Ok(json!({"k": 1, "n": 2})),
+                },
+            ],
+        );
+
+        // Without the `deletions` option, tombstones are reduced like any
+        // other document.
+        let mut schema = schema;
+        schema["reduce"] = json!({"strategy": "merge"});
+
+        run_reduce_cases(
+            schema,
+            vec![
+                Partial {
+                    rhs: json!({"k": 1, "n": 1}),
+                    expect: Ok(json!({"k": 1, "n": 1})),
+                },
+                Partial {
+                    rhs: json!({"k": 1, "_meta": {"op": "d"}}),
+                    expect: Ok(json!({"k": 1, "n": 1, "_meta": {"op": "d"}})),
+                },
+                Full {
+                    rhs: json!({"k": 1, "n": 2, "_meta": {"op": "c"}}),
+                    expect: Ok(json!({"k": 1, "n": 3, "_meta": {"op": "c"}})),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_deletions_enabled_by_schema() {
+        let schema = json!({
+            "$defs": {
+                "enabled": {"reduce": {"strategy": "merge", "deletions": true}},
+                "disabled": {"reduce": {"strategy": "merge"}},
+                "ref": {"$ref": "#/$defs/enabled"},
+                "allOf": {"allOf": [{"required": ["k"]}, {"$ref": "#/$defs/ref"}]},
+                "nested": {
+                    "properties": {"a": {"$ref": "#/$defs/enabled"}},
+                    "reduce": {"strategy": "merge"},
+                },
+                "recursive": {"$ref": "#/$defs/recursive"},
+            },
+        });
+        let curi = url::Url::parse("http://example/schema").unwrap();
+        let schema: Schema = build_schema(curi.clone(), &schema).unwrap();
+
+        let mut index = IndexBuilder::new();
+        index.add(&schema).unwrap();
+        let index = index.into_index();
+
+        for (def, expect) in &[
+            ("enabled", true),
+            ("disabled", false),
+            ("ref", true),
+            ("allOf", true),
+            ("nested", false),
+            ("recursive", false),
+            ("missing", false),
+        ] {
+            let uri = curi.join(&format!("#/$defs/{}", def)).unwrap();
+            assert_eq!(deletions_enabled_by(&index, &uri), *expect, "{}", def);
+        }
+    }
+
     pub enum Case {
         Partial { rhs: Value, expect: Result<Value> },
         Full { rhs: Value, expect: Result<Value> },
@@ -0,0 +1,221 @@
+use super::{reduce_accumulator, Cursor, Error, Reducer, Result};
+use serde_json::{Map, Value};
+
+use super::strategy::Stats;
+
+/// Accumulator of running statistics over weighted observations, which
+/// merges using the parallel algorithm of Chan et al. to Welford's method.
+#[derive(Debug, Default, PartialEq)]
+pub struct Accumulator {
+    /// Total weight of observations.
+    count: f64,
+    /// Weighted mean of observations.
+    mean: f64,
+    /// Weighted sum of squared differences from the mean.
+    m2: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Accumulator {
+    pub fn add(&mut self, value: f64, weight: f64) {
+        self.merge(Accumulator {
+            count: weight,
+            mean: value,
+            m2: 0.0,
+            min: Some(value),
+            max: Some(value),
+        })
+    }
+
+    pub fn merge(&mut self, other: Accumulator) {
+        let count = self.count + other.count;
+
+        if other.count == 0.0 {
+            // Nothing to merge.
+        } else if self.count == 0.0 {
+            self.mean = other.mean;
+            self.m2 = other.m2;
+        } else {
+            let delta = other.mean - self.mean;
+            self.mean += delta * other.count / count;
+            self.m2 += other.m2 + delta * delta * self.count * other.count / count;
+        }
+        self.count = count;
+
+        self.min = match (self.min, other.min) {
+            (Some(l), Some(r)) => Some(l.min(r)),
+            (l, r) => l.or(r),
+        };
+        self.max = match (self.max, other.max) {
+            (Some(l), Some(r)) => Some(l.max(r)),
+            (l, r) => l.or(r),
+        };
+    }
+}
+
+/// Map an f64 into a Value, using an integer representation if it's exact.
+fn number(f: f64) -> Value {
+    if f.fract() == 0.0 && f.abs() < (1u64 << f64::MANTISSA_DIGITS) as f64 {
+        Value::from(f as i64)
+    } else {
+        Value::from(f)
+    }
+}
+
+impl Stats {
+    /// Parse a statistics instance. Instances are objects which may have
+    /// "add" (an array of observations), and "count", "mean", and "m2"
+    /// properties of a prior reduction, as well as "min" and "max" if
+    /// they're tracked. Observations are numbers, or objects having a
+    /// numeric "value" and a positive "weight".
+    fn parse(&self, instance: Value) -> Option<Accumulator> {
+        let mut acc = Accumulator::default();
+        let mut adds = Vec::new();
+
+        for (prop, value) in instance.as_object()? {
+            match (prop.as_ref(), value) {
+                ("add", Value::Array(items)) => {
+                    for item in items {
+                        adds.push(match item {
+                            Value::Number(n) => (n.as_f64()?, 1.0),
+                            Value::Object(obs) if obs.len() == 2 => (
+                                obs.get("value")?.as_f64()?,
+                                obs.get("weight")?.as_f64().filter(|w| *w > 0.0)?,
+                            ),
+                            _ => return None,
+                        });
+                    }
+                }
+                ("count", count) => acc.count = count.as_f64().filter(|c| *c >= 0.0)?,
+                ("mean", mean) => acc.mean = mean.as_f64()?,
+                ("m2", m2) => acc.m2 = m2.as_f64().filter(|m| *m >= 0.0)?,
+                ("min", min) if self.min_max => acc.min = Some(min.as_f64()?),
+                ("max", max) if self.min_max => acc.max = Some(max.as_f64()?),
+                _ => return None,
+            }
+        }
+
+        for (value, weight) in adds {
+            acc.add(value, weight);
+        }
+        Some(acc)
+    }
+
+    fn to_value(&self, acc: Accumulator) -> Value {
+        let mut out = Map::new();
+
+        out.insert("count".to_string(), number(acc.count));
+        out.insert("mean".to_string(), number(acc.mean));
+        out.insert("m2".to_string(), number(acc.m2));
+
+        if let (true, Some(min), Some(max)) = (self.min_max, acc.min, acc.max) {
+            out.insert("min".to_string(), number(min));
+            out.insert("max".to_string(), number(max));
+        }
+        Value::Object(out)
+    }
+}
+
+impl Reducer for Stats {
+    fn reduce(&self, cur: Cursor) -> Result<Value> {
+        let acc = reduce_accumulator(cur, Accumulator::default(), |acc, instance| {
+            self.parse(instance)
+                .map(|other| acc.merge(other))
+                .ok_or(Error::StatsWrongType)
+        })?;
+        Ok(self.to_value(acc))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::*;
+    use super::*;
+
+    #[test]
+    fn test_merges_match_a_single_pass() {
+        let values = (0..100)
+            .map(|i| ((i * 37) % 101) as f64 / 4.0 - 3.0)
+            .collect::<Vec<_>>();
+
+        let accumulate = |values: &[f64]| {
+            let mut acc = Accumulator::default();
+            for v in values {
+                acc.add(*v, 1.0);
+            }
+            acc
+        };
+        let full = accumulate(&values);
+
+        let mut left = accumulate(&values[..10]);
+        left.merge(accumulate(&values[10..70]));
+        left.merge(accumulate(&values[70..]));
+
+        let mut right = accumulate(&values[10..70]);
+        right.merge(accumulate(&values[70..]));
+        let mut outer = accumulate(&values[..10]);
+        outer.merge(right);
+
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance =
+            values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / values.len() as f64;
+
+        for acc in &[full, left, outer] {
+            assert_eq!(acc.count, 100.0);
+            assert!((acc.mean - mean).abs() < 1e-9, "{:?}", acc);
+            assert!((acc.m2 / acc.count - variance).abs() < 1e-9, "{:?}", acc);
+            assert_eq!((acc.min, acc.max), (Some(-3.0), Some(22.0)));
+        }
+    }
+
+    #[test]
+    fn test_reduction() {
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "stats", "minMax": true } }),
+            vec![
+                Partial {
+                    rhs: json!({"add": [2, 4]}),
+                    expect: Ok(json!({"count": 2, "mean": 3, "m2": 2, "min": 2, "max": 4})),
+                },
+                // Weighted observations.
+                Partial {
+                    rhs: json!({"add": [{"value": 9, "weight": 2}]}),
+                    expect: Ok(json!({"count": 4, "mean": 6, "m2": 38, "min": 2, "max": 9})),
+                },
+                // Accumulators of other reductions are merged.
+                Partial {
+                    rhs: json!({"count": 4, "mean": 1, "m2": 0.5, "min": 0.5, "max": 1.5}),
+                    expect: Ok(json!({"count": 8, "mean": 3.5, "m2": 88.5, "min": 0.5, "max": 9})),
+                },
+                Partial {
+                    rhs: json!({"add": ["1"]}),
+                    expect: Err(Error::StatsWrongType),
+                },
+                Partial {
+                    rhs: json!({"add": [{"value": 1, "weight": 0}]}),
+                    expect: Err(Error::StatsWrongType),
+                },
+                Partial {
+                    rhs: json!({"count": -1}),
+                    expect: Err(Error::StatsWrongType),
+                },
+            ],
+        );
+
+        // Without minMax, extremes are neither tracked nor accepted.
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "stats" } }),
+            vec![
+                Partial {
+                    rhs: json!({"add": [1.5]}),
+                    expect: Ok(json!({"count": 1, "mean": 1.5, "m2": 0})),
+                },
+                Partial {
+                    rhs: json!({"min": 1}),
+                    expect: Err(Error::StatsWrongType),
+                },
+            ],
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::{count_nodes, reduce_item, reduce_prop, Cursor, Error, Reducer, Result};
 use itertools::EitherOrBoth;
-use json::{json_cmp, json_cmp_at, Number};
+use json::{json_cmp, json_cmp_at, Decimal, Number};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::cmp::Ordering;
@@ -15,8 +15,41 @@ pub enum Strategy {
     Append,
     /// FirstWriteWins keeps the LHS value.
     FirstWriteWins,
+    /// DdSketch maintains a DDSketch of observed numbers, from which
+    /// quantiles (such as the median or 99th percentile) are estimated
+    /// to within a configured relative accuracy.
+    ///
+    /// The LHS and RHS must be objects having an optional "add" property,
+    /// an array of numbers to be added, and optional "positive", "negative",
+    /// "zeroCount", "min", and "max" properties of a sketch produced by a
+    /// prior reduction. Reductions always produce a complete sketch,
+    /// along with its total "count" and estimates of each configured
+    /// "quantiles". A "count" or "quantiles" of either side is ignored.
+    ///
+    /// Sketches merge by summing the counts of their bins, which is exactly
+    /// associative: partial reductions produce the same sketch as a full one.
+    DdSketch(DdSketch),
+    /// HyperLogLog maintains a sketch for estimating the number of distinct
+    /// items which have been added to it, using constant space.
+    ///
+    /// The LHS and RHS must be objects having an optional "add" property,
+    /// an array of items to be added, and an optional "sketch" property
+    /// holding a sketch produced by a prior reduction. Reductions always
+    /// produce an object having a "sketch" and an estimated "count" of
+    /// distinct items. A "count" of either side is ignored.
+    ///
+    /// Sketches are mergeable, and have a stable serialized encoding:
+    /// see `hyperloglog::Registers`. Each sketch uses 2^precision bytes,
+    /// and has a standard error of about 1.04 / sqrt(2^precision).
+    HyperLogLog(HyperLogLog),
     /// LastWriteWins takes the RHS value.
-    LastWriteWins,
+    ///
+    /// A provided key, if present, is a version of the LHS & RHS (such as
+    /// a sequence number or timestamp) which guards the replacement:
+    /// the RHS is taken only if its version is greater than or equal to that
+    /// of the LHS, and the LHS is kept otherwise. This allows for documents
+    /// which arrive out of order to converge to the most recent value.
+    LastWriteWins(LastWriteWins),
     /// Maximize keeps the greater of the LHS & RHS.
     /// A provided key, if present, determines the relative ordering.
     /// If values are equal, they're deeply merged.
@@ -34,6 +67,9 @@ pub enum Strategy {
     /// items of the longer.
     ///
     /// If LHS and RHS are both Objects then it perform a deep merge of each property.
+    ///
+    /// If `deletions` is set on the document root, then documents having a
+    /// `_meta/op` of "d" are deletion tombstones of their key (see `reduce::reduce`).
     Merge(Merge),
     /// Minimize keeps the smaller of the LHS & RHS.
     /// A provided key, if present, determines the relative ordering.
@@ -67,13 +103,46 @@ pub enum Strategy {
     /// consistent across the "add" / "intersect" / "remove" terms of both
     /// sides of the reduction.
     Set(Set),
+    /// Stats maintains running statistics of observed numbers, from which
+    /// their mean and variance are derived.
+    ///
+    /// The LHS and RHS must be objects having an optional "add" property,
+    /// an array of observations to be added, and optional "count", "mean",
+    /// and "m2" properties of a prior reduction. Observations are numbers,
+    /// or objects having a numeric "value" and a positive "weight".
+    /// Reductions always produce "count" (the total weight of observations),
+    /// "mean", and "m2" (the weighted sum of squared differences from the
+    /// mean), and population variance is `m2 / count`. If `minMax` is set,
+    /// then "min" and "max" observations are tracked as well.
+    ///
+    /// Accumulators are merged using the parallel variant of Welford's
+    /// algorithm, which is associative and numerically stable.
+    Stats(Stats),
     /// Sum the LHS and RHS, both of which must be numbers.
     /// Sum will fail if the operation would result in a numeric overflow
     /// (in other words, the numbers become too large to be represented).
     ///
-    /// In the future, we may allow for arbitrary-sized integer and
-    /// floating-point representations which use a string encoding scheme.
-    Sum,
+    /// If `mode` is "exact", then the LHS and RHS may also be strings of
+    /// decimal numbers, and are summed with arbitrary precision. The sum
+    /// is a number if it can be represented without loss of precision,
+    /// and is otherwise a string of the exact decimal result.
+    Sum(Sum),
+    /// TopN keeps an array of the N greatest items of the LHS & RHS,
+    /// in descending order, or the N smallest in ascending order if
+    /// `bottom` is set. A provided key, if present, determines the
+    /// relative ordering of items. Items that are equal are deeply merged.
+    ///
+    /// The LHS and RHS must both be arrays which are already sorted and
+    /// de-duplicated in the order of the strategy. An RHS of a single
+    /// item is always sorted.
+    TopN(TopN),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct LastWriteWins {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -95,6 +164,142 @@ pub struct Maximize {
 pub struct Merge {
     #[serde(default)]
     key: Vec<String>,
+    /// Treat documents having a `_meta/op` of "d" as deletion tombstones.
+    /// Applies only to the document root.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deletions: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Stats {
+    /// Track the minimum and maximum observations.
+    #[serde(default)]
+    pub min_max: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Sum {
+    #[serde(default)]
+    pub mode: SumMode,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SumMode {
+    /// Sum using the native integer or floating-point representations of
+    /// the LHS and RHS, failing upon overflow.
+    Native,
+    /// Sum numbers or numeric strings with arbitrary precision.
+    Exact,
+}
+
+impl Default for SumMode {
+    fn default() -> Self {
+        SumMode::Native
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TopN {
+    #[serde(default)]
+    key: Vec<String>,
+    /// Maximum number of items to keep.
+    limit: usize,
+    /// Keep the smallest items instead of the greatest.
+    #[serde(default)]
+    bottom: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DdSketch {
+    /// Relative accuracy of estimated quantiles, between 0 and 1 (exclusive).
+    #[serde(
+        default = "DdSketch::default_relative_accuracy",
+        deserialize_with = "DdSketch::deserialize_relative_accuracy"
+    )]
+    pub relative_accuracy: f64,
+    /// Quantiles to estimate, each between 0 and 1 (inclusive).
+    #[serde(
+        default = "DdSketch::default_quantiles",
+        deserialize_with = "DdSketch::deserialize_quantiles"
+    )]
+    pub quantiles: Vec<f64>,
+}
+
+impl DdSketch {
+    fn default_relative_accuracy() -> f64 {
+        0.01
+    }
+
+    fn default_quantiles() -> Vec<f64> {
+        vec![0.5, 0.9, 0.99]
+    }
+
+    fn deserialize_relative_accuracy<'de, D>(d: D) -> std::result::Result<f64, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let accuracy = f64::deserialize(d)?;
+        if accuracy > 0.0 && accuracy < 1.0 {
+            Ok(accuracy)
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "ddSketch relativeAccuracy must be between 0 and 1 (got {})",
+                accuracy
+            )))
+        }
+    }
+
+    fn deserialize_quantiles<'de, D>(d: D) -> std::result::Result<Vec<f64>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let quantiles = Vec::<f64>::deserialize(d)?;
+        match quantiles.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+            Some(q) => Err(serde::de::Error::custom(format!(
+                "ddSketch quantiles must be between 0 and 1 (got {})",
+                q
+            ))),
+            None => Ok(quantiles),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HyperLogLog {
+    /// Number of leading hash bits used to index sketch registers,
+    /// between 4 and 16.
+    #[serde(
+        default = "HyperLogLog::default_precision",
+        deserialize_with = "HyperLogLog::deserialize_precision"
+    )]
+    pub precision: u8,
+}
+
+impl HyperLogLog {
+    fn default_precision() -> u8 {
+        12
+    }
+
+    fn deserialize_precision<'de, D>(d: D) -> std::result::Result<u8, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let precision = u8::deserialize(d)?;
+        if (4..=16).contains(&precision) {
+            Ok(precision)
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "hyperLogLog precision must be between 4 and 16 (got {})",
+                precision
+            )))
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -116,13 +321,22 @@ impl Reducer for Strategy {
     fn reduce(&self, cur: Cursor) -> Result<Value> {
         match self {
             Strategy::Append => Self::append(cur),
+            Strategy::DdSketch(sketch) => sketch.reduce(cur),
             Strategy::FirstWriteWins => Self::first_write_wins(cur),
-            Strategy::LastWriteWins => Self::last_write_wins(cur),
+            Strategy::HyperLogLog(hll) => hll.reduce(cur),
+            Strategy::LastWriteWins(lww) => Self::last_write_wins(cur, lww),
             Strategy::Maximize(max) => Self::maximize(cur, max),
             Strategy::Merge(merge) => Self::merge(cur, merge),
             Strategy::Minimize(min) => Self::minimize(cur, min),
             Strategy::Set(set) => set.reduce(cur),
-            Strategy::Sum => Self::sum(cur),
+            Strategy::Stats(stats) => stats.reduce(cur),
+            Strategy::Sum(Sum {
+                mode: SumMode::Native,
+            }) => Self::sum(cur),
+            Strategy::Sum(Sum {
+                mode: SumMode::Exact,
+            }) => Self::sum_exact(cur),
+            Strategy::TopN(top) => Self::top_n(cur, top),
         }
     }
 }
@@ -187,8 +401,16 @@ impl Strategy {
         }
     }
 
-    fn last_write_wins(cur: Cursor) -> Result<Value> {
+    fn last_write_wins(cur: Cursor, lww: &LastWriteWins) -> Result<Value> {
         match cur {
+            // Keep a LHS having a greater version than the RHS.
+            Cursor::Both { tape, lhs, rhs, .. }
+                if !lww.key.is_empty()
+                    && json_cmp_at(&lww.key, &lhs, &rhs) == Ordering::Greater =>
+            {
+                *tape = &tape[count_nodes(&rhs)..];
+                Ok(lhs)
+            }
             Cursor::Right { tape, rhs, .. } | Cursor::Both { tape, rhs, .. } => {
                 *tape = &tape[count_nodes(&rhs)..];
                 Ok(rhs)
@@ -282,6 +504,36 @@ impl Strategy {
         }
     }
 
+    fn sum_exact(cur: Cursor) -> Result<Value> {
+        fn decimal(v: &Value) -> Option<Decimal> {
+            match v {
+                Value::Number(n) => Decimal::try_from(Number::from(n)).ok(),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            }
+        }
+
+        let sum = match &cur {
+            Cursor::Both { lhs, rhs, .. } => match (decimal(lhs), decimal(rhs)) {
+                (Some(lhs), Some(rhs)) => Some(lhs + rhs),
+                _ => None,
+            },
+            Cursor::Right { rhs, .. } => decimal(rhs),
+        };
+        let sum = match sum {
+            Some(sum) => sum,
+            None => return Err(Error::cursor(cur, Error::SumWrongType)),
+        };
+
+        match cur {
+            Cursor::Both { tape, .. } | Cursor::Right { tape, .. } => *tape = &tape[1..],
+        }
+        match sum.to_number() {
+            Some(n) => Ok(Value::try_from(n).unwrap()),
+            None => Ok(Value::String(sum.to_string())),
+        }
+    }
+
     fn merge_with_key(key: &[String], cur: Cursor) -> Result<Value> {
         match cur {
             // Merge of Object <= Object.
@@ -390,6 +642,57 @@ impl Strategy {
     fn merge(cur: Cursor, merge: &Merge) -> Result<Value> {
         Self::merge_with_key(&merge.key, cur)
     }
+
+    fn top_n(cur: Cursor, top: &TopN) -> Result<Value> {
+        let (tape, loc, prune, lhs, rhs) = match cur {
+            Cursor::Both {
+                tape,
+                loc,
+                prune,
+                lhs: Value::Array(lhs),
+                rhs: Value::Array(rhs),
+            } => (tape, loc, prune, lhs, rhs),
+            Cursor::Right {
+                tape,
+                loc,
+                prune,
+                rhs: Value::Array(rhs),
+            } => (tape, loc, prune, Vec::new(), rhs),
+
+            cur => return Err(Error::cursor(cur, Error::TopNWrongType)),
+        };
+
+        *tape = &tape[1..]; // Consume array container.
+
+        let cmp = |lhs: &Value, rhs: &Value| -> Ordering {
+            let ord = if top.key.is_empty() {
+                json_cmp(lhs, rhs)
+            } else {
+                json_cmp_at(&top.key, lhs, rhs)
+            };
+            if top.bottom {
+                ord
+            } else {
+                ord.reverse()
+            }
+        };
+
+        let mut items = Vec::with_capacity(top.limit.min(lhs.len() + rhs.len()));
+        for eob in itertools::merge_join_by(
+            lhs.into_iter().enumerate(),
+            rhs.into_iter().enumerate(),
+            |(_, lhs), (_, rhs)| cmp(lhs, rhs),
+        ) {
+            if items.len() < top.limit {
+                items.push(reduce_item(tape, loc, prune, eob)?);
+            } else if let EitherOrBoth::Right((_, rhs)) | EitherOrBoth::Both(_, (_, rhs)) = eob {
+                // Items beyond the limit are discarded, but their annotations must still be
+                // consumed from the tape.
+                *tape = &tape[count_nodes(&rhs)..];
+            }
+        }
+        Ok(Value::Array(items))
+    }
 }
 
 #[cfg(test)]
@@ -461,6 +764,46 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_last_write_wins_with_version() {
+        run_reduce_cases(
+            json!({
+                "properties": {
+                    "n": {"reduce": {"strategy": "sum"}}
+                },
+                "reduce": {
+                    "strategy": "lastWriteWins",
+                    "key": ["/v"],
+                },
+            }),
+            vec![
+                Partial {
+                    rhs: json!({"v": 2, "n": 1}),
+                    expect: Ok(json!({"v": 2, "n": 1})),
+                },
+                // An out-of-order RHS having a lesser version is ignored.
+                Partial {
+                    rhs: json!({"v": 1, "n": 10}),
+                    expect: Ok(json!({"v": 2, "n": 1})),
+                },
+                // Equal versions take the RHS, without a deep merge.
+                Partial {
+                    rhs: json!({"v": 2, "n": 2}),
+                    expect: Ok(json!({"v": 2, "n": 2})),
+                },
+                Partial {
+                    rhs: json!({"v": 5}),
+                    expect: Ok(json!({"v": 5})),
+                },
+                // A missing version is ordered before any other.
+                Partial {
+                    rhs: json!({"n": 3}),
+                    expect: Ok(json!({"v": 5})),
+                },
+            ],
+        )
+    }
+
     #[test]
     fn test_first_write_wins() {
         run_reduce_cases(
@@ -643,6 +986,86 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_top_n_with_deep_merge() {
+        run_reduce_cases(
+            json!({
+                "items": {
+                    "properties": {
+                        "count": {"reduce": {"strategy": "sum"}},
+                    },
+                    "reduce": {"strategy": "merge"},
+                },
+                "reduce": {
+                    "strategy": "topN",
+                    "key": ["/ts"],
+                    "limit": 3,
+                },
+            }),
+            vec![
+                Partial {
+                    rhs: json!([{"ts": 5, "count": 1}]),
+                    expect: Ok(json!([{"ts": 5, "count": 1}])),
+                },
+                Partial {
+                    rhs: json!([{"ts": 3, "count": 1}]),
+                    expect: Ok(json!([{"ts": 5, "count": 1}, {"ts": 3, "count": 1}])),
+                },
+                // Equal keys are deeply merged.
+                Partial {
+                    rhs: json!([{"ts": 7, "count": 1}, {"ts": 5, "count": 2}]),
+                    expect: Ok(json!([
+                        {"ts": 7, "count": 1},
+                        {"ts": 5, "count": 3},
+                        {"ts": 3, "count": 1},
+                    ])),
+                },
+                // Items beyond the limit are dropped.
+                Partial {
+                    rhs: json!([{"ts": 4, "count": 1}]),
+                    expect: Ok(json!([
+                        {"ts": 7, "count": 1},
+                        {"ts": 5, "count": 3},
+                        {"ts": 4, "count": 1},
+                    ])),
+                },
+                Partial {
+                    rhs: json!([{"ts": 1, "count": 1}]),
+                    expect: Ok(json!([
+                        {"ts": 7, "count": 1},
+                        {"ts": 5, "count": 3},
+                        {"ts": 4, "count": 1},
+                    ])),
+                },
+                Partial {
+                    rhs: json!({"ts": 9}),
+                    expect: Err(Error::TopNWrongType),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_bottom_n() {
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "topN", "limit": 2, "bottom": true } }),
+            vec![
+                Partial {
+                    rhs: json!([3]),
+                    expect: Ok(json!([3])),
+                },
+                Partial {
+                    rhs: json!([1, 5]),
+                    expect: Ok(json!([1, 3])),
+                },
+                Partial {
+                    rhs: json!([0, 3]),
+                    expect: Ok(json!([0, 1])),
+                },
+            ],
+        )
+    }
+
     #[test]
     fn test_sum() {
         run_reduce_cases(
@@ -716,6 +1139,57 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_sum_exact() {
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "sum", "mode": "exact" } }),
+            vec![
+                // Non-numeric string (without LHS) returns an error.
+                Partial {
+                    rhs: json!("whoops"),
+                    expect: Err(Error::SumWrongType),
+                },
+                // Numeric strings are normalized.
+                Partial {
+                    rhs: json!("0.10"),
+                    expect: Ok(json!(0.1)),
+                },
+                // Floats are summed exactly.
+                Partial {
+                    rhs: json!(0.2),
+                    expect: Ok(json!(0.3)),
+                },
+                // Sums beyond the safe integer range become strings.
+                Partial {
+                    rhs: json!(u64::MAX),
+                    expect: Ok(json!("18446744073709551615.3")),
+                },
+                Partial {
+                    rhs: json!("18446744073709551615.7"),
+                    expect: Ok(json!("36893488147419103231")),
+                },
+                // And return to numbers once back in range.
+                Partial {
+                    rhs: json!("-36893488147419103230"),
+                    expect: Ok(json!(1)),
+                },
+                Partial {
+                    rhs: json!("-1.5e1"),
+                    expect: Ok(json!(-14)),
+                },
+                // Sums which can't be exactly represented as f64 are strings.
+                Partial {
+                    rhs: json!("0.00000000000000000001"),
+                    expect: Ok(json!("-13.99999999999999999999")),
+                },
+                Partial {
+                    rhs: json!(true),
+                    expect: Err(Error::SumWrongType),
+                },
+            ],
+        );
+    }
+
     #[test]
     fn test_merge_array_in_place() {
         run_reduce_cases(
@@ -1,4 +1,4 @@
-use derive::combiner::Combiner;
+use derive::combiner::{Combiner, DrainedEntry};
 use doc::{ptr::Pointer, SchemaIndex, SchemaIndexBuilder, Validator};
 use futures::future::LocalBoxFuture;
 use models::tables::SchemaDoc;
@@ -13,6 +13,9 @@ pub struct CombineArgs {
     /// Maximum number of documents to add to the combiner before draining it. If 0, then there is no maximum
     #[clap(long, default_value = "0")]
     max_docs: u64,
+    /// Validate, rather than only annotate, known JSON schema formats of combined documents
+    #[clap(long)]
+    assert_formats: bool,
 }
 
 /// How to get the schema and key
@@ -40,12 +43,14 @@ pub fn run(
     CombineArgs {
         build_source,
         max_docs,
+        assert_formats,
     }: CombineArgs,
 ) -> Result<(), anyhow::Error> {
     let (index, schema_url, key_pointers) = get_indexed_schemas_and_key(build_source)?;
 
     let mut combiner = Combiner::new(schema_url, key_pointers.into());
     let mut validator = Validator::new(&index);
+    validator.set_assert_formats(assert_formats);
 
     let sin = io::stdin();
     let stdin_locked = sin.lock();
@@ -94,7 +99,7 @@ fn drain_combiner(
     let mut bytes = 0u64;
 
     let mut line_buf = Vec::with_capacity(4096);
-    for (doc, _) in combiner.drain_entries("") {
+    for DrainedEntry { doc, .. } in combiner.drain_entries("") {
         line_buf.clear();
         serde_json::to_writer(&mut line_buf, &doc)?;
         docs += 1;
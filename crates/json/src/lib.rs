@@ -2,7 +2,7 @@ use std::fmt::{self, Write};
 
 use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 mod number;
-pub use number::Number;
+pub use number::{Decimal, Number};
 
 mod compare;
 pub use compare::{json_cmp, json_cmp_at};
@@ -87,6 +87,16 @@ impl PartialEq for Number {
 }
 impl Eq for Number {}
 
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unsigned(n) => write!(f, "{}", n),
+            Signed(n) => write!(f, "{}", n),
+            Float(n) => write!(f, "{}", n),
+        }
+    }
+}
+
 impl Number {
     pub fn checked_add(self: Self, other: Self) -> Option<Self> {
         match (self, other) {
@@ -135,6 +145,242 @@ impl Number {
     }
 }
 
+/// `Decimal` is an exact decimal number of arbitrary precision.
+/// It's used where the fixed-width representations of `Number`
+/// would overflow or lose precision, such as when summing currency.
+///
+/// A `Decimal` is always normalized, having no leading zeros or trailing
+/// fractional zeros, so that equal values are also structurally equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decimal {
+    negative: bool,
+    // Base-10 digits of the magnitude, least-significant first.
+    digits: Vec<u8>,
+    // Number of `digits` which follow the decimal point.
+    scale: usize,
+}
+
+impl Decimal {
+    /// Exponents of parsed numbers are bounded, so that a short input
+    /// can't expand into an unreasonably large number of digits.
+    pub const MAX_EXPONENT: i64 = 4096;
+
+    /// Largest integer magnitude which is exactly representable by an f64,
+    /// and is thus safe for use by JSON parsers in most languages.
+    pub const MAX_SAFE_INTEGER: u64 = (1 << f64::MANTISSA_DIGITS) - 1;
+
+    fn normalize(mut self) -> Self {
+        let trailing = self
+            .digits
+            .iter()
+            .take(self.scale)
+            .take_while(|d| **d == 0)
+            .count();
+        self.digits.drain(..trailing);
+        self.scale -= trailing;
+
+        while self.digits.len() > self.scale && self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        if self.digits.iter().all(|d| *d == 0) {
+            self.digits.clear();
+            self.scale = 0;
+            self.negative = false;
+        }
+        self
+    }
+
+    /// Map this Decimal into an equal Number, if it can be represented
+    /// without loss of precision: either as an integer having a magnitude
+    /// of at most `MAX_SAFE_INTEGER`, or as an f64 which exactly
+    /// round-trips to this Decimal.
+    pub fn to_number(&self) -> Option<Number> {
+        if self.scale == 0 {
+            let magnitude = self
+                .digits
+                .iter()
+                .rev()
+                .try_fold(0u64, |m, d| m.checked_mul(10)?.checked_add(*d as u64))
+                .filter(|m| *m <= Self::MAX_SAFE_INTEGER)?;
+
+            if self.negative {
+                Some(Signed(-(magnitude as i64)))
+            } else {
+                Some(Unsigned(magnitude))
+            }
+        } else {
+            let f = self.to_string().parse::<f64>().ok()?;
+
+            match Decimal::try_from(Float(f)) {
+                Ok(d) if &d == self => Some(Float(f)),
+                _ => None,
+            }
+        }
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        // Scales are aligned, and digits have no leading zeros.
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+
+    fn with_scale(&self, scale: usize) -> Self {
+        let mut digits = vec![0; scale - self.scale];
+        digits.extend_from_slice(&self.digits);
+
+        Self {
+            negative: self.negative,
+            digits,
+            scale,
+        }
+    }
+}
+
+impl std::str::FromStr for Decimal {
+    type Err = ();
+
+    /// Parse a Decimal from the syntax of a JSON number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (mantissa, exponent) = match s.find(['e', 'E'].as_ref()) {
+            Some(ind) => (&s[..ind], s[ind + 1..].parse::<i64>().map_err(|_| ())?),
+            None => (s, 0),
+        };
+        let (integral, fractional) = match mantissa.split_once('.') {
+            Some((integral, fractional)) if !fractional.is_empty() => (integral, fractional),
+            Some(_) => return Err(()),
+            None => (mantissa, ""),
+        };
+        if integral.is_empty()
+            || exponent.unsigned_abs() > Self::MAX_EXPONENT as u64
+            || !integral
+                .bytes()
+                .chain(fractional.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(());
+        }
+
+        let mut digits: Vec<u8> = integral
+            .bytes()
+            .chain(fractional.bytes())
+            .rev()
+            .map(|b| b - b'0')
+            .collect();
+
+        let scale = fractional.len() as i64 - exponent;
+        let scale = if scale < 0 {
+            digits.splice(0..0, std::iter::repeat(0).take(-scale as usize));
+            0
+        } else {
+            scale as usize
+        };
+
+        Ok(Self {
+            negative,
+            digits,
+            scale,
+        }
+        .normalize())
+    }
+}
+
+impl TryFrom<Number> for Decimal {
+    type Error = ();
+
+    /// Floats map to the Decimal of their shortest representation which
+    /// round-trips to the same f64. For example, 0.1 maps to exactly 0.1.
+    fn try_from(n: Number) -> Result<Self, Self::Error> {
+        match n {
+            Unsigned(n) => n.to_string().parse(),
+            Signed(n) => n.to_string().parse(),
+            Float(n) if n.is_finite() => n.to_string().parse(),
+            Float(_) => Err(()),
+        }
+    }
+}
+
+impl std::ops::Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Decimal) -> Decimal {
+        let scale = self.scale.max(other.scale);
+        let (lhs, rhs) = (self.with_scale(scale), other.with_scale(scale));
+
+        let (negative, digits) = if lhs.negative == rhs.negative {
+            let mut digits = Vec::with_capacity(lhs.digits.len().max(rhs.digits.len()) + 1);
+            let mut carry = 0;
+
+            for ind in 0..lhs.digits.len().max(rhs.digits.len()) {
+                let sum =
+                    lhs.digits.get(ind).unwrap_or(&0) + rhs.digits.get(ind).unwrap_or(&0) + carry;
+                digits.push(sum % 10);
+                carry = sum / 10;
+            }
+            if carry != 0 {
+                digits.push(carry);
+            }
+            (lhs.negative, digits)
+        } else {
+            // Subtract the smaller magnitude from the larger.
+            let (large, small) = match lhs.cmp_magnitude(&rhs) {
+                Ordering::Less => (rhs, lhs),
+                _ => (lhs, rhs),
+            };
+            let mut digits = Vec::with_capacity(large.digits.len());
+            let mut borrow = 0;
+
+            for (ind, d) in large.digits.iter().enumerate() {
+                let sub = small.digits.get(ind).unwrap_or(&0) + borrow;
+                if *d >= sub {
+                    digits.push(d - sub);
+                    borrow = 0;
+                } else {
+                    digits.push(d + 10 - sub);
+                    borrow = 1;
+                }
+            }
+            (large.negative, digits)
+        };
+
+        Self {
+            negative,
+            digits,
+            scale,
+        }
+        .normalize()
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        let integral = self.digits.len().saturating_sub(self.scale);
+
+        if integral == 0 {
+            f.write_str("0")?;
+        }
+        for (ind, d) in self.digits.iter().rev().enumerate() {
+            if ind == integral {
+                f.write_str(".")?;
+
+                for _ in self.digits.len()..self.scale {
+                    f.write_str("0")?;
+                }
+            }
+            write!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
 fn f64_cmp(lhs: &f64, rhs: &f64) -> Ordering {
     lhs.partial_cmp(rhs).unwrap_or_else(|| {
         if lhs.is_nan() && rhs.is_nan() {
@@ -320,6 +566,78 @@ mod test {
         assert_eq!(Float(f64::MAX).checked_add(Float(f64::MAX / 2.0)), None);
     }
 
+    #[test]
+    fn test_decimal_parse_and_display() {
+        for (input, expect) in &[
+            ("0", "0"),
+            ("-0.000", "0"),
+            ("007", "7"),
+            ("12.3400", "12.34"),
+            ("-0.0012", "-0.0012"),
+            ("1.5e3", "1500"),
+            ("-15E-3", "-0.015"),
+            ("1e+2", "100"),
+            (
+                "123456789012345678901234567890.5",
+                "123456789012345678901234567890.5",
+            ),
+        ] {
+            let d: Decimal = input.parse().unwrap();
+            assert_eq!(&d.to_string(), expect, "input {}", input);
+        }
+        for input in &[
+            "", "-", "1.", ".5", "1e", "1.2.3", "0x10", "1e99999", "--1", "NaN",
+        ] {
+            assert_eq!(input.parse::<Decimal>(), Err(()), "input {}", input);
+        }
+        // An exponent of i64::MIN is rejected, rather than overflowing its absolute value.
+        assert_eq!("1e-9223372036854775808".parse::<Decimal>(), Err(()));
+
+        // Floats map to their shortest, round-trip representation.
+        let d = Decimal::try_from(Float(0.1)).unwrap();
+        assert_eq!(d.to_string(), "0.1");
+        assert!(Decimal::try_from(Float(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_decimal_add() {
+        fn add(lhs: &str, rhs: &str) -> String {
+            let (lhs, rhs): (Decimal, Decimal) = (lhs.parse().unwrap(), rhs.parse().unwrap());
+            (lhs + rhs).to_string()
+        }
+        assert_eq!(add("0.1", "0.2"), "0.3");
+        assert_eq!(add("999.99", "0.01"), "1000");
+        assert_eq!(add("-5", "3.25"), "-1.75");
+        assert_eq!(add("5", "-5.00"), "0");
+        assert_eq!(add("-0.5", "-0.75"), "-1.25");
+        assert_eq!(add("100", "-99.999"), "0.001");
+        assert_eq!(
+            add("18446744073709551615", "18446744073709551615"),
+            "36893488147419103230"
+        );
+    }
+
+    #[test]
+    fn test_decimal_to_number() {
+        fn to_number(s: &str) -> Option<Number> {
+            s.parse::<Decimal>().unwrap().to_number()
+        }
+        assert_eq!(to_number("42"), Some(Unsigned(42)));
+        assert_eq!(to_number("-42"), Some(Signed(-42)));
+        assert_eq!(
+            to_number("9007199254740991"),
+            Some(Unsigned(Decimal::MAX_SAFE_INTEGER))
+        );
+        assert_eq!(to_number("0.3"), Some(Float(0.3)));
+        assert_eq!(to_number("-1.75"), Some(Float(-1.75)));
+
+        // Values which would lose precision aren't mapped.
+        assert_eq!(to_number("9007199254740992"), None);
+        assert_eq!(to_number("-18446744073709551616"), None);
+        assert_eq!(to_number("0.10000000000000000001"), None);
+        assert_eq!(to_number("12345678901234567.5"), None);
+    }
+
     fn is_lt(lhs: Number, rhs: Number) {
         assert_eq!(lhs.cmp(&rhs), Ordering::Less);
         assert_eq!(rhs.cmp(&lhs), Ordering::Greater);
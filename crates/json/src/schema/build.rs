@@ -1,6 +1,6 @@
 use crate::schema::{
-    intern, keywords, types, Annotation, Application, CoreAnnotation, HashedLiteral, Keyword,
-    Schema, Validation,
+    formats, intern, keywords, types, Annotation, Application, CoreAnnotation, Draft,
+    HashedLiteral, Keyword, Schema, Validation,
 };
 use crate::{de, NoopWalker, Number};
 use fancy_regex as regex;
@@ -77,6 +77,8 @@ where
     curi: url::Url,
     kw: Vec<Keyword<A>>,
     tbl: intern::Table,
+    // Draft of the specification which this schema is built under.
+    draft: Draft,
 
     // "nullable" support for OpenAPI schemas prior to version 3.1,
     // which are still prevelant as of Sept 2021.
@@ -92,7 +94,7 @@ where
         // semantics of {"minContains": 1}, if a MinContains validation is not
         // otherwise specified.
         let (has_contains, has_min) = self.kw.iter().fold((false, false), |(c, m), kw| match kw {
-            Keyword::Application(Application::Contains, _) => (true, m),
+            Keyword::Application(Application::Contains { .. }, _) => (true, m),
             Keyword::Validation(Validation::MinContains(_)) => (c, true),
             _ => (c, m),
         });
@@ -126,18 +128,20 @@ where
                 K::Application(A::AdditionalProperties, _) => 4,
                 // UnevaluatedProperties is evaluated last.
 
-                // Contains is always applied. Items conditions whether AdditionalItems applies.
-                K::Application(A::Contains, _) => 5,
-                K::Application(A::Items { .. }, _) => 6,
+                // Contains is always applied. PrefixItems conditions whether Items applies,
+                // and Items conditions whether AdditionalItems applies.
+                K::Application(A::Contains { .. }, _) => 5,
+                K::Application(A::PrefixItems { .. }, _) => 6,
+                K::Application(A::Items { .. }, _) => 7,
                 // AdditionalItems also conditions whether UnevaluatedItems applies.
-                K::Application(A::AdditionalItems, _) => 7,
+                K::Application(A::AdditionalItems, _) => 8,
                 // UnevaluatedItems is evaluated last.
 
                 // When unwinding applications, we want to know which branch was taken before
                 // we examine branch results.
-                K::Application(A::Else, _) => 8,
-                K::Application(A::Then, _) => 9,
-                K::Application(A::If, _) => 10,
+                K::Application(A::Else, _) => 9,
+                K::Application(A::Then, _) => 10,
+                K::Application(A::If, _) => 11,
 
                 _ => 100,
             }
@@ -163,7 +167,7 @@ where
             keywords::NULLABLE => (),
 
             // Meta keywords.
-            keywords::RECURSIVE_ANCHOR => match v {
+            keywords::RECURSIVE_ANCHOR if self.draft == Draft::Draft2019_09 => match v {
                 sj::Value::Bool(b) if *b => self.kw.push(Keyword::RecursiveAnchor),
                 sj::Value::Bool(b) if !*b => (), // Ignore.
                 _ => return Err(ExpectedBool),
@@ -175,6 +179,13 @@ where
                 }
                 _ => return Err(ExpectedString),
             },
+            keywords::DYNAMIC_ANCHOR if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::String(anchor) => {
+                    let anchor = self.curi.join(&format!("#{}", anchor))?;
+                    self.kw.push(Keyword::DynamicAnchor(anchor))
+                }
+                _ => return Err(ExpectedString),
+            },
             keywords::DEF => match v {
                 sj::Value::Object(m) => {
                     for (prop, child) in m {
@@ -203,7 +214,7 @@ where
                 }
                 _ => return Err(ExpectedString),
             },
-            keywords::RECURSIVE_REF => match v {
+            keywords::RECURSIVE_REF if self.draft == Draft::Draft2019_09 => match v {
                 sj::Value::String(ref_uri) => {
                     // Assert |ref_uri| parses correctly when joined with a base URL.
                     url::Url::parse("http://example")?.join(ref_uri)?;
@@ -211,6 +222,16 @@ where
                 }
                 _ => return Err(ExpectedString),
             },
+            keywords::DYNAMIC_REF if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::String(ref_uri) => {
+                    let mut ref_uri = self.curi.join(ref_uri)?;
+                    if let Some("") = ref_uri.fragment() {
+                        ref_uri.set_fragment(None);
+                    }
+                    self.add_application(App::DynamicRef(ref_uri), &true_placeholder)?;
+                }
+                _ => return Err(ExpectedString),
+            },
             keywords::ANY_OF => match v {
                 sj::Value::Array(children) => {
                     for (i, child) in children.iter().enumerate() {
@@ -287,19 +308,37 @@ where
             }
 
             // Item application keywords.
-            keywords::CONTAINS => self.add_application(App::Contains, v)?,
-            keywords::ITEMS => match v {
-                sj::Value::Object(_) | sj::Value::Bool(_) => {
+            // Draft 2020-12 replaced an array of "items" with "prefixItems",
+            // and "additionalItems" with "items" having a single schema.
+            keywords::CONTAINS => self.add_application(
+                App::Contains {
+                    evaluates: self.draft == Draft::Draft2020_12,
+                },
+                v,
+            )?,
+            keywords::ITEMS => match (self.draft, v) {
+                (_, sj::Value::Object(_)) | (_, sj::Value::Bool(_)) => {
                     self.add_application(App::Items { index: None }, v)?
                 }
-                sj::Value::Array(vec) => {
+                (Draft::Draft2019_09, sj::Value::Array(vec)) => {
                     for (i, child) in vec.iter().enumerate() {
                         self.add_application(App::Items { index: Some(i) }, child)?;
                     }
                 }
-                _ => return Err(ExpectedSchemaOrArrayOfSchemas),
+                (Draft::Draft2019_09, _) => return Err(ExpectedSchemaOrArrayOfSchemas),
+                (Draft::Draft2020_12, _) => return Err(ExpectedSchema),
             },
-            keywords::ADDITIONAL_ITEMS => self.add_application(App::AdditionalItems, v)?,
+            keywords::PREFIX_ITEMS if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::Array(vec) => {
+                    for (i, child) in vec.iter().enumerate() {
+                        self.add_application(App::PrefixItems { index: i }, child)?;
+                    }
+                }
+                _ => return Err(ExpectedArray),
+            },
+            keywords::ADDITIONAL_ITEMS if self.draft == Draft::Draft2019_09 => {
+                self.add_application(App::AdditionalItems, v)?
+            }
             keywords::UNEVALUATED_ITEMS => self.add_application(App::UnevaluatedItems, v)?,
 
             // Common validation keywords.
@@ -326,6 +365,12 @@ where
             keywords::PATTERN => {
                 self.add_validation(Val::Pattern(regex::Regex::new(extract_str(v)?)?))
             }
+            // Unknown formats are permitted, and are collected only as annotations.
+            keywords::FORMAT => {
+                if let Some(format) = formats::Format::parse(extract_str(v)?) {
+                    self.add_validation(Val::Format(format))
+                }
+            }
 
             // Number-specific validation keywords.
             keywords::MULTIPLE_OF => self.add_validation(Val::MultipleOf(extract_number(v)?)),
@@ -378,6 +423,16 @@ where
 
             keywords::SCHEMA | keywords::VOCABULARY | keywords::COMMENT => (), // Ignored.
 
+            // Keywords of the draft which this schema *isn't* built under fall through
+            // the draft-gated arms above. They're ignored rather than rejected,
+            // as the other draft's keywords are unknown annotations of this one.
+            keywords::RECURSIVE_ANCHOR
+            | keywords::RECURSIVE_REF
+            | keywords::ADDITIONAL_ITEMS
+            | keywords::DYNAMIC_ANCHOR
+            | keywords::DYNAMIC_REF
+            | keywords::PREFIX_ITEMS => (),
+
             // This is not a core validation keyword. Does the AnnotationBuilder consume it?
             _ => {
                 unknown = true;
@@ -416,7 +471,7 @@ where
         // Note that it could still override with it's own $id keyword.
         let child_uri = self.curi.join(ptr.as_str()).unwrap();
 
-        let child = build_schema(child_uri, child)?;
+        let child = build_draft_schema(child_uri, child, self.draft)?;
         self.kw.push(Keyword::Application(app, child));
 
         Ok(())
@@ -424,7 +479,22 @@ where
 }
 
 /// `build_schema` builds a Schema instance from a JSON-Schema document.
+/// The document's "$schema" keyword selects the Draft it's built under,
+/// and otherwise the default Draft is used.
 pub fn build_schema<A>(curi: url::Url, v: &sj::Value) -> Result<Schema<A>, Error>
+where
+    A: AnnotationBuilder,
+{
+    build_draft_schema(curi, v, Draft::default())
+}
+
+/// `build_draft_schema` builds a Schema instance from a JSON-Schema document
+/// under the given Draft, unless the document's "$schema" keyword selects another.
+pub fn build_draft_schema<A>(
+    curi: url::Url,
+    v: &sj::Value,
+    draft: Draft,
+) -> Result<Schema<A>, Error>
 where
     A: AnnotationBuilder,
 {
@@ -458,6 +528,11 @@ where
         curi: build_curi(curi, obj.get(keywords::ID))?,
         kw,
         tbl,
+        draft: obj
+            .get(keywords::SCHEMA)
+            .and_then(|s| s.as_str())
+            .and_then(Draft::from_meta_schema)
+            .unwrap_or(draft),
         nullable: obj
             .get(keywords::NULLABLE)
             .and_then(|n| n.as_bool())
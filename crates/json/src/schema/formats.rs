@@ -0,0 +1,514 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Format is a "format" keyword which the validator knows how to assert.
+/// Formats not enumerated here are collected only as annotations.
+/// C.f. https://json-schema.org/draft/2019-09/json-schema-validation.html#rfc.section.7.3
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    DateTime,
+    Date,
+    Time,
+    Duration,
+    Email,
+    Hostname,
+    Ipv4,
+    Ipv6,
+    Uri,
+    Uuid,
+    Regex,
+}
+
+impl Format {
+    /// Parse a Format from its keyword value, returning None if the format is not known.
+    pub fn parse(name: &str) -> Option<Format> {
+        use Format::*;
+
+        Some(match name {
+            "date-time" => DateTime,
+            "date" => Date,
+            "time" => Time,
+            "duration" => Duration,
+            "email" => Email,
+            "hostname" => Hostname,
+            "ipv4" => Ipv4,
+            "ipv6" => Ipv6,
+            "uri" => Uri,
+            "uuid" => Uuid,
+            "regex" => Regex,
+            _ => return None,
+        })
+    }
+
+    /// Returns the keyword value of this Format.
+    pub fn as_str(&self) -> &'static str {
+        use Format::*;
+
+        match self {
+            DateTime => "date-time",
+            Date => "date",
+            Time => "time",
+            Duration => "duration",
+            Email => "email",
+            Hostname => "hostname",
+            Ipv4 => "ipv4",
+            Ipv6 => "ipv6",
+            Uri => "uri",
+            Uuid => "uuid",
+            Regex => "regex",
+        }
+    }
+
+    /// Validate returns true if the string is a valid instance of this Format.
+    pub fn validate(&self, s: &str) -> bool {
+        use Format::*;
+
+        match self {
+            DateTime => is_date_time(s),
+            Date => matches!(parse_date(s.as_bytes()), Some([])),
+            Time => matches!(parse_time(s.as_bytes()), Some([])),
+            Duration => is_duration(s),
+            Email => is_email(s),
+            Hostname => is_hostname(s),
+            Ipv4 => s.parse::<Ipv4Addr>().is_ok(),
+            Ipv6 => s.parse::<Ipv6Addr>().is_ok(),
+            Uri => is_uri(s),
+            Uuid => is_uuid(s),
+            Regex => is_regex(s),
+        }
+    }
+}
+
+// Returns true if |s| compiles as a regular expression. Compiling is relatively
+// expensive and the same pattern is often validated repeatedly (for example, a
+// fixed pattern in each of many documents), so outcomes are cached per-thread.
+fn is_regex(s: &str) -> bool {
+    // Bound the cache to keep memory in check if instances are highly variable.
+    const MAX_CACHED: usize = 1024;
+
+    thread_local! {
+        static CACHE: RefCell<HashMap<String, bool>> = RefCell::new(HashMap::new());
+    }
+
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        if let Some(valid) = cache.get(s) {
+            return *valid;
+        }
+        let valid = fancy_regex::Regex::new(s).is_ok();
+
+        if cache.len() >= MAX_CACHED {
+            cache.clear();
+        }
+        cache.insert(s.to_string(), valid);
+
+        valid
+    })
+}
+
+// Take exactly |n| ASCII digits from the front of |s|, returning their value and the remainder.
+fn take_digits(s: &[u8], n: usize) -> Option<(u32, &[u8])> {
+    if s.len() < n || !s[..n].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let value = s[..n].iter().fold(0, |acc, b| acc * 10 + (b - b'0') as u32);
+
+    Some((value, &s[n..]))
+}
+
+// Take the byte |expect| from the front of |s|, returning the remainder.
+fn take_byte(s: &[u8], expect: u8) -> Option<&[u8]> {
+    match s.split_first() {
+        Some((b, rest)) if *b == expect => Some(rest),
+        _ => None,
+    }
+}
+
+// Parse a RFC 3339 "full-date" from the front of |s|, returning the remainder.
+fn parse_date(s: &[u8]) -> Option<&[u8]> {
+    let (year, s) = take_digits(s, 4)?;
+    let s = take_byte(s, b'-')?;
+    let (month, s) = take_digits(s, 2)?;
+    let s = take_byte(s, b'-')?;
+    let (day, s) = take_digits(s, 2)?;
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+
+    if day == 0 || day > days {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+// Parse a RFC 3339 "full-time" from the front of |s|, returning the remainder.
+fn parse_time(s: &[u8]) -> Option<&[u8]> {
+    let (hour, s) = take_digits(s, 2)?;
+    let s = take_byte(s, b':')?;
+    let (minute, s) = take_digits(s, 2)?;
+    let s = take_byte(s, b':')?;
+    let (second, s) = take_digits(s, 2)?;
+
+    // Optional fractional seconds.
+    let s = match s.split_first() {
+        Some((b'.', rest)) => match rest.iter().take_while(|b| b.is_ascii_digit()).count() {
+            0 => return None,
+            n => &rest[n..],
+        },
+        _ => s,
+    };
+
+    // Offset from UTC, in minutes.
+    let (offset, s) = match s.split_first()? {
+        (b'Z' | b'z', rest) => (0, rest),
+        (sign @ (b'+' | b'-'), rest) => {
+            let (off_hour, rest) = take_digits(rest, 2)?;
+            let rest = take_byte(rest, b':')?;
+            let (off_minute, rest) = take_digits(rest, 2)?;
+
+            if off_hour > 23 || off_minute > 59 {
+                return None;
+            }
+            let offset = (off_hour * 60 + off_minute) as i32;
+            (if *sign == b'+' { offset } else { -offset }, rest)
+        }
+        _ => return None,
+    };
+
+    // Leap seconds may only occur within the last minute of a UTC day.
+    let utc_minute = ((hour * 60 + minute) as i32 - offset).rem_euclid(24 * 60);
+
+    if hour > 23 || minute > 59 || second > 60 || (second == 60 && utc_minute != 23 * 60 + 59) {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+fn is_date_time(s: &str) -> bool {
+    let rest = match parse_date(s.as_bytes()) {
+        Some([b'T' | b't', rest @ ..]) => rest,
+        _ => return false,
+    };
+    matches!(parse_time(rest), Some([]))
+}
+
+// Durations are as defined by RFC 3339 Appendix A, such as "P1Y2M3DT4H5M6S" or "P3W".
+fn is_duration(s: &str) -> bool {
+    let rest = match s.strip_prefix('P') {
+        Some(rest) => rest,
+        None => return false,
+    };
+    if let Some(weeks) = rest.strip_suffix('W') {
+        return !weeks.is_empty() && weeks.bytes().all(|b| b.is_ascii_digit());
+    }
+
+    match rest.split_once('T') {
+        Some((date, time)) => {
+            is_duration_units(date, "YMD") && !time.is_empty() && is_duration_units(time, "HMS")
+        }
+        None => !rest.is_empty() && is_duration_units(rest, "YMD"),
+    }
+}
+
+// Returns true if |s| is a sequence of numbered units, which are a contiguous
+// run of |units| in their given order. For example "1M2D" of units "YMD".
+fn is_duration_units(s: &str, units: &str) -> bool {
+    let mut designators = String::new();
+    let mut digits = 0;
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits += 1;
+        } else if digits != 0 {
+            designators.push(c);
+            digits = 0;
+        } else {
+            return false;
+        }
+    }
+    digits == 0 && units.contains(designators.as_str())
+}
+
+// Hostnames are as defined by RFC 1123 section 2.1.
+fn is_hostname(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 253
+        && s.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+}
+
+// Emails are as defined by RFC 5321 section 4.1.2.
+fn is_email(s: &str) -> bool {
+    let (local, domain) = match s.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let local_ok = match local
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+    {
+        Some(quoted) => {
+            // Quoted local parts hold printable ASCII, with '"' and '\' escaped.
+            let mut escaped = false;
+            quoted.bytes().all(|b| {
+                let ok = (b' '..=b'~').contains(&b) && (escaped || b != b'"');
+                escaped = !escaped && b == b'\\';
+                ok
+            }) && !escaped
+        }
+        None => local.split('.').all(|atom| {
+            !atom.is_empty()
+                && atom
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&b))
+        }),
+    };
+
+    let domain_ok = match domain
+        .strip_prefix('[')
+        .and_then(|literal| literal.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix("IPv6:") {
+            Some(addr) => addr.parse::<Ipv6Addr>().is_ok(),
+            None => literal.parse::<Ipv4Addr>().is_ok(),
+        },
+        None => is_hostname(domain),
+    };
+
+    local.len() <= 64 && local_ok && domain_ok
+}
+
+// URIs are as defined by RFC 3986, and must be absolute.
+fn is_uri(s: &str) -> bool {
+    // The URL parser is lenient, and will percent-encode characters which
+    // may not appear in a URI. Reject them instead.
+    s.bytes()
+        .all(|b| b.is_ascii_graphic() && !b"\"<>\\^`{|}".contains(&b))
+        && url::Url::parse(s).is_ok()
+}
+
+// UUIDs are as defined by RFC 4122, in their hyphenated form.
+fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.bytes().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::Format;
+    use crate::schema::{build, index, CoreAnnotation};
+    use crate::{de, validator};
+    use serde_json::json;
+
+    #[test]
+    fn test_formats_are_asserted_only_if_enabled() {
+        let curi = url::Url::parse("http://example/schema").unwrap();
+        let schema = build::build_schema::<CoreAnnotation>(
+            curi.clone(),
+            &json!({
+                "properties": {
+                    "ts": {"format": "date-time"},
+                    "other": {"format": "not-a-known-format"},
+                }
+            }),
+        )
+        .unwrap();
+
+        let mut index = index::IndexBuilder::new();
+        index.add(&schema).unwrap();
+        let index = index.into_index();
+
+        let mut val = validator::Validator::<CoreAnnotation, validator::SpanContext>::new(&index);
+
+        for (assert_formats, doc, expect_valid) in vec![
+            (false, json!({"ts": "not a timestamp", "other": "x"}), true),
+            (true, json!({"ts": "not a timestamp", "other": "x"}), false),
+            (
+                true,
+                json!({"ts": "2021-10-18T12:34:56Z", "other": "x"}),
+                true,
+            ),
+            // Formats don't apply to non-string types.
+            (true, json!({"ts": 1234}), true),
+        ] {
+            val.set_assert_formats(assert_formats);
+            val.prepare(&curi).unwrap();
+            de::walk(&doc, &mut val).unwrap();
+
+            assert_eq!(!val.invalid(), expect_valid, "{} {}", assert_formats, doc);
+        }
+    }
+
+    #[test]
+    fn test_format_cases() {
+        let cases: &[(&str, &[&str], &[&str])] = &[
+            (
+                "date-time",
+                &[
+                    "1963-06-19T08:30:06.283185Z",
+                    "1963-06-19t08:30:06z",
+                    "1998-12-31T23:59:60Z",
+                    "1998-12-31T15:59:60.123-08:00",
+                    "2020-02-29T00:00:00+05:30",
+                ],
+                &[
+                    "1963-06-19T08:30:06",
+                    "1963-06-19 08:30:06Z",
+                    "1998-12-31T23:58:60Z",
+                    "2021-02-29T00:00:00Z",
+                    "1990-02-31T15:59:60.123-08:00",
+                    "2013-350T01:01:01Z",
+                    "1963-06-19T08:30:06.Z",
+                ],
+            ),
+            (
+                "date",
+                &["1963-06-19", "2000-02-29", "2020-12-31"],
+                &[
+                    "1963-6-19",
+                    "1900-02-29",
+                    "2020-13-01",
+                    "2020-00-10",
+                    "1963-06-19T",
+                ],
+            ),
+            (
+                "time",
+                &["08:30:06Z", "08:30:06.283185+01:00", "23:59:60Z"],
+                &[
+                    "08:30:06",
+                    "24:00:00Z",
+                    "08:60:06Z",
+                    "08:30:06+24:00",
+                    "8:30:06Z",
+                ],
+            ),
+            (
+                "duration",
+                &[
+                    "P4DT12H30M5S",
+                    "P4Y",
+                    "PT36H",
+                    "P1Y2M",
+                    "P1M2D",
+                    "PT1M2S",
+                    "P2W",
+                ],
+                &[
+                    "P",
+                    "PT",
+                    "P1D2H",
+                    "PT1D",
+                    "P2D1Y",
+                    "P1Y2W",
+                    "P1Y1D",
+                    "4DT12H30M5S",
+                    "P1.5D",
+                ],
+            ),
+            (
+                "email",
+                &[
+                    "joe.bloggs@example.com",
+                    "te~st@example.com",
+                    "\"joe bloggs\"@example.com",
+                    "joe@[127.0.0.1]",
+                    "joe@[IPv6:::1]",
+                ],
+                &[
+                    "2962",
+                    ".test@example.com",
+                    "te..st@example.com",
+                    "test.@example.com",
+                    "joe@-example.com",
+                    "\"joe\"bloggs\"@example.com",
+                ],
+            ),
+            (
+                "hostname",
+                &["www.example.com", "xn--4gbwdl.xn--wgbh1c", "a-b.c"],
+                &[
+                    "-a.com",
+                    "a-.com",
+                    "not_a_valid_host",
+                    "a..com",
+                    "a123456789b123456789c123456789d123456789e123456789f123456789abcd.com",
+                ],
+            ),
+            (
+                "ipv4",
+                &["192.168.0.1", "0.0.0.0"],
+                &["127.0.0.0.1", "256.256.256.256", "087.10.0.1", "1"],
+            ),
+            (
+                "ipv6",
+                &["::1", "2001:db8::ff00:42:8329", "::ffff:192.168.0.1"],
+                &["12345::", "::laptop", "1:2:3:4:5:6:7:8:9", "fe80::1%eth0"],
+            ),
+            (
+                "uri",
+                &[
+                    "http://foo.bar/?baz=qux#quux",
+                    "urn:isbn:0451450523",
+                    "mailto:a@b.c",
+                ],
+                &[
+                    "//foo.bar/?baz=qux",
+                    "relative/path",
+                    "http://a b.com/",
+                    "http://x/^",
+                ],
+            ),
+            (
+                "uuid",
+                &[
+                    "2EB8AA08-AA98-11EA-B4AA-73B441D16380",
+                    "00000000-0000-0000-0000-000000000000",
+                ],
+                &[
+                    "2eb8aa08-aa98-11ea-b4aa-73b441d1638",
+                    "2eb8aa08aa9811eab4aa73b441d16380",
+                    "2eb8aa08-aa98-11ea-b4ga-73b441d16380",
+                ],
+            ),
+            (
+                "regex",
+                &["^[a-z]+$", "a(b|c)*d", "(?<=foo)bar", ""],
+                &["(unclosed", "[a-", "a{2,1}", "*a"],
+            ),
+        ];
+
+        for (name, valid, invalid) in cases {
+            let format = Format::parse(name).unwrap();
+
+            for s in valid.iter() {
+                assert!(format.validate(s), "{} should be a valid {}", s, name);
+            }
+            for s in invalid.iter() {
+                assert!(!format.validate(s), "{} should be an invalid {}", s, name);
+            }
+        }
+        assert_eq!(Format::parse("unknown"), None);
+    }
+}
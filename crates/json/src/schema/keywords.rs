@@ -16,6 +16,8 @@ pub const DEPENDENT_REQUIRED: &str = "dependentRequired";
 pub const DEPENDENT_SCHEMAS: &str = "dependentSchemas";
 pub const DEPRECATED: &str = "deprecated";
 pub const DESCRIPTION: &str = "description";
+pub const DYNAMIC_ANCHOR: &str = "$dynamicAnchor";
+pub const DYNAMIC_REF: &str = "$dynamicRef";
 pub const ELSE: &str = "else";
 pub const ENUM: &str = "enum";
 pub const EXAMPLE: &str = "example"; // OpenAPI < 3.1. Merged with "examples".
@@ -42,6 +44,7 @@ pub const NULLABLE: &str = "nullable"; // OpenAPI < 3.1. Merged into "type".
 pub const ONE_OF: &str = "oneOf";
 pub const PATTERN: &str = "pattern";
 pub const PATTERN_PROPERTIES: &str = "patternProperties";
+pub const PREFIX_ITEMS: &str = "prefixItems";
 pub const PROPERTIES: &str = "properties";
 pub const PROPERTY_NAMES: &str = "propertyNames";
 pub const READ_ONLY: &str = "readOnly";
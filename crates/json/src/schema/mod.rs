@@ -3,6 +3,7 @@ use serde_json as sj;
 use std::fmt::Write;
 
 pub mod build;
+pub mod formats;
 pub mod index;
 pub mod intern;
 pub mod keywords;
@@ -10,6 +11,33 @@ pub mod types;
 
 pub use build::Error as BuildError;
 
+/// Draft of the JSON-Schema specification which determines the semantics of
+/// a Schema's keywords. It's selected by the "$schema" keyword, and applies
+/// to the schema and its sub-schemas, unless they select another Draft.
+/// Schemas which don't select a known Draft use draft 2019-09.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Draft {
+    Draft2019_09,
+    Draft2020_12,
+}
+
+impl Draft {
+    /// Map a "$schema" meta-schema URI to its Draft, if known.
+    pub fn from_meta_schema(uri: &str) -> Option<Draft> {
+        match uri.trim_end_matches('#') {
+            "https://json-schema.org/draft/2019-09/schema" => Some(Draft::Draft2019_09),
+            "https://json-schema.org/draft/2020-12/schema" => Some(Draft::Draft2020_12),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Draft {
+    fn default() -> Self {
+        Draft::Draft2019_09
+    }
+}
+
 #[derive(Debug)]
 pub struct Schema<A>
 where
@@ -43,8 +71,8 @@ pub enum CoreAnnotation {
     ContentEncoding(String),
     ContentMediaType(String),
     /// The "format" annotation can be simply informational, or it can optionally be an assertion
-    /// that a string is valid for the given format. We currently only collect and use it as an
-    /// annotation, but we may also use it as an assertion in the future.
+    /// that a string is valid for the given format. It's always collected as an annotation,
+    /// and known formats are also asserted by a Validator which opts in (c.f. Validation::Format).
     /// https://json-schema.org/draft/2019-09/json-schema-validation.html#format
     Format(String),
 }
@@ -68,6 +96,10 @@ where
     // an additional canonical URI, which is computed as the base URI
     // extended with a URI fragment composed of the Anchor string.
     Anchor(url::Url),
+    // $dynamicAnchor keyword is indexed exactly like $anchor, but also
+    // marks this Schema as a target which a $dynamicRef of a sub-schema
+    // of the current *dynamic* scope may resolve to.
+    DynamicAnchor(url::Url),
     // Application of an in-place or child Schema, with respect to this Schema.
     Application(Application, Schema<A>),
     // Validation keyword verified by this Schema.
@@ -93,6 +125,7 @@ pub enum Application {
     // In-place applications.
     Ref(url::Url),
     RecursiveRef(String),
+    DynamicRef(url::Url),
     AnyOf {
         index: usize,
     },
@@ -124,7 +157,14 @@ pub enum Application {
     UnevaluatedProperties,
 
     // Item applications.
-    Contains,
+    // In draft 2020-12, "contains" also evaluates the items which it matches.
+    Contains {
+        evaluates: bool,
+    },
+    // prefixItems of draft 2020-12 is equivalent to an array of "items" in draft 2019-09.
+    PrefixItems {
+        index: usize,
+    },
     Items {
         index: Option<usize>,
     },
@@ -143,6 +183,7 @@ impl Application {
             // In-place keywords.
             Ref(_) => parent.push_prop(keywords::REF),
             RecursiveRef(_) => parent.push_prop(keywords::RECURSIVE_REF),
+            DynamicRef(_) => parent.push_prop(keywords::DYNAMIC_REF),
             AnyOf { .. } => parent.push_prop(keywords::ANY_OF),
             AllOf { .. } => parent.push_prop(keywords::ALL_OF),
             OneOf { .. } => parent.push_prop(keywords::ONE_OF),
@@ -160,7 +201,8 @@ impl Application {
             UnevaluatedProperties => parent.push_prop(keywords::UNEVALUATED_PROPERTIES),
 
             // Item keywords.
-            Contains => parent.push_prop(keywords::CONTAINS),
+            Contains { .. } => parent.push_prop(keywords::CONTAINS),
+            PrefixItems { .. } => parent.push_prop(keywords::PREFIX_ITEMS),
             Items { .. } => parent.push_prop(keywords::ITEMS),
             AdditionalItems => parent.push_prop(keywords::ADDITIONAL_ITEMS),
             UnevaluatedItems => parent.push_prop(keywords::UNEVALUATED_ITEMS),
@@ -182,6 +224,7 @@ impl Application {
             // In-place keywords.
             Ref(_) => *parent,
             RecursiveRef(_) => *parent,
+            DynamicRef(_) => *parent,
             AnyOf { index } => parent.push_item(*index),
             AllOf { index } => parent.push_item(*index),
             OneOf { index } => parent.push_item(*index),
@@ -195,7 +238,8 @@ impl Application {
             AdditionalProperties | UnevaluatedProperties => *parent,
 
             // Item keywords.
-            Contains => *parent,
+            Contains { .. } => *parent,
+            PrefixItems { index } => parent.push_item(*index),
             Items { index: None } => *parent,
             Items { index: Some(i) } => parent.push_item(*i),
             AdditionalItems | UnevaluatedItems => *parent,
@@ -232,7 +276,8 @@ pub enum Validation {
     MaxLength(usize),
     MinLength(usize),
     Pattern(fancy_regex::Regex),
-    // Format(String),
+    // Format is asserted only if the Validator is configured to do so.
+    Format(formats::Format),
 
     // Number-specific validations.
     MultipleOf(Number),
@@ -262,3 +307,93 @@ pub enum Validation {
         then_interned: intern::Set,
     },
 }
+
+impl Validation {
+    /// Returns the schema keyword from which this Validation was built,
+    /// or None if it's the `false` schema.
+    pub fn keyword(&self) -> Option<&'static str> {
+        use Validation::*;
+
+        Some(match self {
+            False => return None,
+            Type(_) => keywords::TYPE,
+            Const(_) => keywords::CONST,
+            Enum { .. } => keywords::ENUM,
+
+            MaxLength(_) => keywords::MAX_LENGTH,
+            MinLength(_) => keywords::MIN_LENGTH,
+            Pattern(_) => keywords::PATTERN,
+            Format(_) => keywords::FORMAT,
+
+            MultipleOf(_) => keywords::MULTIPLE_OF,
+            Maximum(_) => keywords::MAXIMUM,
+            ExclusiveMaximum(_) => keywords::EXCLUSIVE_MAXIMUM,
+            Minimum(_) => keywords::MINIMUM,
+            ExclusiveMinimum(_) => keywords::EXCLUSIVE_MINIMUM,
+
+            MaxItems(_) => keywords::MAX_ITEMS,
+            MinItems(_) => keywords::MIN_ITEMS,
+            UniqueItems => keywords::UNIQUE_ITEMS,
+            MaxContains(_) => keywords::MAX_CONTAINS,
+            MinContains(_) => keywords::MIN_CONTAINS,
+
+            MaxProperties(_) => keywords::MAX_PROPERTIES,
+            MinProperties(_) => keywords::MIN_PROPERTIES,
+            Required { .. } => keywords::REQUIRED,
+            DependentRequired { .. } => keywords::DEPENDENT_REQUIRED,
+        })
+    }
+}
+
+// Display of a Validation describes the constraint which an invalid instance
+// failed to satisfy, for use in human-readable error reports.
+impl std::fmt::Display for Validation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use itertools::Itertools;
+        use Validation::*;
+
+        match self {
+            False => write!(f, "must not exist (schema is false)"),
+            Type(set) => write!(f, "must be of type {}", set),
+            Const(literal) => write!(f, "must equal {}", literal.value),
+            Enum { variants } => write!(
+                f,
+                "must be one of {}",
+                variants.iter().map(|v| &v.value).format(", ")
+            ),
+
+            MaxLength(n) => write!(f, "must be at most {} characters long", n),
+            MinLength(n) => write!(f, "must be at least {} characters long", n),
+            Pattern(re) => write!(f, "must match pattern {:?}", re.as_str()),
+            Format(format) => write!(f, "must be a valid {:?}", format.as_str()),
+
+            MultipleOf(n) => write!(f, "must be a multiple of {}", n),
+            Maximum(n) => write!(f, "must be at most {}", n),
+            ExclusiveMaximum(n) => write!(f, "must be less than {}", n),
+            Minimum(n) => write!(f, "must be at least {}", n),
+            ExclusiveMinimum(n) => write!(f, "must be greater than {}", n),
+
+            MaxItems(n) => write!(f, "must have at most {} items", n),
+            MinItems(n) => write!(f, "must have at least {} items", n),
+            UniqueItems => write!(f, "must have unique items"),
+            MaxContains(n) => write!(f, "must have at most {} items matching \"contains\"", n),
+            MinContains(n) => write!(f, "must have at least {} items matching \"contains\"", n),
+
+            MaxProperties(n) => write!(f, "must have at most {} properties", n),
+            MinProperties(n) => write!(f, "must have at least {} properties", n),
+            Required { props, .. } => {
+                write!(
+                    f,
+                    "must have required properties {:?}",
+                    props.iter().format(", ")
+                )
+            }
+            DependentRequired { if_, then_, .. } => write!(
+                f,
+                "must have properties {:?} when property {:?} is present",
+                then_.iter().format(", "),
+                if_
+            ),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::schema::{index, intern, Annotation, Application, Keyword, Schema, Validation, *};
 use crate::{LocatedItem, LocatedProperty, Location, Number, Span, Walker};
 use fxhash::FxHashSet as HashSet;
+use percent_encoding::utf8_percent_encode;
 use std::borrow::Cow;
 
 pub trait Context: Sized + std::fmt::Debug {
@@ -25,6 +26,7 @@ pub trait Context: Sized + std::fmt::Debug {
 /// expensive up-front tracking work.
 #[derive(Debug)]
 pub struct FullContext {
+    // JSON pointer of the validated document location, which is not URL-escaped.
     pub instance_ptr: String,
     pub canonical_uri: String,
     pub keyword_location: String,
@@ -42,7 +44,7 @@ impl Context for FullContext {
         A: Annotation,
     {
         FullContext {
-            instance_ptr: loc.url_escaped().to_string(),
+            instance_ptr: loc.pointer_str().to_string(),
             canonical_uri: scope.schema.curi.as_str().to_owned(),
             keyword_location: scope.keyword_location(parents),
             span: Span {
@@ -60,7 +62,7 @@ impl Context for FullContext {
     fn basic_output_entry(&self, error: String) -> serde_json::Value {
         serde_json::json!({
             "keywordLocation": self.keyword_location,
-            "instanceLocation": self.instance_ptr,
+            "instanceLocation": utf8_percent_encode(&self.instance_ptr, crate::PTR_ESCAPE_SET).to_string(),
             "absoluteKeywordLocation": self.canonical_uri,
             "error": error,
         })
@@ -131,6 +133,40 @@ impl<'sm, A: Annotation> Outcome<'sm, A> {
             Outcome::Annotation(_) => false,
         }
     }
+
+    /// Returns the schema keyword which produced this Outcome, if there is one.
+    pub fn keyword(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Invalid(validation) => validation.keyword(),
+            Outcome::NotIsValid => Some(keywords::NOT),
+            Outcome::AnyOfNotMatched => Some(keywords::ANY_OF),
+            Outcome::OneOfNotMatched | Outcome::OneOfMultipleMatched => Some(keywords::ONE_OF),
+            // A missing reference may be of $ref, $recursiveRef, or $dynamicRef.
+            Outcome::ReferenceNotFound(_) | Outcome::Annotation(_) => None,
+        }
+    }
+}
+
+// Display of an Outcome describes it for use in human-readable error reports.
+impl<'sm, A: Annotation> std::fmt::Display for Outcome<'sm, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Invalid(validation) => validation.fmt(f),
+            Outcome::NotIsValid => write!(f, "must not match the \"not\" schema"),
+            Outcome::AnyOfNotMatched => write!(f, "must match at least one \"anyOf\" schema"),
+            Outcome::OneOfNotMatched => {
+                write!(f, "must match exactly one \"oneOf\" schema (matched none)")
+            }
+            Outcome::OneOfMultipleMatched => write!(
+                f,
+                "must match exactly one \"oneOf\" schema (matched several)"
+            ),
+            Outcome::ReferenceNotFound(uri) => {
+                write!(f, "references schema {} which was not found", uri)
+            }
+            Outcome::Annotation(annotation) => write!(f, "annotation {:?}", annotation),
+        }
+    }
 }
 
 /// Build "basic" output from a set of validator outcomes.
@@ -224,6 +260,30 @@ where
         }
     }
 
+    // Returns the URI of the outer-most $dynamicAnchor named |anchor| which is
+    // within a schema resource of the current dynamic scope, if one exists.
+    fn dynamic_anchor(
+        &self,
+        parents: &[Scope<'sm, A, C>],
+        index: &index::Index<'sm, A>,
+        anchor: &str,
+    ) -> Option<url::Url> {
+        let mut r = None;
+        if let Some((ind, _)) = self.parent {
+            r = parents[ind].dynamic_anchor(parents, index, anchor);
+        }
+        r.or_else(|| {
+            let mut uri = self.schema.curi.clone();
+            uri.set_fragment(Some(anchor));
+
+            if is_dynamic_anchor(index, &uri) {
+                Some(uri)
+            } else {
+                None
+            }
+        })
+    }
+
     fn dynamic_base(&self, parents: &[Scope<'sm, A, C>]) -> Option<&'sm url::Url> {
         let mut r = None;
         if let Some((ind, _)) = self.parent {
@@ -246,6 +306,8 @@ where
     index: &'sm index::Index<'sm, A>,
     scopes: Vec<Scope<'sm, A, C>>,
     active_offsets: Vec<usize>,
+    // Should "format" keywords be asserted, or only collected as annotations?
+    assert_formats: bool,
 
     // Pools of empty-but-reserved vectors for re-use.
     outcomes_pool: Vec<Vec<(Outcome<'sm, A>, C)>>,
@@ -335,7 +397,7 @@ where
     fn push_item<'a>(&mut self, span: &Span, loc: &'a LocatedItem<'a>) {
         //println!("\t\t\t\tpush_item {} @ {:?}", Location::Item(*loc), span);
 
-        use Application::{AdditionalItems, Contains, Items, UnevaluatedItems};
+        use Application::{AdditionalItems, Contains, Items, PrefixItems, UnevaluatedItems};
         use Keyword::Application as KWApp;
 
         let active_from = *self.active_offsets.last().unwrap();
@@ -354,8 +416,10 @@ where
                 // Item applications also have preference rules (which keywords are sorted by).
                 // C.f https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.9.3.1
                 let evaluates = match app {
-                    // Items without an index always applies.
-                    Items { index: None } => true,
+                    // PrefixItems matches on location index equality.
+                    PrefixItems { index } if *index == loc.index => true,
+                    // Items without an index applies if PrefixItems hasn't.
+                    Items { index: None } if !evaluated => true,
                     // Items with an index matches on location index equality.
                     Items { index: Some(i) } => {
                         indexed_items = true;
@@ -366,8 +430,9 @@ where
                     }
                     // AdditionalItems evaluates if indexed Items exist and none matched.
                     AdditionalItems if indexed_items && !evaluated => true,
-                    // Contains applies but does not evaluate.
-                    Contains => false,
+                    // Contains applies, and evaluates only if it matches
+                    // (c.f. the handling of its scope in unwind_scope).
+                    Contains { .. } => false,
                     // Finally, UnevaluatedItems applies if no other application evaluates.
                     UnevaluatedItems if !evaluated => false,
 
@@ -496,6 +561,7 @@ where
         //    span
         //);
 
+        let assert_formats = self.assert_formats;
         self.check_validations(span, loc, |validation, _| -> bool {
             use Validation::*;
 
@@ -507,6 +573,7 @@ where
                 MinLength(bound) => *bound <= s.chars().count(),
                 MaxLength(bound) => *bound >= s.chars().count(),
                 Pattern(re) => regex_matches(re, s),
+                Format(format) => !assert_formats || format.validate(s),
                 _ => true,
             }
         });
@@ -542,12 +609,29 @@ where
             index,
             scopes: Vec::new(),
             active_offsets: Vec::new(),
+            assert_formats: false,
             outcomes_pool: Vec::new(),
             outcomes_uneval_pool: Vec::new(),
             bits_pool: Vec::new(),
         }
     }
 
+    /// Returns whether the Validator asserts known formats (c.f. set_assert_formats).
+    pub fn assert_formats(&self) -> bool {
+        self.assert_formats
+    }
+
+    /// Set whether the Validator asserts that strings are valid instances of their
+    /// schema's "format", where the format is known. By default, "format" is
+    /// collected only as an annotation and doesn't affect validation.
+    ///
+    /// Within Flow, formats are asserted only by the combiners of captures and
+    /// materializations (and `flowctl combine`), where it's opted into through
+    /// CombineAPI.Config. Derivation and register validators don't set it.
+    pub fn set_assert_formats(&mut self, assert_formats: bool) {
+        self.assert_formats = assert_formats;
+    }
+
     /// Index of the Validator.
     pub fn schema_index(&self) -> &'sm index::Index<'sm, A> {
         self.index
@@ -804,7 +888,11 @@ where
             App::Def { .. } | App::Definition { .. } => panic!("unexpected Def"),
 
             // In-place keywords which must always validate.
-            App::AllOf { .. } | App::Ref(_) | App::RecursiveRef(_) | App::Not => RequiredInPlace,
+            App::AllOf { .. }
+            | App::Ref(_)
+            | App::RecursiveRef(_)
+            | App::DynamicRef(_)
+            | App::Not => RequiredInPlace,
 
             // In-place keywords which must validate subject to the state
             // of a previously-collected annotation.
@@ -837,16 +925,28 @@ where
                 parent.valid_one_of.push(!scope.invalid);
                 OptionalInPlace
             }
-            App::Contains { .. } => {
+            // Contains is a child application: a valid match contributes its
+            // outcomes, and (in 2020-12) marks the matched item as evaluated.
+            App::Contains { evaluates } => {
                 if !scope.invalid {
                     parent.valid_contains += 1;
+                    parent.outcomes.extend(scope.outcomes.drain(..));
+
+                    if *evaluates {
+                        let child_index = match loc {
+                            Location::Item(LocatedItem { index, .. }) => *index,
+                            _ => unreachable!(),
+                        };
+                        parent.evaluated.set(child_index, true);
+                    }
                 }
-                OptionalInPlace
+                Ignore
             }
 
             // Child applications which must always succeed.
             App::PatternProperties { .. }
             | App::AdditionalProperties
+            | App::PrefixItems { .. }
             | App::Items { .. }
             | App::Properties { .. }
             | App::PropertyNames
@@ -908,7 +1008,8 @@ where
 
     fn expand_scope<'a>(&mut self, index: usize, span: &Span, loc: &'a Location<'a>) {
         use Application::{
-            AllOf, AnyOf, DependentSchema, Else, If, Not, OneOf, RecursiveRef, Ref, Then,
+            AllOf, AnyOf, DependentSchema, DynamicRef, Else, If, Not, OneOf, RecursiveRef, Ref,
+            Then,
         };
 
         //println!("expand_scope '{}' '{}'", self.scopes[index].keyword_location(&self.scopes), self.scopes[index].schema.curi);
@@ -945,6 +1046,18 @@ where
                     }
                     (schema, Some(uri))
                 }
+                DynamicRef(uri) => {
+                    // A $dynamicRef resolves as a $ref, unless its referent is a $dynamicAnchor.
+                    // If it is, then it instead resolves to the outer-most like-named
+                    // $dynamicAnchor within the current dynamic scope.
+                    let dynamic = match uri.fragment() {
+                        Some(anchor) if is_dynamic_anchor(self.index, uri) => {
+                            self.scopes[index].dynamic_anchor(&self.scopes, self.index, anchor)
+                        }
+                        _ => None,
+                    };
+                    (schema, Some(dynamic.map_or(Cow::Borrowed(uri), Cow::Owned)))
+                }
                 AnyOf { .. }
                 | AllOf { .. }
                 | OneOf { .. }
@@ -984,6 +1097,17 @@ where
     }
 }
 
+/// Returns true if |uri| is indexed as a $dynamicAnchor (and not only as an $anchor).
+fn is_dynamic_anchor<A: Annotation>(index: &index::Index<'_, A>, uri: &url::Url) -> bool {
+    match index.fetch(uri) {
+        Some(schema) => schema.kw.iter().any(|kw| match kw {
+            Keyword::DynamicAnchor(anchor) => anchor == uri,
+            _ => false,
+        }),
+        None => false,
+    }
+}
+
 /// Returns true if the text is a match for the given regex. This function exists primarily so we
 /// have a common place to put logging, since there's a weird edge case where `is_match` returns an
 /// `Err`. This can happen if a regex uses backtracking and overflows the `backtracking_limit` when
@@ -7,17 +7,6 @@ set -e
 
 DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" >/dev/null 2>&1 && pwd )"
 
-DRAFT2019_TESTS="${DIR}/draft2019_tests.rs";
-cat >"$DRAFT2019_TESTS" <<EOF
-//! DO NOT EDIT THIS FILE!
-//! This file is generated by $(basename "$0") based on the official
-//! test cases in the submodule.
-
-mod validator_test_utils;
-use validator_test_utils::run_draft09_test;
-
-EOF
-
 function make_base_test_name() {
     # Strips off the .json extension, then changes all capitals to lowercase with preceeding
     # underscore and also replaces dashes with underscores the bit in the middle is a special case
@@ -25,17 +14,43 @@ function make_base_test_name() {
     basename "$1" .json | sed 's/\([A-Z]\)/_\L\1/g;s/^_//;s/-/_/g'
 }
 
-for file in $DIR/official/tests/draft2019-09/*.json; do
-    filename=$(basename "$file")
-    test_base_name="$(make_base_test_name "$filename")"
-    printf 'Using file "%s" to generate test: "%s"\n' "$filename" "$test_base_name"
+# Usage: generate_tests <output file> <draft directory> <test name prefix> <runner function>
+function generate_tests() {
+    local output="$1" draft="$2" prefix="$3" runner="$4"
 
-    cat >> "$DRAFT2019_TESTS" << EOF
+    # Refuse to (re)generate from a missing or empty suite, which would
+    # otherwise produce tests that can't find their cases.
+    local files=("$DIR"/official/tests/"${draft}"/*.json)
+    if [[ ! -e "${files[0]}" ]]; then
+        echo "official test cases of ${draft} are missing." >&2
+        echo "Run \`git submodule update --init\` to fetch the official test suite." >&2
+        exit 1
+    fi
+
+    cat >"$output" <<EOT
+//! DO NOT EDIT THIS FILE!
+//! This file is generated by $(basename "$0") based on the official
+//! test cases in the submodule.
+
+mod validator_test_utils;
+use validator_test_utils::${runner};
+
+EOT
+
+    for file in "${files[@]}"; do
+        filename=$(basename "$file")
+        test_base_name="$(make_base_test_name "$filename")"
+        printf 'Using file "%s" to generate test: "%s"\n' "$filename" "$test_base_name"
+
+        cat >> "$output" << EOT
 #[test]
-fn test_d09_${test_base_name}() {
-    run_draft09_test("${filename}");
+fn test_${prefix}_${test_base_name}() {
+    ${runner}("${filename}");
 }
 
-EOF
-done
+EOT
+    done
+}
 
+generate_tests "${DIR}/draft2019_tests.rs" draft2019-09 d09 run_draft09_test
+generate_tests "${DIR}/draft2020_tests.rs" draft2020-12 d2020 run_draft2020_test
@@ -51,3 +51,129 @@ fn test_bassssic() -> Result {
     print!("{:?}", sch);
     Ok(())
 }
+
+#[test]
+fn test_draft_2020_12_keywords() -> Result {
+    use json::{de, validator};
+
+    let j = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "http://example.com/list.json",
+        "$defs": {
+            // Generic list, having elements which are dynamically typed.
+            "list": {
+                "$id": "generic-list.json",
+                "type": "array",
+                "prefixItems": [{"const": "head"}],
+                "items": {"$dynamicRef": "#element"},
+                "$defs": {
+                    "element": {"$dynamicAnchor": "element"},
+                },
+            },
+            // Specialize the list's elements to be integers.
+            "int": {"$dynamicAnchor": "element", "type": "integer"},
+        },
+        "$ref": "generic-list.json",
+    });
+
+    let url = url::Url::parse("http://example.com/list.json")?;
+    let sch = build::build_schema::<CoreAnnotation>(url.clone(), &j)?;
+
+    let mut ind = index::IndexBuilder::new();
+    ind.add(&sch)?;
+    ind.verify_references()?;
+    let ind = ind.into_index();
+
+    let mut val = validator::Validator::<CoreAnnotation, validator::FullContext>::new(&ind);
+
+    for (doc, expect_valid) in vec![
+        (json!(["head", 1, 2]), true),
+        (json!(["head"]), true),
+        // prefixItems applies to the first item.
+        (json!(["tail", 1, 2]), false),
+        // items applies to subsequent items, which resolve to the specialized element.
+        (json!(["head", 1, "two"]), false),
+    ] {
+        val.prepare(&url)?;
+        de::walk(&doc, &mut val)?;
+        assert_eq!(!val.invalid(), expect_valid, "{}", doc);
+    }
+
+    // Keywords of the other draft are ignored, rather than rejected.
+    // "prefixItems" and "$dynamic*" are keywords only of draft 2020-12,
+    // while "additionalItems" and "$recursive*" are keywords only of draft 2019-09.
+    for j in vec![
+        json!({"prefixItems": [{"const": "head"}]}),
+        json!({"$dynamicRef": "#"}),
+        json!({"$dynamicAnchor": "foo"}),
+        json!({"$schema": "https://json-schema.org/draft/2020-12/schema", "additionalItems": false}),
+        json!({"$schema": "https://json-schema.org/draft/2020-12/schema", "$recursiveRef": "#"}),
+        json!({"$schema": "https://json-schema.org/draft/2020-12/schema", "$recursiveAnchor": true}),
+    ] {
+        let sch = build::build_schema::<CoreAnnotation>(url.clone(), &j)?;
+        assert!(sch.kw.is_empty(), "{} => {:?}", j, sch.kw);
+    }
+    // Under draft 2020-12, "items" must be a schema.
+    let j = json!({"$schema": "https://json-schema.org/draft/2020-12/schema", "items": [true]});
+    assert!(build::build_schema::<CoreAnnotation>(url.clone(), &j).is_err());
+
+    for j in vec![
+        json!({"$recursiveRef": "#", "$recursiveAnchor": true}),
+        json!({"$schema": "https://json-schema.org/draft/2020-12/schema", "$dynamicRef": "#", "$dynamicAnchor": "foo"}),
+    ] {
+        let sch = build::build_schema::<CoreAnnotation>(url.clone(), &j)?;
+        assert!(!sch.kw.is_empty(), "{}", j);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_contains_evaluates_items() -> Result {
+    use json::{de, validator};
+
+    let url = url::Url::parse("http://example.com/contains.json")?;
+
+    for (draft, expect) in vec![
+        // Under draft 2020-12, items matched by "contains" are evaluated.
+        (
+            "https://json-schema.org/draft/2020-12/schema",
+            vec![true, false, true, false],
+        ),
+        // Under draft 2019-09, they're not.
+        (
+            "https://json-schema.org/draft/2019-09/schema",
+            vec![false, false, false, false],
+        ),
+    ] {
+        let j = json!({
+            "$schema": draft,
+            "contains": {"type": "integer"},
+            "unevaluatedItems": {"type": "string"},
+        });
+        let sch = build::build_schema::<CoreAnnotation>(url.clone(), &j)?;
+
+        let mut ind = index::IndexBuilder::new();
+        ind.add(&sch)?;
+        ind.verify_references()?;
+        let ind = ind.into_index();
+
+        let mut val = validator::Validator::<CoreAnnotation, validator::FullContext>::new(&ind);
+
+        for (doc, expect_valid) in vec![
+            json!([1, 2, 3]),
+            json!([1, 2, true]),
+            json!(["one", 2]),
+            json!(["one"]),
+        ]
+        .into_iter()
+        .zip(expect.into_iter())
+        {
+            val.prepare(&url)?;
+            de::walk(&doc, &mut val)?;
+            assert_eq!(!val.invalid(), expect_valid, "{} {}", draft, doc);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! Targeted test cases, in the format of the official test suite, which
+//! cover keywords whose behavior differs between drafts. Unlike the
+//! generated tests, these don't require the official submodule.
+
+mod validator_test_utils;
+use validator_test_utils::{run_targeted_draft09_test, run_targeted_draft2020_test};
+
+#[test]
+fn test_targeted_d09_unevaluated_items() {
+    run_targeted_draft09_test("unevaluatedItems.json");
+}
+
+#[test]
+fn test_targeted_d2020_dynamic_ref() {
+    run_targeted_draft2020_test("dynamicRef.json");
+}
+
+#[test]
+fn test_targeted_d2020_prefix_items() {
+    run_targeted_draft2020_test("prefixItems.json");
+}
+
+#[test]
+fn test_targeted_d2020_unevaluated_items() {
+    run_targeted_draft2020_test("unevaluatedItems.json");
+}
@@ -2,15 +2,40 @@
 use glob;
 use json::{
     de,
-    schema::{build, index, CoreAnnotation, Schema},
+    schema::{build, index, CoreAnnotation, Draft, Schema},
     validator,
 };
 use serde_json as sj;
 use std::{env, fs, io, path};
 
 /// Runs tests from the given file within the `draft2019-09/` directory.
+#[allow(dead_code)]
 pub fn run_draft09_test(target: &str) {
-    run_file_test(&["official", "tests", "draft2019-09", target]);
+    run_file_test(
+        &["official", "tests", "draft2019-09", target],
+        Draft::Draft2019_09,
+    );
+}
+
+/// Runs tests from the given file within the `draft2020-12/` directory.
+#[allow(dead_code)]
+pub fn run_draft2020_test(target: &str) {
+    run_file_test(
+        &["official", "tests", "draft2020-12", target],
+        Draft::Draft2020_12,
+    );
+}
+
+/// Runs tests from the given file within the `targeted/draft2019-09/` directory.
+#[allow(dead_code)]
+pub fn run_targeted_draft09_test(target: &str) {
+    run_file_test(&["targeted", "draft2019-09", target], Draft::Draft2019_09);
+}
+
+/// Runs tests from the given file within the `targeted/draft2020-12/` directory.
+#[allow(dead_code)]
+pub fn run_targeted_draft2020_test(target: &str) {
+    run_file_test(&["targeted", "draft2020-12", target], Draft::Draft2020_12);
 }
 
 fn read_json_file(target: &[&str]) -> sj::Value {
@@ -20,6 +45,15 @@ fn read_json_file(target: &[&str]) -> sj::Value {
     path.push("tests");
     path.extend(target.iter());
 
+    // The official suite is a git submodule. Fail with a clear cause if
+    // it's not been fetched, rather than an opaque file-not-found error.
+    if target.first() == Some(&"official") && !path.exists() {
+        panic!(
+            "official test case {} is missing: run `git submodule update --init` to fetch the official test suite",
+            path.display(),
+        );
+    }
+
     let file = fs::File::open(path).unwrap();
     sj::from_reader(io::BufReader::new(file)).unwrap()
 }
@@ -54,7 +88,7 @@ where
     })
 }
 
-fn run_file_test(target: &[&str]) {
+fn run_file_test(target: &[&str], draft: Draft) {
     let test_root = &env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_owned());
 
     let url = url::Url::parse("http://localhost:1234").unwrap();
@@ -81,7 +115,8 @@ fn run_file_test(target: &[&str]) {
 
         println!("{}:", desc);
         println!("\t{}:", schema);
-        let schema = build::build_schema::<CoreAnnotation>(url.clone(), schema).unwrap();
+        let schema =
+            build::build_draft_schema::<CoreAnnotation>(url.clone(), schema, draft).unwrap();
         println!("\t{:?}", schema);
 
         let mut ind = index::IndexBuilder::new();
@@ -0,0 +1,25 @@
+//! Configuration related to Avro object container files.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvroConfig {
+    /// An Avro schema that each record is resolved against, according to the schema resolution
+    /// rules of the Avro spec. Fields that are missing from a file are filled in with their
+    /// defaults, fields are matched by their aliases, and numeric types are promoted. This allows
+    /// files written with different versions of a schema to produce documents of the same shape.
+    /// Aliases are only applied to the fields of the top-level record. When this is unset, records
+    /// are output using the schema that's embedded in each file.
+    #[serde(default)]
+    pub reader_schema: Option<Value>,
+}
+
+impl AvroConfig {
+    pub fn merge(&mut self, other: &AvroConfig) {
+        if other.reader_schema.is_some() {
+            self.reader_schema = other.reader_schema.clone();
+        }
+    }
+}
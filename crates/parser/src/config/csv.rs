@@ -142,6 +142,11 @@ pub struct CharacterSeparatedConfig {
     /// matched with the columns in the file by the order in which they appear here.
     #[serde(default)]
     pub headers: Vec<String>,
+    /// Whether to detect if the first row is data rather than column names, when `headers` are
+    /// unspecified. If enabled, and the first row appears to be data, then the columns are named
+    /// `column1`, `column2`, etc. Otherwise, the first row is always used as the column names.
+    #[serde(default)]
+    pub detect_headers: Option<bool>,
     /// The delimiter that separates values within each row. Only single-byte delimiters are
     /// supported.
     #[serde(default)]
@@ -166,6 +171,30 @@ pub struct CharacterSeparatedConfig {
     /// parsing process. When this limit is exceeded, parsing halts.
     #[serde(default)]
     pub error_threshold: Option<ErrorThreshold>,
+    /// Values that will be interpreted as null, for columns where the schema allows null. Matching
+    /// is case-sensitive. If unspecified, then empty values, `NULL`, `null`, `nil`, and `\N` are
+    /// all interpreted as null.
+    #[serde(default)]
+    pub null_values: Vec<String>,
+    /// Values that will be interpreted as `true`, for columns where the schema allows booleans.
+    /// Matching is case-sensitive. Defaults to `true`.
+    #[serde(default)]
+    pub true_values: Vec<String>,
+    /// Values that will be interpreted as `false`, for columns where the schema allows booleans.
+    /// Matching is case-sensitive. Defaults to `false`.
+    #[serde(default)]
+    pub false_values: Vec<String>,
+    /// Patterns used to parse values of columns with a schema `format` of `date-time`, using
+    /// strftime-style syntax like `%m/%d/%Y %H:%M:%S`. Values in RFC3339 format are always
+    /// accepted, and values that match one of these patterns are converted to RFC3339. Patterns
+    /// without a time zone are assumed to be UTC.
+    #[serde(default)]
+    pub date_time_formats: Vec<String>,
+    /// Patterns used to parse values of columns with a schema `format` of `date`, using
+    /// strftime-style syntax like `%m/%d/%Y`. Values in RFC3339 full-date format (`%Y-%m-%d`) are
+    /// always accepted, and values that match one of these patterns are converted to that format.
+    #[serde(default)]
+    pub date_formats: Vec<String>,
 }
 
 impl CharacterSeparatedConfig {
@@ -173,6 +202,9 @@ impl CharacterSeparatedConfig {
         if !other.headers.is_empty() {
             self.headers = other.headers.clone();
         }
+        if other.detect_headers.is_some() {
+            self.detect_headers = other.detect_headers;
+        }
         if other.delimiter.is_some() {
             self.delimiter = other.delimiter;
         }
@@ -191,6 +223,21 @@ impl CharacterSeparatedConfig {
         if other.error_threshold.is_some() {
             self.error_threshold = other.error_threshold.clone();
         }
+        if !other.null_values.is_empty() {
+            self.null_values = other.null_values.clone();
+        }
+        if !other.true_values.is_empty() {
+            self.true_values = other.true_values.clone();
+        }
+        if !other.false_values.is_empty() {
+            self.false_values = other.false_values.clone();
+        }
+        if !other.date_time_formats.is_empty() {
+            self.date_time_formats = other.date_time_formats.clone();
+        }
+        if !other.date_formats.is_empty() {
+            self.date_formats = other.date_formats.clone();
+        }
     }
 }
 
@@ -205,13 +252,19 @@ mod test {
             headers: vec![String::from("nope")],
             quote: Some(Char(34)),
             error_threshold: Some(ErrorThreshold::new(33).unwrap()),
+            null_values: vec![String::from("-")],
+            true_values: vec![String::from("yes")],
             ..Default::default()
         };
         base.merge(&CharacterSeparatedConfig {
             delimiter: Some(Char(44)),
             headers: vec![String::from("foo")],
+            detect_headers: Some(true),
             escape: Some(Char(22)),
             error_threshold: Some(ErrorThreshold::new(77).unwrap()),
+            true_values: vec![String::from("Y")],
+            date_time_formats: vec![String::from("%Y%m%d%H%M%S")],
+            date_formats: vec![String::from("%d.%m.%Y")],
             ..Default::default()
         });
 
@@ -220,5 +273,14 @@ mod test {
         assert_eq!(Some(Char(22)), base.escape);
         assert_eq!(Some(ErrorThreshold::new(77).unwrap()), base.error_threshold);
         assert_eq!(&[String::from("foo")], base.headers.as_slice());
+        assert_eq!(Some(true), base.detect_headers);
+        assert_eq!(&[String::from("-")], base.null_values.as_slice());
+        assert_eq!(&[String::from("Y")], base.true_values.as_slice());
+        assert!(base.false_values.is_empty());
+        assert_eq!(
+            &[String::from("%Y%m%d%H%M%S")],
+            base.date_time_formats.as_slice()
+        );
+        assert_eq!(&[String::from("%d.%m.%Y")], base.date_formats.as_slice());
     }
 }
@@ -0,0 +1,70 @@
+//! Configuration related to Excel spreadsheets.
+use super::JsonPointer;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcelConfig {
+    /// The name of the worksheet to parse. If unspecified, then the first worksheet in the
+    /// workbook will be parsed. Ignored if `allSheets` is true.
+    #[serde(default)]
+    pub sheet: Option<String>,
+    /// Parse all of the worksheets in the workbook, in the order in which they appear.
+    #[serde(default)]
+    pub all_sheets: bool,
+    /// Add the name of the worksheet as a property of each object at the location given. If
+    /// unspecified and `allSheets` is true, then the sheet name will be added at `/_meta/sheet`.
+    #[serde(default)]
+    pub add_sheet_name: Option<JsonPointer>,
+    /// The row number (starting at 1) of the row that contains the column names. Any rows above
+    /// it are skipped. Defaults to 1, unless `headers` are given, in which case there is assumed
+    /// to be no header row unless one is specified here.
+    #[serde(default)]
+    pub header_row: Option<u32>,
+    /// Manually specified headers, which can be used in cases where the worksheet doesn't contain
+    /// a header row. The column names will be matched with the columns in the worksheet by the
+    /// order in which they appear here, starting with column A.
+    #[serde(default)]
+    pub headers: Vec<String>,
+}
+
+impl ExcelConfig {
+    pub fn merge(&mut self, other: &ExcelConfig) {
+        if other.sheet.is_some() {
+            self.sheet = other.sheet.clone();
+        }
+        if other.all_sheets {
+            self.all_sheets = true;
+        }
+        if other.add_sheet_name.is_some() {
+            self.add_sheet_name = other.add_sheet_name.clone();
+        }
+        if other.header_row.is_some() {
+            self.header_row = other.header_row;
+        }
+        if !other.headers.is_empty() {
+            self.headers = other.headers.clone();
+        }
+    }
+
+    /// Returns the location at which to add the sheet name, if any.
+    pub fn sheet_name_location(&self) -> Option<JsonPointer> {
+        self.add_sheet_name.clone().or_else(|| {
+            if self.all_sheets {
+                Some(JsonPointer::from("/_meta/sheet"))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the 1-based row number of the header row, or 0 if there is no header row.
+    pub fn resolved_header_row(&self) -> u32 {
+        match self.header_row {
+            Some(row) => row,
+            None if self.headers.is_empty() => 1,
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Configuration related to fixed-width (positional) text formats.
+use super::csv::Char;
+use super::{EncodingRef, ErrorThreshold};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Which sides of each value will have padding removed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Trim {
+    None,
+    Start,
+    End,
+    Both,
+}
+
+/// The units that column offsets and widths are measured in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WidthUnit {
+    Characters,
+    Bytes,
+}
+
+/// A single column within each fixed-width line.
+#[derive(Debug, Clone, PartialEq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedWidthColumn {
+    /// The name of the column, which is used to determine its location within output documents in
+    /// the same way as a CSV column header.
+    pub name: String,
+    /// The offset of the start of the column within each line, starting at 0.
+    pub start: usize,
+    /// The width of the column.
+    pub width: usize,
+    /// Overrides the `trim` rule for this column.
+    #[serde(default)]
+    pub trim: Option<Trim>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedWidthConfig {
+    /// The columns within each line. Columns may be given in any order, and any portions of the
+    /// line that aren't covered by a column are ignored.
+    #[serde(default)]
+    pub columns: Vec<FixedWidthColumn>,
+    /// Whether column offsets and widths are measured in characters or bytes. Defaults to
+    /// characters. Byte offsets require an encoding in which each line ending is a single `\n` byte,
+    /// such as UTF-8 or any single-byte encoding.
+    #[serde(default)]
+    pub units: Option<WidthUnit>,
+    /// The character used to pad values to the width of their column. Defaults to a space.
+    #[serde(default)]
+    pub padding: Option<Char>,
+    /// Which sides of each value will have padding removed. Defaults to both.
+    #[serde(default)]
+    pub trim: Option<Trim>,
+    /// The number of lines at the start of the file to skip, such as header or banner lines.
+    #[serde(default)]
+    pub skip_lines: Option<u64>,
+    /// The character encoding of the source file. If unspecified, then the parser will make a
+    /// best-effort guess based on peeking at a small portion of the beginning of the file.
+    #[serde(default)]
+    pub encoding: Option<EncodingRef>,
+    /// Allows a percentage of errors to be ignored without failing the entire
+    /// parsing process. When this limit is exceeded, parsing halts.
+    #[serde(default)]
+    pub error_threshold: Option<ErrorThreshold>,
+}
+
+impl FixedWidthConfig {
+    pub fn merge(&mut self, other: &FixedWidthConfig) {
+        if !other.columns.is_empty() {
+            self.columns = other.columns.clone();
+        }
+        if other.units.is_some() {
+            self.units = other.units;
+        }
+        if other.padding.is_some() {
+            self.padding = other.padding;
+        }
+        if other.trim.is_some() {
+            self.trim = other.trim;
+        }
+        if other.skip_lines.is_some() {
+            self.skip_lines = other.skip_lines;
+        }
+        if other.encoding.is_some() {
+            self.encoding = other.encoding;
+        }
+        if other.error_threshold.is_some() {
+            self.error_threshold = other.error_threshold.clone();
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Configuration related to JSON documents.
+use super::JsonPointer;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonConfig {
+    /// A JSON pointer to an array within each document, whose items are output as separate
+    /// records. For example, `/data` will output each item of the `data` array in content like
+    /// `{"data": [...]}`, and an empty pointer will output each item of a top-level array. The
+    /// rest of the document is skipped. When this is unset, each whitespace-separated document is
+    /// output as a record.
+    #[serde(default)]
+    pub records_pointer: Option<JsonPointer>,
+}
+
+impl JsonConfig {
+    pub fn merge(&mut self, other: &JsonConfig) {
+        if other.records_pointer.is_some() {
+            self.records_pointer = other.records_pointer.clone();
+        }
+    }
+}
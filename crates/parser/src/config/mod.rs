@@ -1,4 +1,10 @@
+pub mod avro;
 pub mod csv;
+pub mod excel;
+pub mod fixed_width;
+pub mod json;
+pub mod protobuf;
+pub mod xml;
 
 use encoding_rs::Encoding;
 use schemars::{gen, schema as schemagen};
@@ -11,7 +17,13 @@ use std::fs;
 use std::io;
 use std::path::Path;
 
+use self::avro::AvroConfig;
 use self::csv::CharacterSeparatedConfig;
+use self::excel::ExcelConfig;
+use self::fixed_width::FixedWidthConfig;
+use self::json::JsonConfig;
+use self::protobuf::ProtobufConfig;
+use self::xml::XmlConfig;
 
 /// References an encoding by WHATWG label.
 #[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
@@ -108,6 +120,16 @@ pub enum Format {
     W3cExtendedLog,
     /// Avro object container files, as defined by the [avro spec](https://avro.apache.org/docs/current/spec.html#Object+Container+Files)
     Avro,
+    /// Apache Parquet files, as defined by the [parquet format spec](https://parquet.apache.org/documentation/latest/)
+    Parquet,
+    /// Excel spreadsheets in the Office Open XML (.xlsx) format
+    Excel,
+    /// Fixed-width text, where each line is a record and each column occupies a fixed position
+    FixedWidth,
+    /// Protobuf messages, each of which is prefixed by its length as a varint
+    Protobuf,
+    /// XML documents, where each record is an element at a configured path
+    Xml,
 }
 
 impl std::convert::TryFrom<String> for Format {
@@ -147,6 +169,11 @@ impl Format {
             Format::Tsv => "tsv",
             Format::W3cExtendedLog => "w3cExtendedLog",
             Format::Avro => "avro",
+            Format::Parquet => "parquet",
+            Format::Excel => "excel",
+            Format::FixedWidth => "fixedWidth",
+            Format::Protobuf => "protobuf",
+            Format::Xml => "xml",
         }
     }
 
@@ -157,6 +184,11 @@ impl Format {
             Format::Tsv,
             Format::W3cExtendedLog,
             Format::Avro,
+            Format::Parquet,
+            Format::Excel,
+            Format::FixedWidth,
+            Format::Protobuf,
+            Format::Xml,
         ]
     }
 }
@@ -184,17 +216,41 @@ impl std::str::FromStr for Format {
 pub enum Compression {
     Gzip,
     ZipArchive,
+    Zstd,
+    Bzip2,
+    Xz,
+    Lz4,
+    TarArchive,
 }
 
 impl Compression {
-    pub const ALL: &'static [Compression] = &[Compression::Gzip, Compression::ZipArchive];
+    pub const ALL: &'static [Compression] = &[
+        Compression::Gzip,
+        Compression::ZipArchive,
+        Compression::Zstd,
+        Compression::Bzip2,
+        Compression::Xz,
+        Compression::Lz4,
+        Compression::TarArchive,
+    ];
 
     pub fn id(&self) -> &'static str {
         match *self {
             Compression::Gzip => "gzip",
             Compression::ZipArchive => "zip",
+            Compression::Zstd => "zstd",
+            Compression::Bzip2 => "bzip2",
+            Compression::Xz => "xz",
+            Compression::Lz4 => "lz4",
+            Compression::TarArchive => "tar",
         }
     }
+
+    /// Returns true if this is an archive format, which may contain multiple files. Each file
+    /// within an archive is parsed separately, using its own inferred format.
+    pub fn is_archive(&self) -> bool {
+        matches!(self, Compression::ZipArchive | Compression::TarArchive)
+    }
 }
 
 impl fmt::Display for Compression {
@@ -353,6 +409,13 @@ pub struct ParseConfig {
     /// monotonic counter that starts at 0 and increases by 1 for each output document.
     pub add_record_offset: Option<JsonPointer>,
 
+    /// Add the archive entry that each document was parsed from as a property of each object at
+    /// the location given. The value is an object with the `name` of the file within the archive
+    /// and the `offset` of the document within that file, which starts at 0 for each file. This
+    /// is ignored for content that isn't an archive.
+    #[serde(default)]
+    pub add_archive_entry: Option<JsonPointer>,
+
     /// Static data to add to each output JSON document.
     #[serde(default)]
     pub add_values: BTreeMap<JsonPointer, Value>,
@@ -379,6 +442,14 @@ pub struct ParseConfig {
     pub content_type_mappings: BTreeMap<String, Format>,
 
     // Below are format-specific configurations, which are used by the parsers.
+    /// Configures handling of Avro object container files.
+    #[serde(default)]
+    pub avro: Option<AvroConfig>,
+
+    /// Configures handling of JSON documents.
+    #[serde(default)]
+    pub json: Option<JsonConfig>,
+
     /// Configures handling of comma-separated values (CSV) format.
     #[serde(default)]
     pub csv: Option<CharacterSeparatedConfig>,
@@ -386,6 +457,22 @@ pub struct ParseConfig {
     /// Configures handling of tab-separated values (TSV) format.
     #[serde(default)]
     pub tsv: Option<CharacterSeparatedConfig>,
+
+    /// Configures handling of Excel spreadsheets.
+    #[serde(default)]
+    pub excel: Option<ExcelConfig>,
+
+    /// Configures handling of fixed-width text.
+    #[serde(default)]
+    pub fixed_width: Option<FixedWidthConfig>,
+
+    /// Configures handling of length-delimited protobuf messages.
+    #[serde(default)]
+    pub protobuf: Option<ProtobufConfig>,
+
+    /// Configures handling of XML documents.
+    #[serde(default)]
+    pub xml: Option<XmlConfig>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -419,15 +506,24 @@ impl ParseConfig {
         if other.format.is_some() {
             self.format = other.format.clone();
         }
+        if other.compression.is_some() {
+            self.compression = other.compression.clone();
+        }
         if other.filename.is_some() {
             self.filename = other.filename.clone();
         }
         if other.content_type.is_some() {
             self.content_type = other.content_type.clone();
         }
+        if other.content_encoding.is_some() {
+            self.content_encoding = other.content_encoding.clone();
+        }
         if other.add_record_offset.is_some() {
             self.add_record_offset = other.add_record_offset.clone();
         }
+        if other.add_archive_entry.is_some() {
+            self.add_archive_entry = other.add_archive_entry.clone();
+        }
         self.add_values.extend(
             other
                 .add_values
@@ -455,6 +551,20 @@ impl ParseConfig {
                 .iter()
                 .map(|kv| (kv.0.clone(), kv.1.clone())),
         );
+        if let Some(other_avro) = other.avro.as_ref() {
+            if let Some(self_avro) = self.avro.as_mut() {
+                self_avro.merge(other_avro);
+            } else {
+                self.avro = Some(other_avro.clone());
+            }
+        }
+        if let Some(other_json) = other.json.as_ref() {
+            if let Some(self_json) = self.json.as_mut() {
+                self_json.merge(other_json);
+            } else {
+                self.json = Some(other_json.clone());
+            }
+        }
         if let Some(other_csv) = other.csv.as_ref() {
             if let Some(self_csv) = self.csv.as_mut() {
                 self_csv.merge(other_csv);
@@ -469,6 +579,34 @@ impl ParseConfig {
                 self.tsv = Some(other_tsv.clone());
             }
         }
+        if let Some(other_excel) = other.excel.as_ref() {
+            if let Some(self_excel) = self.excel.as_mut() {
+                self_excel.merge(other_excel);
+            } else {
+                self.excel = Some(other_excel.clone());
+            }
+        }
+        if let Some(other_fixed_width) = other.fixed_width.as_ref() {
+            if let Some(self_fixed_width) = self.fixed_width.as_mut() {
+                self_fixed_width.merge(other_fixed_width);
+            } else {
+                self.fixed_width = Some(other_fixed_width.clone());
+            }
+        }
+        if let Some(other_protobuf) = other.protobuf.as_ref() {
+            if let Some(self_protobuf) = self.protobuf.as_mut() {
+                self_protobuf.merge(other_protobuf);
+            } else {
+                self.protobuf = Some(other_protobuf.clone());
+            }
+        }
+        if let Some(other_xml) = other.xml.as_ref() {
+            if let Some(self_xml) = self.xml.as_mut() {
+                self_xml.merge(other_xml);
+            } else {
+                self.xml = Some(other_xml.clone());
+            }
+        }
         self
     }
 
@@ -487,13 +625,20 @@ impl Default for ParseConfig {
             content_encoding: None,
             compression: None,
             add_record_offset: None,
+            add_archive_entry: None,
             add_values: BTreeMap::new(),
             projections: BTreeMap::new(),
             schema: Value::Null,
             file_extension_mappings: default_file_extension_mappings(),
             content_type_mappings: default_content_type_mappings(),
+            avro: None,
+            json: None,
             csv: None,
             tsv: None,
+            excel: None,
+            fixed_width: None,
+            protobuf: None,
+            xml: None,
         }
     }
 }
@@ -504,6 +649,12 @@ fn default_content_type_mappings() -> BTreeMap<String, Format> {
         ("text/json", Format::Json),
         ("text/csv", Format::Csv),
         ("text/tab-separated-values", Format::Tsv),
+        ("application/xml", Format::Xml),
+        ("text/xml", Format::Xml),
+        (
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            Format::Excel,
+        ),
     ])
         .iter()
         .map(|(k, v)| (k.to_string(), *v))
@@ -517,6 +668,9 @@ fn default_file_extension_mappings() -> BTreeMap<String, Format> {
         ("csv", Format::Csv),
         ("tsv", Format::Tsv),
         ("avro", Format::Avro),
+        ("parquet", Format::Parquet),
+        ("xlsx", Format::Excel),
+        ("xml", Format::Xml),
     ])
         .iter()
         .map(|(k, v)| (k.to_string(), *v))
@@ -554,12 +708,14 @@ mod test {
             &serde_json::from_str(
                 r#"{
                     "contentType": "application/json",
+                    "contentEncoding": "zstd",
                     "addValues": {
                         "/foo": "newFoo",
                         "/new": "new"
                     },
                     "schema": true,
                     "addRecordOffset": "/offset",
+                    "addArchiveEntry": "/entry",
                     "projections": {
                         "fee": "fi"
                     },
@@ -574,6 +730,7 @@ mod test {
             r#"{
                 "format": "json",
                 "contentType": "application/json",
+                "contentEncoding": "zstd",
                 "addValues": {
                     "/baz": 2,
                     "/foo": "newFoo",
@@ -581,6 +738,7 @@ mod test {
                 },
                 "schema": true,
                 "addRecordOffset": "/offset",
+                "addArchiveEntry": "/entry",
                 "projections": {
                     "weee": "wooo",
                     "fee": "fi"
@@ -0,0 +1,35 @@
+//! Configuration related to protobuf messages.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtobufConfig {
+    /// The fully-qualified name of the protobuf message type, for example `my.package.Event`.
+    /// This is required in order to parse protobuf messages.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// A base64 encoded `FileDescriptorSet` that includes the definition of the message type and
+    /// all of its dependencies, as produced by `protoc --include_imports --descriptor_set_out`.
+    /// Takes precedence over `fileDescriptorSetPath` if both are given.
+    #[serde(default)]
+    pub file_descriptor_set: Option<String>,
+    /// The path to a file containing a serialized `FileDescriptorSet`. This is an alternative to
+    /// providing the descriptors inline using `fileDescriptorSet`.
+    #[serde(default)]
+    pub file_descriptor_set_path: Option<String>,
+}
+
+impl ProtobufConfig {
+    pub fn merge(&mut self, other: &ProtobufConfig) {
+        if other.message.is_some() {
+            self.message = other.message.clone();
+        }
+        if other.file_descriptor_set.is_some() {
+            self.file_descriptor_set = other.file_descriptor_set.clone();
+        }
+        if other.file_descriptor_set_path.is_some() {
+            self.file_descriptor_set_path = other.file_descriptor_set_path.clone();
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Configuration related to XML documents.
+use super::ErrorThreshold;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmlConfig {
+    /// The path of the elements that represent each record, given as the names of the elements
+    /// from the root of the document separated by `/`. For example, `/feed/entry` will output a
+    /// JSON document for each `entry` element within the root `feed` element. This is required in
+    /// order to parse XML.
+    #[serde(default)]
+    pub record_path: Option<String>,
+    /// The prefix that's added to the names of attributes in order to distinguish them from child
+    /// elements. Defaults to `@`.
+    #[serde(default)]
+    pub attribute_prefix: Option<String>,
+    /// The property name used for the text content of elements that also have attributes or child
+    /// elements. Elements having only text content are output as plain strings. Defaults to
+    /// `#text`.
+    #[serde(default)]
+    pub text_key: Option<String>,
+    /// Allows a percentage of errors to be ignored without failing the entire
+    /// parsing process. When this limit is exceeded, parsing halts.
+    #[serde(default)]
+    pub error_threshold: Option<ErrorThreshold>,
+}
+
+impl XmlConfig {
+    pub fn merge(&mut self, other: &XmlConfig) {
+        if other.record_path.is_some() {
+            self.record_path = other.record_path.clone();
+        }
+        if other.attribute_prefix.is_some() {
+            self.attribute_prefix = other.attribute_prefix.clone();
+        }
+        if other.text_key.is_some() {
+            self.text_key = other.text_key.clone();
+        }
+        if other.error_threshold.is_some() {
+            self.error_threshold = other.error_threshold.clone();
+        }
+    }
+}
@@ -6,6 +6,9 @@ use serde_json::Value;
 pub struct Decorator {
     fields: Vec<(Pointer, Value)>,
     offset_location: Option<Pointer>,
+    /// Location and name of the archive entry that documents are being parsed from. This is only
+    /// set when parsing an entry of an archive and `add_archive_entry` is configured.
+    archive_entry: Option<(Pointer, String)>,
 }
 
 impl Decorator {
@@ -19,16 +22,41 @@ impl Decorator {
         Decorator {
             fields,
             offset_location,
+            archive_entry: None,
         }
     }
 
-    /// Adds the properties to the given `doc`. If any field cannot be added, this function returns
-    /// immediately with the first error, and leaves the document in a partially modified state.
-    pub fn add_fields(&self, record_offset: u64, doc: &mut Value) -> Result<(), AddFieldError> {
+    /// Returns a Decorator that will also add the given archive entry name to each document, if
+    /// the config has an `add_archive_entry` location.
+    pub fn for_archive_entry(mut self, config: &ParseConfig, entry_name: &str) -> Decorator {
+        self.archive_entry = config
+            .add_archive_entry
+            .as_ref()
+            .map(|ptr| (Pointer::from(ptr), entry_name.to_string()));
+        self
+    }
+
+    /// Adds the properties to the given `doc`. The `record_offset` is the offset of the document
+    /// within the entire output, while the `entry_offset` is the offset within the current archive
+    /// entry, if any. If any field cannot be added, this function returns immediately with the
+    /// first error, and leaves the document in a partially modified state.
+    pub fn add_fields(
+        &self,
+        record_offset: u64,
+        entry_offset: u64,
+        doc: &mut Value,
+    ) -> Result<(), AddFieldError> {
         if let Some(location) = self.offset_location.as_ref() {
             let value = Value::from(record_offset);
             add_field(doc, location, &value)?;
         }
+        if let Some((location, name)) = self.archive_entry.as_ref() {
+            let value = serde_json::json!({
+                "name": name,
+                "offset": entry_offset,
+            });
+            add_field(doc, location, &value)?;
+        }
         for (pointer, value) in self.fields.iter() {
             add_field(doc, pointer, value)?;
         }
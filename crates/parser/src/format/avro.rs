@@ -4,9 +4,10 @@ use crate::format::projection::{build_projections, Projection};
 use crate::format::{Output, ParseError, ParseResult, Parser};
 use crate::input::Input;
 use avro_rs::{schema::SchemaKind, types::Value as AvroValue, Reader, Schema};
-use chrono::{NaiveDateTime, NaiveTime};
+use chrono::{DateTime, NaiveDateTime, NaiveTime, SecondsFormat, Utc};
 use json::schema::types;
-use serde_json::Value;
+use num_bigint::{BigInt, Sign};
+use serde_json::{json, Value};
 use std::collections::BTreeMap;
 use std::io;
 
@@ -35,6 +36,12 @@ pub enum AvroError {
     /// it's distinctly possible for them to be out of range. This error is returned in that case.
     #[error("the column '{2}' value {0} is out of range for an avro {1}")]
     DateTimeOverflow(i64, &'static str, String),
+
+    #[error("invalid avro reader schema: {0}")]
+    InvalidReaderSchema(avro_rs::Error),
+
+    #[error("record cannot be resolved against the reader schema: {0}")]
+    Resolution(avro_rs::Error),
 }
 
 impl Parser for AvroParser {
@@ -47,28 +54,98 @@ impl Parser for AvroParser {
 struct AvroIter {
     reader: Reader<'static, Box<dyn io::BufRead>>,
     projections: BTreeMap<String, Projection>,
+    /// The schema of each field, which determines how logical types are converted.
+    field_schemas: BTreeMap<String, Schema>,
+    reader_schema: Option<ReaderSchema>,
+}
+
+/// A schema that each record is resolved against, which may differ from the writer schema.
+struct ReaderSchema {
+    schema: Schema,
+    /// Maps the aliases of top-level fields to the names of those fields.
+    aliases: BTreeMap<String, String>,
+}
+
+impl ReaderSchema {
+    fn parse(json: &Value) -> Result<ReaderSchema, AvroError> {
+        let schema = Schema::parse(json).map_err(AvroError::InvalidReaderSchema)?;
+        // The avro_rs crate doesn't parse the aliases of fields, so they're read from the JSON.
+        let mut aliases = BTreeMap::new();
+        let fields = json.get("fields").and_then(Value::as_array);
+        for field in fields.into_iter().flatten() {
+            let name = field.get("name").and_then(Value::as_str);
+            let field_aliases = field.get("aliases").and_then(Value::as_array);
+            if let (Some(name), Some(field_aliases)) = (name, field_aliases) {
+                for alias in field_aliases.iter().filter_map(Value::as_str) {
+                    aliases.insert(alias.to_string(), name.to_string());
+                }
+            }
+        }
+        Ok(ReaderSchema { schema, aliases })
+    }
+
+    /// Resolves a record that was written with a different schema. A field that's named by an alias
+    /// is renamed, unless the reader schema also has a field of that name.
+    fn resolve(&self, value: AvroValue) -> Result<AvroValue, AvroError> {
+        let value = match value {
+            AvroValue::Record(fields) => AvroValue::Record(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| match self.aliases.get(&name) {
+                        Some(field) if !self.has_field(&name) => (field.clone(), value),
+                        _ => (name, value),
+                    })
+                    .collect(),
+            ),
+            other => other,
+        };
+        value.resolve(&self.schema).map_err(AvroError::Resolution)
+    }
+
+    fn has_field(&self, name: &str) -> bool {
+        match &self.schema {
+            Schema::Record { lookup, .. } => lookup.contains_key(name),
+            _ => false,
+        }
+    }
 }
 
 impl AvroIter {
     fn from_config_and_input(config: &ParseConfig, content: Input) -> Result<AvroIter, ParseError> {
         let projections = build_projections(config)?;
+        let reader_schema = config
+            .avro
+            .as_ref()
+            .and_then(|avro| avro.reader_schema.as_ref())
+            .map(ReaderSchema::parse)
+            .transpose()
+            .map_err(|err| ParseError::Parse(Box::new(err)))?;
 
         let reader = Reader::new(content.into_buffered_stream(64 * 1024))
             .map_err(|err| ParseError::Parse(Box::new(err)))?;
 
         tracing::debug!(avro_writer_schema = ?reader.writer_schema(), "parsed avro header");
-        match reader.writer_schema() {
+        // Records are output with the fields of the reader schema, if there is one.
+        let schema = match reader_schema.as_ref() {
+            Some(reader_schema) => &reader_schema.schema,
+            None => reader.writer_schema(),
+        };
+        match schema {
             Schema::Record { fields, .. } => {
                 let mut resolved = BTreeMap::new();
+                let mut field_schemas = BTreeMap::new();
                 for field in fields {
                     let projection = projections.lookup(&field.name);
                     resolved.insert(field.name.clone(), projection);
+                    field_schemas.insert(field.name.clone(), field.schema.clone());
                 }
                 tracing::debug!(projections = ?resolved, "resolved projections for avro schema");
 
                 Ok(AvroIter {
                     reader,
                     projections: resolved,
+                    field_schemas,
+                    reader_schema,
                 })
             }
             other => Err(ParseError::Parse(Box::new(AvroError::NonRecordSchema(
@@ -89,7 +166,8 @@ impl AvroIter {
                 .possible_types
                 .map(|t| t.overlaps(types::STRING))
                 .unwrap_or(true);
-            let json_value = avro_to_json(&avro_key, avro_value, allow_string_repr)?;
+            let schema = self.field_schemas.get(&avro_key);
+            let json_value = avro_to_json(&avro_key, avro_value, schema, allow_string_repr)?;
 
             if let Some(loc) = projection.target_location.create(&mut json) {
                 *loc = json_value;
@@ -112,6 +190,10 @@ impl Iterator for AvroIter {
         let next = self.reader.next()?;
         let result = next
             .map_err(|e| AvroError::Read(e))
+            .and_then(|avro_value| match self.reader_schema.as_ref() {
+                Some(reader_schema) => reader_schema.resolve(avro_value),
+                None => Ok(avro_value),
+            })
             .and_then(|avro_value| match avro_value {
                 AvroValue::Record(fields) => self.record_to_json(fields),
                 other => Err(AvroError::NonRecordSchema(SchemaKind::from(&other))),
@@ -130,9 +212,20 @@ impl Iterator for AvroIter {
 /// more commonly represent as RFC3339 strings in JSON. The string representation is the default,
 /// but we also handle the case where the JSON schema doesn't permit strings in a particular
 /// location by converting the timestamp to a json number.
+///
+/// The string representations are:
+/// - `date`: an RFC3339 full-date, like `2021-06-15`.
+/// - `timestamp-millis` and `timestamp-micros`: an RFC3339 date-time in UTC, like
+///   `2021-06-15T12:30:00.123Z`.
+/// - `time-millis` and `time-micros`: a time of day without any offset, like `12:30:00.123`.
+/// - `decimal`: the exact decimal value, like `-123.45`, using the scale from the avro schema.
+/// - `uuid`: the hyphenated UUID.
+///
+/// Durations are always output as an object of `months`, `days`, and `millis`.
 fn avro_to_json(
     column_name: &str,
     avro_value: avro_rs::types::Value,
+    schema: Option<&Schema>,
     allow_string_repr: bool,
 ) -> Result<Value, AvroError> {
     use avro_rs::types::Value::*;
@@ -173,25 +266,51 @@ fn avro_to_json(
         Enum(_, s) => Ok(Value::String(s)),
         // union is just a wrapper around a boxed avro Value, so we just unwrap it here and
         // propagate `allow_string_repr`.
-        Union(boxed) => avro_to_json(column_name, *boxed, allow_string_repr),
+        Union(boxed) => {
+            let schema = match schema {
+                Some(Schema::Union(union)) => union.find_schema(&boxed).map(|(_, s)| s),
+                _ => None,
+            };
+            avro_to_json(column_name, *boxed, schema, allow_string_repr)
+        }
 
         // Within nested structures, we always allow types to be represented as strings, as that's
         // the more sane default. If we ever wanted to get fancy, we could try to resolve the full
         // pointer to each nested type and lookup the inferred type information, but I'm
         // considering that to not be worth the effort at this stage.
-        Array(items) => items
-            .into_iter()
-            .map(|i| avro_to_json(column_name, i, true))
-            .collect::<Result<Vec<_>, _>>()
-            .map(Value::Array),
-        Map(items) => items
-            .into_iter()
-            .map(|(k, v)| avro_to_json(column_name, v, true).map(|jv| (k, jv)))
-            .collect::<Result<serde_json::Map<_, _>, _>>()
-            .map(Value::Object),
+        Array(items) => {
+            let schema = match schema {
+                Some(Schema::Array(items)) => Some(items.as_ref()),
+                _ => None,
+            };
+            items
+                .into_iter()
+                .map(|i| avro_to_json(column_name, i, schema, true))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+        Map(items) => {
+            let schema = match schema {
+                Some(Schema::Map(values)) => Some(values.as_ref()),
+                _ => None,
+            };
+            items
+                .into_iter()
+                .map(|(k, v)| avro_to_json(column_name, v, schema, true).map(|jv| (k, jv)))
+                .collect::<Result<serde_json::Map<_, _>, _>>()
+                .map(Value::Object)
+        }
         Record(items) => items
             .into_iter()
-            .map(|(k, v)| avro_to_json(column_name, v, true).map(|jv| (k, jv)))
+            .map(|(k, v)| {
+                let schema = match schema {
+                    Some(Schema::Record { fields, lookup, .. }) => {
+                        lookup.get(&k).map(|i| &fields[*i].schema)
+                    }
+                    _ => None,
+                };
+                avro_to_json(column_name, v, schema, true).map(|jv| (k, jv))
+            })
             .collect::<Result<serde_json::Map<_, _>, _>>()
             .map(Value::Object),
 
@@ -230,25 +349,46 @@ fn avro_to_json(
             // avro durations are really weird. We always convert them to json objects, since
             // there's no obvious alternative representation because they encode a number of
             // _months_. I seriously doubt that anyone uses this goofy crap anyway.
-            Ok(serde_json::json!({
+            Ok(json!({
                 "months": u32::from(avro_dur.months()),
                 "days": u32::from(avro_dur.days()),
                 "millis": u32::from(avro_dur.millis()),
             }))
         }
         Uuid(uuid) => Ok(Value::String(uuid.to_string())),
-        // The decimal type in avro_rs does't allow access to the underlying value (which seems
-        // like a bug). So we just use the built-in converstion, which results in a json array of
-        // the underlying byte values. It probably doesn't matter much, since probably not many
-        // people are using these anyway.
-        other @ Decimal(_) => Ok(std::convert::TryFrom::try_from(other)
-            .expect("converting decimal value to json is infallible")),
+        // The decimal type in avro_rs does't allow direct access to the underlying value (which
+        // seems like a bug). But its conversion to json results in an array of the bytes of the
+        // unscaled value, which is combined with the scale from the schema.
+        other @ Decimal(_) => {
+            let bytes = match std::convert::TryFrom::try_from(other)
+                .expect("converting decimal value to json is infallible")
+            {
+                Value::Array(bytes) => bytes
+                    .iter()
+                    .filter_map(Value::as_u64)
+                    .map(|b| b as u8)
+                    .collect::<Vec<_>>(),
+                other => unreachable!("decimal converted to non-array json: {}", other),
+            };
+            let scale = match schema {
+                Some(Schema::Decimal { scale, .. }) => *scale as i64,
+                _ => 0,
+            };
+            let decimal = decimal_to_string(&BigInt::from_signed_bytes_be(&bytes), scale);
+            if allow_string_repr {
+                Ok(Value::String(decimal))
+            } else {
+                let num = serde_json::from_str::<serde_json::Number>(&decimal)
+                    .expect("decimal string is always a valid json number");
+                Ok(Value::Number(num))
+            }
+        }
     }
 }
 
-const MILLIS_PER_SEC: i64 = 1_000;
-const MICROS_PER_SEC: i64 = 1_000_000;
-const NANOS_PER_SEC: i64 = 1_000_000_000;
+pub(crate) const MILLIS_PER_SEC: i64 = 1_000;
+pub(crate) const MICROS_PER_SEC: i64 = 1_000_000;
+pub(crate) const NANOS_PER_SEC: i64 = 1_000_000_000;
 
 fn time_from_midnight(
     avro_type: &'static str,
@@ -272,7 +412,7 @@ fn time_from_midnight(
 /// parameter then accepts either `MICROS_PER_SEC` or `MILLIS_PER_SEC`, which is the constant used
 /// to normalize the duration to seconds and subsecond nanoseconds, which is the format required by
 /// the `chrono` crate.
-fn to_secs_and_nanos(duration: i64, units_per_sec: i64) -> (i64, u32) {
+pub(crate) fn to_secs_and_nanos(duration: i64, units_per_sec: i64) -> (i64, u32) {
     let mut secs = duration / units_per_sec;
     let rem = duration % units_per_sec;
     let mut nanos = (rem * NANOS_PER_SEC) / units_per_sec;
@@ -297,7 +437,113 @@ fn timestamp_from_unix_epoch(
     let (secs, nanos) = to_secs_and_nanos(duration, units_per_second);
     let ts = NaiveDateTime::from_timestamp_opt(secs, nanos)
         .ok_or_else(|| AvroError::DateTimeOverflow(duration, avro_type, column.to_string()))?;
-    Ok(Value::String(ts.to_string()))
+    let ts = DateTime::<Utc>::from_utc(ts, Utc);
+    Ok(Value::String(
+        ts.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    ))
+}
+
+/// Returns the exact decimal representation of an `unscaled` integer value, where the `scale` is
+/// the number of digits after the decimal point. A negative scale multiplies the value by a power
+/// of 10.
+pub(crate) fn decimal_to_string(unscaled: &BigInt, scale: i64) -> String {
+    let sign = if unscaled.sign() == Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    let mut digits = unscaled.magnitude().to_string();
+
+    if scale <= 0 {
+        digits.extend(std::iter::repeat('0').take(scale.unsigned_abs() as usize));
+        return format!("{}{}", sign, digits);
+    }
+    let scale = scale as usize;
+    if digits.len() <= scale {
+        digits = format!("{:0>width$}", digits, width = scale + 1);
+    }
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int_part, frac_part)
+}
+
+/// Returns a JSON schema for the documents that are output for avro content, based on the reader
+/// schema if one is configured, and otherwise the writer schema of the content. Logical types are
+/// described with `format` annotations, which assumes that they're output as strings.
+pub fn infer_schema(config: &ParseConfig, content: Input) -> Result<Value, ParseError> {
+    let reader_schema = config
+        .avro
+        .as_ref()
+        .and_then(|avro| avro.reader_schema.as_ref());
+    if let Some(json) = reader_schema {
+        let reader_schema =
+            ReaderSchema::parse(json).map_err(|err| ParseError::Parse(Box::new(err)))?;
+        return Ok(to_json_schema(&reader_schema.schema));
+    }
+    let reader = Reader::new(content.into_buffered_stream(64 * 1024))
+        .map_err(|err| ParseError::Parse(Box::new(err)))?;
+    Ok(to_json_schema(reader.writer_schema()))
+}
+
+fn to_json_schema(schema: &Schema) -> Value {
+    match schema {
+        Schema::Null => json!({"type": "null"}),
+        Schema::Boolean => json!({"type": "boolean"}),
+        Schema::Int | Schema::Long => json!({"type": "integer"}),
+        Schema::Float | Schema::Double => json!({"type": "number"}),
+        Schema::Bytes | Schema::Fixed { .. } => {
+            json!({"type": "string", "contentEncoding": "base64"})
+        }
+        Schema::String => json!({"type": "string"}),
+        Schema::Enum { symbols, .. } => json!({"type": "string", "enum": symbols}),
+        Schema::Array(items) => json!({"type": "array", "items": to_json_schema(items)}),
+        Schema::Map(values) => {
+            json!({"type": "object", "additionalProperties": to_json_schema(values)})
+        }
+        Schema::Record { fields, .. } => {
+            let properties = fields
+                .iter()
+                .map(|field| (field.name.clone(), to_json_schema(&field.schema)))
+                .collect::<serde_json::Map<_, _>>();
+            let required = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+            json!({"type": "object", "properties": properties, "required": required})
+        }
+        Schema::Union(union) => {
+            let variants = union.variants();
+            let non_null = variants
+                .iter()
+                .filter(|v| !matches!(v, Schema::Null))
+                .collect::<Vec<_>>();
+            match non_null.as_slice() {
+                // The most common union is of null and a single other type, which is represented
+                // by adding "null" to the allowed types of the other.
+                [other] if non_null.len() < variants.len() => {
+                    let mut schema = to_json_schema(other);
+                    if let Some(ty) = schema.get_mut("type") {
+                        *ty = json!([ty.take(), "null"]);
+                    }
+                    schema
+                }
+                _ => json!({"anyOf": variants.iter().map(to_json_schema).collect::<Vec<_>>()}),
+            }
+        }
+        Schema::Date => json!({"type": "string", "format": "date"}),
+        // Avro times have no offset, which a JSON schema `time` would require.
+        Schema::TimeMillis | Schema::TimeMicros => json!({"type": "string"}),
+        Schema::TimestampMillis | Schema::TimestampMicros => {
+            json!({"type": "string", "format": "date-time"})
+        }
+        Schema::Uuid => json!({"type": "string", "format": "uuid"}),
+        Schema::Decimal { .. } => json!({"type": "string", "format": "number"}),
+        Schema::Duration => json!({
+            "type": "object",
+            "properties": {
+                "months": {"type": "integer"},
+                "days": {"type": "integer"},
+                "millis": {"type": "integer"},
+            },
+            "required": ["months", "days", "millis"],
+        }),
+    }
 }
 
 #[cfg(test)]
@@ -440,8 +686,122 @@ mod test {
             "time":28000000,
             "time_string":"07:46:40",
             "timestamp":1607483647000i64,
-            "timestamp_string":"2020-12-09 03:14:07"
+            "timestamp_string":"2020-12-09T03:14:07Z"
         });
         assert_eq!(expected, json);
     }
+
+    #[test]
+    fn records_are_resolved_against_the_reader_schema() {
+        let writer_schema = avro_rs::Schema::parse_str(
+            r#"{
+                "name": "item",
+                "type": "record",
+                "fields": [
+                    {"name": "id", "type": "int"},
+                    {"name": "label", "type": "string"},
+                    {"name": "removed", "type": "string"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut writer = Writer::new(&writer_schema, Vec::new());
+        let mut record = Record::new(&writer_schema).unwrap();
+        record.put("id", 7);
+        record.put("label", "seven");
+        record.put("removed", "gone");
+        writer.append(record).expect("failed to write");
+        let bytes = writer.into_inner().expect("failed to flush");
+
+        let config = ParseConfig {
+            avro: Some(crate::avro::AvroConfig {
+                reader_schema: Some(serde_json::json!({
+                    "name": "item",
+                    "type": "record",
+                    "fields": [
+                        {"name": "id", "type": "long"},
+                        {"name": "name", "type": "string", "aliases": ["label"]},
+                        {"name": "active", "type": "boolean", "default": true}
+                    ]
+                })),
+            }),
+            ..Default::default()
+        };
+        let input = Input::Stream(Box::new(std::io::Cursor::new(bytes)));
+        let mut iter = AvroIter::from_config_and_input(&config, input).expect("parse failed");
+        let json = iter.next().expect("next result").expect("next document");
+        assert_eq!(
+            serde_json::json!({"id": 7, "name": "seven", "active": true}),
+            json
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn logical_types_are_converted_and_described_by_the_inferred_schema() {
+        let schema = avro_rs::Schema::parse(&json!({
+            "name": "payment",
+            "type": "record",
+            "fields": [
+                {
+                    "name": "amount",
+                    "type": {"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2}
+                },
+                {
+                    "name": "fee",
+                    "type": ["null", {"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 3}]
+                },
+                {"name": "created", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+                {"name": "id", "type": {"type": "string", "logicalType": "uuid"}}
+            ]
+        }))
+        .unwrap();
+
+        let mut writer = Writer::new(&schema, Vec::new());
+        let mut record = Record::new(&schema).unwrap();
+        let decimal =
+            |unscaled: i64| AvroValue::Decimal(BigInt::from(unscaled).to_signed_bytes_be().into());
+        record.put("amount", decimal(-12345));
+        record.put("fee", AvroValue::Union(Box::new(decimal(5))));
+        record.put("created", AvroValue::TimestampMicros(1_623_760_200_123_456));
+        record.put("day", AvroValue::Date(18_793));
+        record.put(
+            "id",
+            AvroValue::Uuid("d5b0c5a4-3c5e-4f69-9a4a-0a8e8f6b1c2d".parse().unwrap()),
+        );
+        writer.append(record).expect("failed to write");
+        let bytes = writer.into_inner().expect("failed to flush");
+
+        let input = || Input::Stream(Box::new(std::io::Cursor::new(bytes.clone())));
+        let config = ParseConfig::default();
+        let mut iter = AvroIter::from_config_and_input(&config, input()).expect("parse failed");
+        let json = iter.next().expect("next result").expect("next document");
+        assert_eq!(
+            json!({
+                "amount": "-123.45",
+                "fee": "0.005",
+                "created": "2021-06-15T12:30:00.123456Z",
+                "day": "2021-06-15",
+                "id": "d5b0c5a4-3c5e-4f69-9a4a-0a8e8f6b1c2d",
+            }),
+            json
+        );
+
+        let inferred = infer_schema(&config, input()).expect("failed to infer schema");
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "amount": {"type": "string", "format": "number"},
+                    "fee": {"type": ["string", "null"], "format": "number"},
+                    "created": {"type": "string", "format": "date-time"},
+                    "day": {"type": "string", "format": "date"},
+                    "id": {"type": "string", "format": "uuid"},
+                },
+                "required": ["amount", "fee", "created", "day", "id"],
+            }),
+            inferred
+        );
+    }
 }
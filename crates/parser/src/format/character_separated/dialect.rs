@@ -0,0 +1,367 @@
+//! Infers the dialect of character-separated content, meaning the delimiter, quote and escape
+//! characters, line ending, and whether the first row contains headers.
+use crate::config::csv::{Char, CharacterSeparatedConfig, LineEnding};
+use crate::config::EncodingRef;
+use crate::format::Format;
+use crate::input::detect_encoding;
+use std::collections::BTreeMap;
+
+/// The delimiters that are considered, in order of preference when they're equally plausible.
+const CSV_DELIMITERS: &[u8] = &[b',', b'\t', b';', b'|'];
+const TSV_DELIMITERS: &[u8] = &[b'\t', b',', b';', b'|'];
+
+/// The quote characters that are considered, in order of preference.
+const QUOTES: &[Option<u8>] = &[Some(b'"'), Some(b'\''), None];
+
+/// The maximum number of rows that are inspected.
+const MAX_SAMPLE_ROWS: usize = 100;
+
+#[derive(Debug, PartialEq)]
+pub struct Dialect {
+    /// The encoding of the content, if it could be told apart from ASCII.
+    pub encoding: Option<EncodingRef>,
+    pub delimiter: u8,
+    pub quote: Option<u8>,
+    pub escape: Option<u8>,
+    pub line_ending: LineEnding,
+    /// The number of columns in the first row, if the first row contains data instead of headers.
+    pub headerless_columns: Option<usize>,
+}
+
+impl Dialect {
+    /// Returns the configuration that corresponds to this dialect. Content without a header row
+    /// gets generated column names of `column1`, `column2`, etc.
+    pub fn to_config(&self) -> CharacterSeparatedConfig {
+        let headers = self
+            .headerless_columns
+            .map(|n| (1..=n).map(|i| format!("column{}", i)).collect())
+            .unwrap_or_default();
+        CharacterSeparatedConfig {
+            headers,
+            delimiter: Some(Char(self.delimiter)),
+            line_ending: Some(self.line_ending),
+            quote: self.quote.map(Char),
+            escape: self.escape.map(Char),
+            encoding: self.encoding,
+            ..Default::default()
+        }
+    }
+
+    /// Sets each option of the `config` which is unset to that of this dialect, such that it
+    /// covers exactly the options of `to_config`. The exception is generated headers, which are
+    /// used only if the `config` enables `detect_headers`, since otherwise the first row has
+    /// always been used as the column names.
+    pub fn fill_unset(&self, config: &mut CharacterSeparatedConfig) {
+        let mut sniffed = self.to_config();
+
+        match self.headerless_columns {
+            Some(columns) if config.headers.is_empty() => {
+                if config.detect_headers == Some(true) {
+                    tracing::info!(
+                        columns,
+                        "the first row appears to be data, so generated column names will be used"
+                    );
+                } else {
+                    tracing::info!(
+                        columns,
+                        "the first row appears to be data, but detectHeaders is not enabled"
+                    );
+                    sniffed.headers.clear();
+                }
+            }
+            _ => (),
+        }
+        sniffed.merge(config);
+        *config = sniffed;
+    }
+}
+
+/// Infers the dialect of the `sample`, which is taken from the start of the content. If the
+/// sample is not `complete`, then its last line is assumed to be truncated, and is ignored.
+pub fn sniff_dialect(format: Format, sample: &[u8], complete: bool) -> Dialect {
+    // ASCII is valid in nearly every encoding, so an ASCII sample says nothing about the encoding
+    // of the rest of the content, which is left for the parser to detect.
+    let encoding = if sample.is_ascii() {
+        None
+    } else {
+        Some(detect_encoding(sample))
+    };
+    let (text, _) = encoding
+        .unwrap_or_else(|| EncodingRef::from(encoding_rs::UTF_8))
+        .encoding()
+        .decode_with_bom_removal(sample);
+    let text = if complete {
+        &text[..]
+    } else {
+        text.rfind('\n').map(|i| &text[..=i]).unwrap_or(&text[..])
+    };
+
+    let line_ending = if text.contains("\r\n") || !text.contains(['\r', '\n'].as_ref()) {
+        LineEnding::CRLF
+    } else if text.contains('\n') {
+        LineEnding::Other(Char(b'\n'))
+    } else {
+        LineEnding::Other(Char(b'\r'))
+    };
+    let lines = text
+        .split(|c| c == '\n' || c == '\r')
+        .filter(|line| !line.is_empty())
+        .take(MAX_SAMPLE_ROWS)
+        .collect::<Vec<_>>();
+
+    let delimiters = if format == Format::Tsv {
+        TSV_DELIMITERS
+    } else {
+        CSV_DELIMITERS
+    };
+    let mut best = Candidate {
+        delimiter: delimiters[0],
+        quote: None,
+        score: 0.0,
+        rows: Vec::new(),
+    };
+    for &delimiter in delimiters {
+        for &quote in QUOTES {
+            // Only consider quote characters that actually begin a value somewhere in the sample.
+            if let Some(q) = quote {
+                let after_delimiter = format!("{}{}", delimiter as char, q as char);
+                let quoted = lines
+                    .iter()
+                    .any(|l| l.as_bytes()[0] == q || l.contains(&after_delimiter));
+                if !quoted {
+                    continue;
+                }
+            }
+            let rows = read_rows(text, delimiter, quote);
+            let score = consistency(&rows);
+            if score > best.score {
+                best = Candidate {
+                    delimiter,
+                    quote,
+                    score,
+                    rows,
+                };
+            }
+        }
+    }
+    if best.rows.is_empty() {
+        best.rows = read_rows(text, best.delimiter, best.quote);
+    }
+
+    // Backslash escapes are only recognized when they precede a quote.
+    let escape = best
+        .quote
+        .filter(|q| text.contains(&format!("\\{}", *q as char)))
+        .map(|_| b'\\');
+    let headerless_columns = match best.rows.first() {
+        Some(first) if !has_header(&best.rows) => Some(first.len()),
+        _ => None,
+    };
+
+    Dialect {
+        encoding,
+        delimiter: best.delimiter,
+        quote: best.quote,
+        escape,
+        line_ending,
+        headerless_columns,
+    }
+}
+
+/// A combination of delimiter and quote character, along with the rows that result from using it.
+struct Candidate {
+    delimiter: u8,
+    quote: Option<u8>,
+    score: f64,
+    rows: Vec<Vec<String>>,
+}
+
+/// Reads up to `MAX_SAMPLE_ROWS` rows using the given delimiter and quote. Reading stops at the
+/// first error, since that means the candidate is unlikely to be correct.
+fn read_rows(text: &str, delimiter: u8, quote: Option<u8>) -> Vec<Vec<String>> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote.unwrap_or(b'"'))
+        .quoting(quote.is_some())
+        .from_reader(text.as_bytes())
+        .into_records()
+        .take(MAX_SAMPLE_ROWS)
+        .map_while(Result::ok)
+        .map(|record| record.iter().map(String::from).collect())
+        .collect()
+}
+
+/// Scores the rows by the proportion of them that have the most common number of columns. Rows
+/// with only one column mean that the delimiter doesn't appear at all, and so score 0.
+fn consistency(rows: &[Vec<String>]) -> f64 {
+    let mut frequencies = BTreeMap::new();
+    for row in rows {
+        *frequencies.entry(row.len()).or_insert(0usize) += 1;
+    }
+    match frequencies.into_iter().max_by_key(|(_, n)| *n) {
+        Some((columns, n)) if columns > 1 => n as f64 / rows.len() as f64,
+        _ => 0.0,
+    }
+}
+
+/// Decides whether the first row is a header. Headers are assumed to be present unless the first
+/// row is clearly data, meaning that each of its values that sits above a column of numbers is
+/// itself a number. Rows of text alone are never considered clear enough, since they look the same
+/// as a header row.
+fn has_header(rows: &[Vec<String>]) -> bool {
+    let (first, data) = match rows.split_first() {
+        Some((first, data)) if !data.is_empty() => (first, data),
+        _ => return true,
+    };
+
+    let mut numeric_columns = 0;
+    for (i, header) in first.iter().enumerate() {
+        let mut values = data
+            .iter()
+            .filter_map(|row| row.get(i))
+            .filter(|v| !v.is_empty())
+            .peekable();
+        if values.peek().is_none() || !values.all(|v| is_number(v)) {
+            continue;
+        }
+        if !is_number(header) {
+            return true;
+        }
+        numeric_columns += 1;
+    }
+    numeric_columns == 0
+}
+
+fn is_number(value: &str) -> bool {
+    value.trim().parse::<f64>().is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dialects_are_sniffed() {
+        let dialect = sniff_dialect(
+            Format::Csv,
+            b"id;name;note\n1;\"Smith; J\";\"say \\\"hi\\\"\"\n2;Ann;x\n3;Bo",
+            false,
+        );
+        assert_eq!(
+            Dialect {
+                encoding: None,
+                delimiter: b';',
+                quote: Some(b'"'),
+                escape: Some(b'\\'),
+                line_ending: LineEnding::Other(Char(b'\n')),
+                headerless_columns: None,
+            },
+            dialect
+        );
+
+        let dialect = sniff_dialect(Format::Tsv, b"1\tfoo|bar\r\n2\tbaz|qux\r\n", true);
+        assert_eq!(b'\t', dialect.delimiter);
+        assert_eq!(None, dialect.quote);
+        assert_eq!(LineEnding::CRLF, dialect.line_ending);
+        assert_eq!(Some(2), dialect.headerless_columns);
+        assert_eq!(
+            vec!["column1".to_string(), "column2".to_string()],
+            dialect.to_config().headers
+        );
+    }
+
+    #[test]
+    fn unset_options_are_filled_with_the_dialect() {
+        let dialect = sniff_dialect(Format::Csv, "a;b\n\"é\";2\n".as_bytes(), true);
+        assert!(dialect.encoding.is_some());
+
+        let mut config = CharacterSeparatedConfig::default();
+        dialect.fill_unset(&mut config);
+        assert_eq!(dialect.to_config(), config);
+
+        let latin1 = EncodingRef::from(encoding_rs::WINDOWS_1252);
+        let mut config = CharacterSeparatedConfig {
+            delimiter: Some(Char(b',')),
+            encoding: Some(latin1),
+            ..Default::default()
+        };
+        dialect.fill_unset(&mut config);
+        assert_eq!(
+            CharacterSeparatedConfig {
+                delimiter: Some(Char(b',')),
+                encoding: Some(latin1),
+                ..dialect.to_config()
+            },
+            config
+        );
+    }
+
+    #[test]
+    fn generated_headers_are_used_only_if_enabled() {
+        let dialect = sniff_dialect(Format::Csv, b"10,AB\n1,CD\n2,EF\n", true);
+        assert_eq!(Some(2), dialect.headerless_columns);
+
+        let mut config = CharacterSeparatedConfig::default();
+        dialect.fill_unset(&mut config);
+        assert!(config.headers.is_empty());
+
+        for detect_headers in &[Some(false), Some(true)] {
+            let mut config = CharacterSeparatedConfig {
+                detect_headers: *detect_headers,
+                ..Default::default()
+            };
+            dialect.fill_unset(&mut config);
+            assert_eq!(
+                *detect_headers == Some(true),
+                !config.headers.is_empty(),
+                "detect_headers: {:?}",
+                detect_headers
+            );
+        }
+
+        // Configured headers are always used.
+        let mut config = CharacterSeparatedConfig {
+            headers: vec!["a".to_string(), "b".to_string()],
+            detect_headers: Some(true),
+            ..Default::default()
+        };
+        dialect.fill_unset(&mut config);
+        assert_eq!(vec!["a".to_string(), "b".to_string()], config.headers);
+    }
+
+    #[test]
+    fn delimiters_within_quotes_are_not_counted() {
+        // Counting commas would suggest a comma delimiter, but they're all within quoted values.
+        let sample = b"name|desc\n'a'|'x, y, z'\n'b'|'1, 2'\n'c'|'it''s, ok'\n";
+        let dialect = sniff_dialect(Format::Csv, sample, true);
+        assert_eq!(b'|', dialect.delimiter);
+        assert_eq!(Some(b'\''), dialect.quote);
+        assert_eq!(None, dialect.headerless_columns);
+    }
+
+    #[test]
+    fn header_presence_is_detected() {
+        let cases: &[(&[u8], bool)] = &[
+            (b"id,code\n1,AB\n2,CD\n", true),
+            (b"10,AB\n1,CD\n2,EF\n", false),
+            (b"1.5,2021,x\n2.5,2022,y\n", false),
+            // Text alone isn't enough to tell a header apart from data.
+            (b"ab,cd\nef,gh\nij,kl\n", true),
+            (b"name,city\nann,Paris\nbob,Oslo\n", true),
+            (b"x,x\n1,2\n", true),
+            // A text value above a column of numbers is a header, even if others are numbers.
+            (b"id,2021\n1,5\n2,6\n", true),
+        ];
+        for (sample, expected) in cases {
+            let dialect = sniff_dialect(Format::Csv, sample, true);
+            assert_eq!(
+                *expected,
+                dialect.headerless_columns.is_none(),
+                "sample: {}",
+                String::from_utf8_lossy(sample)
+            );
+        }
+    }
+}
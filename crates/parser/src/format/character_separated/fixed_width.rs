@@ -0,0 +1,374 @@
+//! Fixed-width (positional) text files, where each line is a record and each column occupies the
+//! same position within every line. These are common for exports from mainframes and other legacy
+//! systems. Values are padded to the width of their column, and the padding is removed before the
+//! values are parsed the same way as CSV values.
+use super::{resolve_headers, row_to_json, Coercion, Header};
+use crate::config::fixed_width::{Trim, WidthUnit};
+use crate::config::ParseConfig;
+use crate::format::error_buffer::ParseErrorBuffer;
+use crate::format::projection::build_projections;
+use crate::format::{Output, ParseError, ParseResult, Parser, RecordError, Rejections};
+use crate::input::{detect_encoding, Input};
+use encoding_rs::Encoding;
+use std::borrow::Cow;
+use std::io::BufRead;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("the fixed-width format requires at least one column to be configured")]
+    MissingColumns,
+
+    #[error("line {0} is not valid utf-8: {1}")]
+    InvalidEncoding(u64, std::str::Utf8Error),
+}
+
+pub fn new_fixed_width_parser() -> Box<dyn Parser> {
+    Box::new(FixedWidthParser)
+}
+pub struct FixedWidthParser;
+
+impl Parser for FixedWidthParser {
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        self.parse_with_rejections(config, content, Rejections::discard())
+    }
+
+    fn parse_with_rejections(
+        &self,
+        config: &ParseConfig,
+        content: Input,
+        rejections: Rejections,
+    ) -> Result<Output, ParseError> {
+        let fw_config = config.fixed_width.clone().unwrap_or_default();
+        if fw_config.columns.is_empty() {
+            return Err(ParseError::Parse(Box::new(Error::MissingColumns)));
+        }
+        let projections = build_projections(config)?;
+
+        let (prefix, input) = content.peek(2048)?;
+        let encoding = fw_config
+            .encoding
+            .unwrap_or_else(|| detect_encoding(&prefix));
+        let units = fw_config.units.unwrap_or(WidthUnit::Characters);
+        // Character offsets are applied after transcoding the whole file into UTF-8. Byte offsets
+        // must be applied to the original bytes, so each value is decoded separately instead.
+        let (input, field_encoding) = match units {
+            WidthUnit::Characters => (
+                input.transcode_non_utf8(Some(encoding), 0)?,
+                encoding_rs::UTF_8,
+            ),
+            WidthUnit::Bytes => (input, encoding.encoding()),
+        };
+
+        let names = fw_config.columns.iter().map(|c| c.name.clone()).collect();
+        let headers = resolve_headers(names, &projections, Coercion::default());
+        let default_trim = fw_config.trim.unwrap_or(Trim::Both);
+        let columns = fw_config
+            .columns
+            .iter()
+            .map(|c| Column {
+                start: c.start,
+                width: c.width,
+                trim: c.trim.unwrap_or(default_trim),
+            })
+            .collect();
+
+        let output = FixedWidthOutput {
+            reader: input.into_buffered_stream(64 * 1024),
+            headers,
+            columns,
+            units,
+            field_encoding,
+            padding: fw_config.padding.map(|c| c.0 as char).unwrap_or(' '),
+            skip_lines: fw_config.skip_lines.unwrap_or_default(),
+            line_num: 0,
+            offset: 0,
+            line: Vec::new(),
+        };
+        let iterator = if let Some(threshold) = fw_config.error_threshold {
+            Box::new(ParseErrorBuffer::new(output, threshold, rejections)) as Output
+        } else {
+            Box::new(output) as Output
+        };
+        Ok(iterator)
+    }
+}
+
+/// The position of a column within each line, with the trim rule resolved from the config.
+struct Column {
+    start: usize,
+    width: usize,
+    trim: Trim,
+}
+
+struct FixedWidthOutput {
+    reader: Box<dyn BufRead>,
+    /// The headers for each column, in the same order as `columns`.
+    headers: Vec<Header>,
+    columns: Vec<Column>,
+    units: WidthUnit,
+    /// The encoding of each value that's sliced from a line. This is only relevant when using byte
+    /// offsets, since the input is otherwise transcoded into UTF-8 up front.
+    field_encoding: &'static Encoding,
+    padding: char,
+    skip_lines: u64,
+    /// The number of the current line within the file, starting at 1.
+    line_num: u64,
+    /// The byte offset of the start of the next line.
+    offset: u64,
+    line: Vec<u8>,
+}
+
+impl FixedWidthOutput {
+    fn parse_current_line(&self) -> Result<serde_json::Value, ParseError> {
+        let values = match self.units {
+            WidthUnit::Characters => {
+                let line = std::str::from_utf8(&self.line).map_err(|err| {
+                    ParseError::Parse(Box::new(Error::InvalidEncoding(self.line_num, err)))
+                })?;
+                self.columns
+                    .iter()
+                    .map(|c| slice_chars(line, c.start, c.width).map(Cow::Borrowed))
+                    .collect::<Vec<_>>()
+            }
+            WidthUnit::Bytes => self
+                .columns
+                .iter()
+                .map(|c| {
+                    slice_bytes(&self.line, c.start, c.width)
+                        .map(|b| self.field_encoding.decode_without_bom_handling(b).0)
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        let trimmed = values
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(value, column)| {
+                value
+                    .as_deref()
+                    .map(|v| trim_value(v, self.padding, column.trim))
+            });
+        row_to_json(&self.headers, self.line_num, trimmed)
+    }
+}
+
+impl Iterator for FixedWidthOutput {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            let line_offset = self.offset;
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(n) => self.offset += n as u64,
+                Err(err) => return Some(Err(err.into())),
+            }
+            self.line_num += 1;
+            if self.line_num <= self.skip_lines {
+                continue;
+            }
+
+            if self.line.ends_with(b"\n") {
+                self.line.pop();
+            }
+            if self.line.ends_with(b"\r") {
+                self.line.pop();
+            }
+            // Blank lines don't contain any columns, so they're skipped rather than being output
+            // as empty documents.
+            if self.line.is_empty() {
+                continue;
+            }
+            let result = self.parse_current_line().map_err(|error| {
+                RecordError {
+                    error,
+                    byte_offset: Some(line_offset),
+                    line: Some(self.line_num),
+                    raw: Some(self.line.clone()),
+                }
+                .into()
+            });
+            return Some(result);
+        }
+    }
+}
+
+/// Returns the `width` characters of `line` that begin at the character offset `start`, or `None`
+/// if the line ends before `start`. Lines that end partway through the column will return only the
+/// characters that are present.
+fn slice_chars(line: &str, start: usize, width: usize) -> Option<&str> {
+    let mut offsets = line
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(line.len()));
+    let begin = offsets.nth(start).filter(|i| *i < line.len())?;
+    let end = if width == 0 {
+        begin
+    } else {
+        offsets.nth(width - 1).unwrap_or(line.len())
+    };
+    Some(&line[begin..end])
+}
+
+/// The same as `slice_chars`, except that `start` and `width` are in bytes.
+fn slice_bytes(line: &[u8], start: usize, width: usize) -> Option<&[u8]> {
+    if start >= line.len() {
+        return None;
+    }
+    let end = line.len().min(start.saturating_add(width));
+    Some(&line[start..end])
+}
+
+fn trim_value(value: &str, padding: char, trim: Trim) -> &str {
+    match trim {
+        Trim::None => value,
+        Trim::Start => value.trim_start_matches(padding),
+        Trim::End => value.trim_end_matches(padding),
+        Trim::Both => value.trim_matches(padding),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::csv::Char;
+    use crate::config::fixed_width::{FixedWidthColumn, FixedWidthConfig};
+    use crate::config::EncodingRef;
+    use serde_json::{json, Value};
+
+    fn column(name: &str, start: usize, width: usize) -> FixedWidthColumn {
+        FixedWidthColumn {
+            name: name.to_string(),
+            start,
+            width,
+            trim: None,
+        }
+    }
+
+    fn parse(config: &ParseConfig, content: impl Into<Vec<u8>>) -> Vec<Result<Value, ParseError>> {
+        let input = Input::Stream(Box::new(std::io::Cursor::new(content.into())));
+        new_fixed_width_parser()
+            .parse(config, input)
+            .expect("failed to init parser")
+            .collect()
+    }
+
+    #[test]
+    fn fixed_width_lines_are_parsed() {
+        let config = ParseConfig {
+            fixed_width: Some(FixedWidthConfig {
+                columns: vec![
+                    column("id", 0, 5),
+                    column("name", 5, 10),
+                    column("balance", 15, 8),
+                ],
+                skip_lines: Some(1),
+                ..Default::default()
+            }),
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer" },
+                    "balance": { "type": ["number", "null"] }
+                },
+                "required": ["id"]
+            }),
+            ..Default::default()
+        };
+        let line =
+            |id: &str, name: &str, balance: &str| format!("{:>5}{:<10}{:>8}", id, name, balance);
+        let content = format!(
+            "ID   NAME      BALANCE\n{}\r\n\n{}\n{}\n",
+            line("1", "Zoë", "12.5"),
+            line("2", "Bob", ""),
+            "    3Al",
+        );
+        let results = parse(&config, content)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse");
+        assert_eq!(
+            vec![
+                json!({"id": 1, "name": "Zoë", "balance": 12.5}),
+                json!({"id": 2, "name": "Bob", "balance": null}),
+                json!({"id": 3, "name": "Al"}),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn byte_offsets_are_applied_before_decoding() {
+        let config = ParseConfig {
+            fixed_width: Some(FixedWidthConfig {
+                columns: vec![
+                    FixedWidthColumn {
+                        trim: Some(Trim::None),
+                        ..column("code", 0, 3)
+                    },
+                    column("city", 3, 8),
+                ],
+                units: Some(WidthUnit::Bytes),
+                padding: Some(Char(b'_')),
+                trim: Some(Trim::End),
+                encoding: Some(EncodingRef::from(encoding_rs::WINDOWS_1252)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // "Malmö" in windows-1252 is 5 bytes, padded to 8 bytes.
+        let mut content = b"_01Malm\xF6___\n".to_vec();
+        content.extend_from_slice(b"002__Oslo\n");
+        let results = parse(&config, content)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse");
+        assert_eq!(
+            vec![
+                json!({"code": "_01", "city": "Malmö"}),
+                json!({"code": "002", "city": "__Oslo"}),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn missing_required_columns_are_errors() {
+        let config = ParseConfig {
+            fixed_width: Some(FixedWidthConfig {
+                columns: vec![column("a", 0, 2), column("b", 2, 2)],
+                ..Default::default()
+            }),
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "b": { "type": "string" }
+                },
+                "required": ["b"]
+            }),
+            ..Default::default()
+        };
+        let results = parse(&config, "aabb\naa\n");
+        assert_eq!(json!({"a": "aa", "b": "bb"}), *results[0].as_ref().unwrap());
+        let err = results[1].as_ref().expect_err("expected an error");
+        assert!(
+            err.to_string().contains("missing required column"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn slicing_by_characters() {
+        for (line, start, width, expected) in &[
+            ("abc", 0, 2, Some("ab")),
+            ("abc", 1, 5, Some("bc")),
+            ("abc", 3, 1, None),
+            ("abc", 1, 0, Some("")),
+            ("añb", 1, 1, Some("ñ")),
+        ] {
+            assert_eq!(*expected, slice_chars(line, *start, *width));
+        }
+    }
+}
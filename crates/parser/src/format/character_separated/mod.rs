@@ -1,24 +1,32 @@
 //! Parsers for character-separated formats like csv.
 
-mod error_buffer;
+mod dialect;
+mod fixed_width;
 mod w3c_extended_log;
 
-use self::error_buffer::ParseErrorBuffer;
 use crate::config::{
     csv::{CharacterSeparatedConfig, LineEnding},
     ParseConfig,
 };
+use crate::format::error_buffer::ParseErrorBuffer;
 use crate::format::projection::{build_projections, Projection, Projections};
-use crate::format::{Format, Output, ParseError, ParseResult, Parser};
-use crate::input::{detect_encoding, Input};
+use crate::format::{Format, Output, ParseError, Parser, RecordError, Rejections};
+use crate::input::{detect_encoding, Input, RawBytes, RecordingReader};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
 use csv::{Reader, StringRecord, Terminator};
 use json::schema::types;
 use serde_json::Value;
 use std::io;
+use std::rc::Rc;
 
 /// Returns a parser for the [W3C extended log format](https://www.w3.org/TR/WD-logfile.html)
 pub use self::w3c_extended_log::new_w3c_extended_log_parser;
 
+/// Returns a parser for fixed-width (positional) text.
+pub use self::fixed_width::new_fixed_width_parser;
+
+pub use self::dialect::sniff_dialect;
+
 /// Returns a Parser for the comma-separated values format.
 pub fn new_csv_parser() -> Box<dyn Parser> {
     Box::new(CsvParser {
@@ -75,13 +83,24 @@ fn detect_quote_char(delimiter: u8, peeked: &[u8]) -> Option<u8> {
 
 impl Parser for CsvParser {
     fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        self.parse_with_rejections(config, content, Rejections::discard())
+    }
+
+    fn parse_with_rejections(
+        &self,
+        config: &ParseConfig,
+        content: Input,
+        rejections: Rejections,
+    ) -> Result<Output, ParseError> {
         let projections = build_projections(config)?;
         let user_provided_config = get_config(self.format, config).cloned().unwrap_or_default();
         // Transcode into UTF-8 before attempting to parse the CSV. This simplifies a lot, since
         // our ultimate target is JSON in UTF-8, and since the configuration is also provided as
         // JSON in UTF-8.
         let (content_peek, input) = content.peek(2048)?;
-        let encoding = detect_encoding(&content_peek);
+        let encoding = user_provided_config
+            .encoding
+            .unwrap_or_else(|| detect_encoding(&content_peek));
         let input = if encoding.is_utf8() {
             input
         } else {
@@ -137,7 +156,7 @@ impl Parser for CsvParser {
         let mut headers = user_provided_config.headers.clone();
         builder.has_headers(headers.is_empty());
 
-        let mut reader = builder.from_reader(input.into_stream());
+        let (mut reader, raw) = recording_reader(&builder, input);
 
         // If headers were not specified in the config, then ask the reader to parse them now.
         if headers.is_empty() {
@@ -149,11 +168,12 @@ impl Parser for CsvParser {
                 .collect();
             tracing::debug!(nColumns = headers.len(), "Parsed headers from file");
         }
-        let columns = resolve_headers(headers, projections, CSV_NULLS);
+        let coercion = Coercion::from_config(&user_provided_config);
+        let columns = resolve_headers(headers, &projections, coercion);
 
-        let csv_output = CsvOutput::new(columns, reader);
+        let csv_output = CsvOutput::new(columns, reader, raw);
         let iterator = if let Some(threshold) = user_provided_config.error_threshold {
-            Box::new(ParseErrorBuffer::new(csv_output, threshold)) as Output
+            Box::new(ParseErrorBuffer::new(csv_output, threshold, rejections)) as Output
         } else {
             Box::new(csv_output) as Output
         };
@@ -167,18 +187,19 @@ impl Parser for CsvParser {
 /// name as the JSON property name and permits any type of value. This is so that the parser can at
 /// least do a basic CSV to JSON conversion without having any prior knowledge about the desired
 /// shape of the JSON.
-fn resolve_headers(
+pub(crate) fn resolve_headers(
     column_header_names: Vec<String>,
-    projections: Projections,
-    null_sentinels: &'static [&'static str],
+    projections: &Projections,
+    coercion: Coercion,
 ) -> Vec<Header> {
+    let coercion = Rc::new(coercion);
     let mut columns = Vec::new();
     for name in column_header_names {
         let projection = projections.lookup(&name);
         columns.push(Header {
             name,
             projection,
-            null_sentinels,
+            coercion: coercion.clone(),
         });
     }
     tracing::info!(headers = ?columns, "resolved column headers");
@@ -251,16 +272,97 @@ impl TargetType {
 pub struct Header {
     pub name: String,
     pub projection: Projection,
-    /// The allowable values that will be interpreted as null. Ignored if the projection
-    /// information doesn't allow nulls. This is static because we currently don't have a use case
-    /// for them to be dynamic, and it's convenient to just use string literals.
-    pub null_sentinels: &'static [&'static str],
+    /// The rules for coercing values into the types permitted by the projection. These are shared
+    /// by all of the columns in a file.
+    pub coercion: Rc<Coercion>,
+}
+
+pub const CSV_NULLS: &[&str] = &["", "NULL", "null", "nil", "\\N"];
+
+/// Rules for coercing the string values of cells into the JSON types that are permitted by the
+/// schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coercion {
+    /// The values that will be interpreted as null. Ignored if the projection information doesn't
+    /// allow nulls.
+    pub null_values: Vec<String>,
+    /// The values that will be interpreted as `true`, if the projection allows booleans.
+    pub true_values: Vec<String>,
+    /// The values that will be interpreted as `false`, if the projection allows booleans.
+    pub false_values: Vec<String>,
+    /// Additional strftime-style patterns that are accepted for `date-time` strings.
+    pub date_time_formats: Vec<String>,
+    /// Additional strftime-style patterns that are accepted for `date` strings.
+    pub date_formats: Vec<String>,
 }
 
-pub const CSV_NULLS: &[&str] = &["", "NULL", "null", "nil"];
+impl Default for Coercion {
+    fn default() -> Coercion {
+        Coercion::with_null_values(CSV_NULLS)
+    }
+}
+
+impl Coercion {
+    /// Returns the default coercion rules, except using the given `null_values`.
+    pub fn with_null_values(null_values: &[&str]) -> Coercion {
+        Coercion {
+            null_values: null_values.iter().map(|v| v.to_string()).collect(),
+            true_values: vec![String::from("true")],
+            false_values: vec![String::from("false")],
+            date_time_formats: Vec::new(),
+            date_formats: Vec::new(),
+        }
+    }
+
+    /// Returns the coercion rules from the config, using the defaults for any that are
+    /// unspecified.
+    pub fn from_config(config: &CharacterSeparatedConfig) -> Coercion {
+        let mut coercion = Coercion::default();
+        if !config.null_values.is_empty() {
+            coercion.null_values = config.null_values.clone();
+        }
+        if !config.true_values.is_empty() {
+            coercion.true_values = config.true_values.clone();
+        }
+        if !config.false_values.is_empty() {
+            coercion.false_values = config.false_values.clone();
+        }
+        coercion.date_time_formats = config.date_time_formats.clone();
+        coercion.date_formats = config.date_formats.clone();
+        coercion
+    }
+
+    /// Returns the given `value` as an RFC3339 date-time, or `None` if it isn't one and doesn't
+    /// match any of the `date_time_formats`. Values that are already RFC3339 are returned as-is.
+    fn parse_date_time(&self, value: &str) -> Option<String> {
+        if DateTime::parse_from_rfc3339(value).is_ok() {
+            return Some(value.to_string());
+        }
+        for pattern in self.date_time_formats.iter() {
+            if let Ok(dt) = DateTime::parse_from_str(value, pattern) {
+                return Some(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+            }
+            // Patterns without any time zone can only be parsed as naive date-times.
+            if let Ok(dt) = NaiveDateTime::parse_from_str(value, pattern) {
+                let dt = DateTime::<Utc>::from_utc(dt, Utc);
+                return Some(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+            }
+        }
+        None
+    }
+
+    /// Returns the given `value` as an RFC3339 full-date, or `None` if it isn't one and doesn't
+    /// match any of the `date_formats`.
+    fn parse_date(&self, value: &str) -> Option<String> {
+        std::iter::once("%Y-%m-%d")
+            .chain(self.date_formats.iter().map(String::as_str))
+            .find_map(|pattern| NaiveDate::parse_from_str(value, pattern).ok())
+            .map(|date| date.format("%Y-%m-%d").to_string())
+    }
+}
 
 impl Header {
-    fn parse(&self, value: &str) -> Result<Value, Error> {
+    pub(crate) fn parse(&self, value: &str) -> Result<Value, Error> {
         if let Some(possible_types) = self.projection.possible_types {
             // Since we have type information about this field, try to parse it as one of the
             // allowable types.
@@ -285,7 +387,7 @@ impl Header {
     fn parse_as_type(&self, value: &str, target_type: TargetType) -> Option<Value> {
         match target_type {
             TargetType::Null => {
-                if self.null_sentinels.contains(&value) {
+                if self.coercion.null_values.iter().any(|n| n == value) {
                     Some(Value::Null)
                 } else {
                     None
@@ -315,10 +417,23 @@ impl Header {
                         None
                     }
                 }),
-            TargetType::Boolean => serde_json::from_str::<bool>(value)
-                .ok()
-                .map(|b| Value::Bool(b)),
-            TargetType::String => Some(Value::String(value.to_string())),
+            TargetType::Boolean => {
+                if self.coercion.true_values.iter().any(|t| t == value) {
+                    Some(Value::Bool(true))
+                } else if self.coercion.false_values.iter().any(|f| f == value) {
+                    Some(Value::Bool(false))
+                } else {
+                    None
+                }
+            }
+            // Strings with a `date-time` or `date` format must actually be date-times or dates, so
+            // that values which don't match are counted as errors rather than output as arbitrary
+            // strings.
+            TargetType::String => match self.projection.format.as_deref() {
+                Some("date-time") => self.coercion.parse_date_time(value).map(Value::String),
+                Some("date") => self.coercion.parse_date(value).map(Value::String),
+                _ => Some(Value::String(value.to_string())),
+            },
         }
     }
 }
@@ -331,23 +446,57 @@ fn get_config(format: Format, conf: &ParseConfig) -> Option<&CharacterSeparatedC
     }
 }
 
+/// Builds a CSV reader that keeps a copy of the bytes it reads, so that the raw content of rows
+/// that fail to parse can be written to the dead-letter output.
+fn recording_reader(
+    builder: &csv::ReaderBuilder,
+    input: Input,
+) -> (Reader<Box<dyn io::Read>>, RawBytes) {
+    let (stream, raw) = RecordingReader::new(input.into_stream());
+    (
+        builder.from_reader(Box::new(stream) as Box<dyn io::Read>),
+        raw,
+    )
+}
+
 pub struct CsvOutput {
     headers: Vec<Header>,
     reader: Reader<Box<dyn io::Read>>,
+    /// The bytes that have been read by the `reader`, which must have been created by
+    /// `recording_reader`.
+    raw: RawBytes,
     current_row: StringRecord,
     row_num: u64,
 }
 
 impl CsvOutput {
-    pub fn new(headers: Vec<Header>, reader: Reader<Box<dyn io::Read>>) -> CsvOutput {
+    pub fn new(
+        headers: Vec<Header>,
+        reader: Reader<Box<dyn io::Read>>,
+        raw: RawBytes,
+    ) -> CsvOutput {
         CsvOutput {
             headers,
             reader,
+            raw,
             current_row: StringRecord::new(),
             row_num: 0,
         }
     }
 
+    /// Attaches the location and raw content of the row that starts at `position` to the `error`.
+    fn record_error(&self, error: ParseError, position: Option<&csv::Position>) -> ParseError {
+        let end = self.reader.position().byte();
+        let start = position.map(|p| p.byte()).unwrap_or(end);
+        RecordError {
+            error,
+            byte_offset: position.map(|p| p.byte()),
+            line: position.map(|p| p.line()),
+            raw: position.map(|_| self.raw.get(start, end)),
+        }
+        .into()
+    }
+
     fn parse_current_row(&mut self) -> Result<Value, ParseError> {
         let CsvOutput {
             headers,
@@ -355,27 +504,11 @@ impl CsvOutput {
             row_num,
             ..
         } = self;
-        let mut result = Value::Object(serde_json::Map::with_capacity(current_row.len()));
-        for (i, header) in headers.iter().enumerate() {
-            if let Some(value) = current_row.get(i) {
-                let parsed = header.parse(value).map_err(box_err)?;
-                if let Some(target) = header.projection.target_location.create(&mut result) {
-                    // Success! We've now placed the parsed value into it's home.
-                    *target = parsed;
-                } else {
-                    return Err(box_err(Error::InvalidStructure(
-                        *row_num,
-                        result.clone(),
-                        format!("{:?}", header.projection.target_location),
-                    ))
-                    .into());
-                }
-            } else {
-                if header.projection.must_exist {
-                    return Err(box_err(Error::MissingColumn(*row_num, header.name.clone())).into());
-                }
-            }
-        }
+        let result = row_to_json(
+            headers,
+            *row_num,
+            (0..headers.len()).map(|i| current_row.get(i)),
+        )?;
         if current_row.len() > headers.len() {
             return Err(box_err(Error::ExtraColumn(
                 *row_num,
@@ -388,6 +521,37 @@ impl CsvOutput {
     }
 }
 
+/// Builds a JSON document from the values of a single row. The `values` must be given in the same
+/// order as the `headers`, with `None` for any column that's missing from the row.
+fn row_to_json<'a>(
+    headers: &[Header],
+    row_num: u64,
+    values: impl Iterator<Item = Option<&'a str>>,
+) -> Result<Value, ParseError> {
+    let mut result = Value::Object(serde_json::Map::with_capacity(headers.len()));
+    for (header, value) in headers.iter().zip(values) {
+        if let Some(value) = value {
+            let parsed = header.parse(value).map_err(box_err)?;
+            if let Some(target) = header.projection.target_location.create(&mut result) {
+                // Success! We've now placed the parsed value into it's home.
+                *target = parsed;
+            } else {
+                return Err(box_err(Error::InvalidStructure(
+                    row_num,
+                    result.clone(),
+                    format!("{:?}", header.projection.target_location),
+                ))
+                .into());
+            }
+        } else {
+            if header.projection.must_exist {
+                return Err(box_err(Error::MissingColumn(row_num, header.name.clone())).into());
+            }
+        }
+    }
+    Ok(result)
+}
+
 impl Iterator for CsvOutput {
     type Item = Result<Value, ParseError>;
 
@@ -400,12 +564,21 @@ impl Iterator for CsvOutput {
             } = self;
             match reader.read_record(current_row) {
                 Ok(more) => more,
-                Err(err) => return Some(Err(box_err(err).into())),
+                Err(err) => {
+                    let position = err.position().cloned();
+                    return Some(Err(
+                        self.record_error(box_err(err).into(), position.as_ref())
+                    ));
+                }
             }
         };
         if has_next {
             self.row_num += 1;
-            Some(self.parse_current_row())
+            let result = self
+                .parse_current_row()
+                .map_err(|err| self.record_error(err, self.current_row.position()));
+            self.raw.discard_before(self.reader.position().byte());
+            Some(result)
         } else {
             None
         }
@@ -415,6 +588,7 @@ impl Iterator for CsvOutput {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::config::ErrorThreshold;
     use serde_json::{json, Value};
 
     fn test_input(content: impl Into<Vec<u8>>) -> Input {
@@ -455,6 +629,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn row_errors_include_the_location_and_raw_content_of_the_row() {
+        let conf = ParseConfig::default();
+        let csv = test_input("a,b\n1,2\n\"3\",4,5\n6,7\n");
+        let results = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .collect::<Vec<_>>();
+        assert_eq!(3, results.len());
+
+        match results[1].as_ref() {
+            Err(ParseError::Record(record)) => {
+                assert_eq!(Some(8), record.byte_offset);
+                assert_eq!(Some(3), record.line);
+                assert_eq!(Some(&b"\"3\",4,5\n"[..]), record.raw.as_deref());
+            }
+            other => panic!("expected a record error, got: {:?}", other),
+        }
+        assert_eq!(json!({"a": "6", "b": "7"}), *results[2].as_ref().unwrap());
+    }
+
     #[test]
     fn parses_when_there_are_more_configured_headers_than_columns() {
         let conf = ParseConfig {
@@ -482,6 +677,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn configured_encoding_is_used_instead_of_detection() {
+        let conf = ParseConfig {
+            format: Some(Format::Csv),
+            csv: Some(CharacterSeparatedConfig {
+                encoding: Some(crate::config::EncodingRef::from(encoding_rs::WINDOWS_1252)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // The UTF-8 encoding of "é", which would otherwise be detected as such.
+        let csv = test_input(b"a\n\xc3\xa9\n".to_vec());
+        let results = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .collect::<Result<Vec<_>, ParseError>>()
+            .expect("output fail");
+        assert_eq!(vec![json!({"a": "Ã©"})], results);
+    }
+
     #[test]
     fn values_parsed_as_null_when_sentinel_matches() {
         let conf = ParseConfig {
@@ -516,6 +732,125 @@ mod test {
         );
     }
 
+    #[test]
+    fn values_are_coerced_using_configured_tokens() {
+        let conf = ParseConfig {
+            format: Some(Format::Csv),
+            csv: Some(CharacterSeparatedConfig {
+                null_values: vec!["\\N".to_string(), "-".to_string()],
+                true_values: vec!["Y".to_string(), "yes".to_string()],
+                false_values: vec!["N".to_string(), "no".to_string()],
+                ..Default::default()
+            }),
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "flag": { "type": ["boolean", "null"] },
+                    "count": { "type": ["integer", "null"] },
+                    "name": { "type": "string" }
+                }
+            }),
+            ..Default::default()
+        };
+        let csv = test_input("flag,count,name\nY,1,a\nno,\\N,-\n-,-,NULL\n");
+        let results = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .collect::<Result<Vec<_>, ParseError>>()
+            .expect("output fail");
+        assert_eq!(
+            vec![
+                json!({"flag": true, "count": 1, "name": "a"}),
+                json!({"flag": false, "count": null, "name": "-"}),
+                json!({"flag": null, "count": null, "name": "NULL"}),
+            ],
+            results
+        );
+
+        // The default boolean tokens no longer apply once others are configured.
+        let csv = test_input("flag\ntrue\n");
+        let err = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .next()
+            .unwrap()
+            .expect_err("expected an error");
+        assert!(err.to_string().contains("\"true\""), "{}", err);
+    }
+
+    #[test]
+    fn date_times_are_parsed_using_configured_formats() {
+        let conf = ParseConfig {
+            format: Some(Format::Csv),
+            csv: Some(CharacterSeparatedConfig {
+                date_time_formats: vec![
+                    "%m/%d/%Y %H:%M:%S".to_string(),
+                    "%Y-%m-%d %H:%M:%S %z".to_string(),
+                ],
+                error_threshold: Some(ErrorThreshold::new(50).unwrap()),
+                ..Default::default()
+            }),
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "ts": { "type": "string", "format": "date-time" }
+                }
+            }),
+            ..Default::default()
+        };
+        let csv = test_input(
+            "ts\n2021-06-15T12:30:00.5-04:00\n06/15/2021 12:30:00\n2021-06-15 12:30:00 +0200\nyesterday\n",
+        );
+        let results = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .collect::<Result<Vec<_>, ParseError>>()
+            .expect("output fail");
+        // The invalid date-time is counted against the error threshold instead of being output.
+        assert_eq!(
+            vec![
+                json!({"ts": "2021-06-15T12:30:00.5-04:00"}),
+                json!({"ts": "2021-06-15T12:30:00Z"}),
+                json!({"ts": "2021-06-15T12:30:00+02:00"}),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn dates_are_parsed_using_configured_formats() {
+        let conf = ParseConfig {
+            format: Some(Format::Csv),
+            csv: Some(CharacterSeparatedConfig {
+                date_formats: vec!["%m/%d/%Y".to_string(), "%d.%m.%Y".to_string()],
+                error_threshold: Some(ErrorThreshold::new(50).unwrap()),
+                ..Default::default()
+            }),
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "day": { "type": "string", "format": "date" }
+                }
+            }),
+            ..Default::default()
+        };
+        let csv = test_input("day\n2021-06-15\n06/15/2021\n15.06.2021\n2021-06-15T12:30:00Z\n");
+        let results = new_csv_parser()
+            .parse(&conf, csv)
+            .expect("parse failed")
+            .collect::<Result<Vec<_>, ParseError>>()
+            .expect("output fail");
+        // The date-time isn't a date, so it's counted against the error threshold.
+        assert_eq!(
+            vec![
+                json!({"day": "2021-06-15"}),
+                json!({"day": "2021-06-15"}),
+                json!({"day": "2021-06-15"}),
+            ],
+            results
+        );
+    }
+
     #[test]
     fn values_parsed_as_strings_when_numbers_would_overflow() {
         let conf = ParseConfig {
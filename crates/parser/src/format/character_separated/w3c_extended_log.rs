@@ -2,7 +2,7 @@
 //! http server logs. It's essentially just a tab-separated values file, with a few extra
 //! differences. The main thing being that the column headers appear in a special `#Fields`
 //! directive instead just being on the first row. Also, nulls are represented as `-`.
-use super::{resolve_headers, CsvOutput};
+use super::{recording_reader, resolve_headers, Coercion, CsvOutput};
 use crate::config::ParseConfig;
 use crate::format::projection::build_projections;
 use crate::format::{Output, ParseError, Parser};
@@ -88,17 +88,23 @@ impl Parser for W3cLogParser {
         // TODO: (optionally) add the extra directives from header as fields to each json object.
 
         let projections = build_projections(config)?;
-        let headers = resolve_headers(header.fields, projections, &["-"]);
+        let headers = resolve_headers(
+            header.fields,
+            &projections,
+            Coercion::with_null_values(&["-"]),
+        );
 
-        let reader = csv::ReaderBuilder::new()
-            .delimiter(b'\t')
-            // Configure the CSV reader to ignore lines that start with a `#`, so that we don't
-            // need to skip ahead after parsing the headers.
-            .comment(Some(b'#'))
-            .has_headers(false)
-            .from_reader(input.into_stream());
+        let (reader, raw) = recording_reader(
+            csv::ReaderBuilder::new()
+                .delimiter(b'\t')
+                // Configure the CSV reader to ignore lines that start with a `#`, so that we don't
+                // need to skip ahead after parsing the headers.
+                .comment(Some(b'#'))
+                .has_headers(false),
+            input,
+        );
 
-        Ok(Box::new(CsvOutput::new(headers, reader)))
+        Ok(Box::new(CsvOutput::new(headers, reader, raw)))
     }
 }
 
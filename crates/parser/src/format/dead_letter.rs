@@ -0,0 +1,126 @@
+//! Dead-letter output, which receives a description of each record that could not be parsed. This
+//! allows rejected records to be inspected and replayed, rather than only being logged.
+use super::ParseError;
+use serde::Serialize;
+use std::io;
+
+/// A single line of dead-letter output.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeadLetter<'a> {
+    /// The name of the file, or archive entry, that the record was read from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filename: Option<&'a str>,
+    error: String,
+    /// The offset of the start of the record, after any decompression and transcoding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_offset: Option<u64>,
+    /// The line number of the start of the record, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    /// The raw content of the record, base64 encoded since it may not be valid UTF-8.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw: Option<String>,
+}
+
+/// Writes rejected records in JSONL format to an optional destination. Each record is written
+/// with a single call to `write_all`, so the destination doesn't need to be buffered.
+pub struct DeadLetters<'a> {
+    dest: Option<&'a mut dyn io::Write>,
+    count: u64,
+}
+
+impl<'a> DeadLetters<'a> {
+    pub fn new(dest: Option<&'a mut dyn io::Write>) -> DeadLetters<'a> {
+        DeadLetters { dest, count: 0 }
+    }
+
+    /// The number of records that have been rejected, whether or not there's a destination.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Records the rejection of a record that failed with the given `error`. Errors that don't
+    /// pertain to a specific record, such as exceeding the error threshold, are ignored.
+    pub fn reject(&mut self, filename: Option<&str>, error: &ParseError) -> io::Result<()> {
+        if matches!(error, ParseError::ErrorLimitExceeded(_) | ParseError::Io(_)) {
+            return Ok(());
+        }
+        self.count += 1;
+
+        let dest = match self.dest.as_mut() {
+            Some(dest) => dest,
+            None => return Ok(()),
+        };
+        let mut letter = DeadLetter {
+            filename,
+            error: error.to_string(),
+            byte_offset: None,
+            line: None,
+            raw: None,
+        };
+        match error {
+            ParseError::Record(record) => {
+                letter.byte_offset = record.byte_offset;
+                letter.line = record.line;
+                letter.raw = record.raw.as_ref().map(base64::encode);
+            }
+            // The JSON parser can't recover the raw content of invalid documents, but the error
+            // includes the line number.
+            ParseError::Json(err) if err.line() > 0 => {
+                letter.line = Some(err.line() as u64);
+            }
+            _ => {}
+        }
+
+        let mut line = serde_json::to_vec(&letter)?;
+        line.push(b'\n');
+        dest.write_all(&line)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::format::RecordError;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn rejected_records_are_written_as_jsonl() {
+        let mut dest = Vec::new();
+        let mut dead_letters = DeadLetters::new(Some(&mut dest));
+        let record = ParseError::Record(Box::new(RecordError {
+            error: ParseError::MissingFormat,
+            byte_offset: Some(12),
+            line: Some(3),
+            raw: Some(b"a,b\xFF".to_vec()),
+        }));
+        dead_letters.reject(Some("data.csv"), &record).unwrap();
+        dead_letters
+            .reject(None, &ParseError::ErrorLimitExceeded(Default::default()))
+            .unwrap();
+        dead_letters
+            .reject(None, &ParseError::CannotInferFormat)
+            .unwrap();
+        assert_eq!(2, dead_letters.count());
+
+        let letters = dest
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect::<Vec<Value>>();
+        assert_eq!(
+            vec![
+                json!({
+                    "filename": "data.csv",
+                    "error": "format is empty",
+                    "byteOffset": 12,
+                    "line": 3,
+                    "raw": "YSxi/w==",
+                }),
+                json!({"error": ParseError::CannotInferFormat.to_string()}),
+            ],
+            letters
+        );
+    }
+}
@@ -1,14 +1,50 @@
 use super::{ParseError, ParseResult};
 use crate::ErrorThreshold;
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 // How many of the recent records to consider when trying to decide if
 // we've entered a new region of bad data of the file.
 const ERROR_BUFFER_WINDOW_SIZE: usize = 1000;
 
+/// Receives the errors of records that are absorbed by a `ParseErrorBuffer`, so that they can be
+/// reported separately from the parser's output. Clones share the same queue of rejections.
+#[derive(Debug, Clone)]
+pub struct Rejections(Option<Rc<RefCell<VecDeque<ParseError>>>>);
+
+impl Rejections {
+    /// Returns a `Rejections` that queues each rejected record until it's taken.
+    pub fn collect() -> Rejections {
+        Rejections(Some(Default::default()))
+    }
+
+    /// Returns a `Rejections` that discards rejected records, which are still logged.
+    pub fn discard() -> Rejections {
+        Rejections(None)
+    }
+
+    /// Removes and returns all of the rejected records that have been queued so far.
+    pub fn take(&self) -> Vec<ParseError> {
+        match self.0.as_ref() {
+            Some(queue) => queue.borrow_mut().drain(..).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn reject(&self, err: ParseError) {
+        tracing::warn!(error=?err, "failed to parse row");
+        if let Some(queue) = self.0.as_ref() {
+            queue.borrow_mut().push_back(err);
+        }
+    }
+}
+
 /// A decorating iterator that tracks parsing errors and absorbs a specified
-/// rate of errors. If that rate is exceeded, then all the errors encountered
-/// are returned. Should not be polled again once an error is returned.
+/// rate of errors. Absorbed errors are sent to the `Rejections`. If the rate is
+/// exceeded, then the errors remaining in the buffer are rejected, and
+/// `ParseError::ErrorLimitExceeded` is returned. Should not be polled again once
+/// an error is returned.
 #[derive(Debug)]
 pub struct ParseErrorBuffer<I> {
     /// The iterator we're wrapping.
@@ -21,16 +57,19 @@ pub struct ParseErrorBuffer<I> {
     errors_in_buffer: usize,
     /// The most recent rows.
     buffer: VecDeque<ParseResult>,
+    /// Receives the errors that are absorbed.
+    rejections: Rejections,
 }
 
 impl<I: Iterator<Item = ParseResult>> ParseErrorBuffer<I> {
-    pub fn new(inner: I, threshold: ErrorThreshold) -> Self {
+    pub fn new(inner: I, threshold: ErrorThreshold, rejections: Rejections) -> Self {
         Self {
             inner,
             threshold,
             total_records: 0,
             errors_in_buffer: 0,
             buffer: VecDeque::with_capacity(ERROR_BUFFER_WINDOW_SIZE),
+            rejections,
         }
     }
 
@@ -90,14 +129,18 @@ impl<I: Iterator<Item = ParseResult>> Iterator for ParseErrorBuffer<I> {
 
         loop {
             if self.exceeded() {
+                // Parsing is about to stop, but the errors that are still in the buffer are
+                // rejected first so that they aren't lost.
+                for item in self.buffer.drain(..) {
+                    if let Err(err) = item {
+                        self.rejections.reject(err);
+                    }
+                }
                 return Some(Err(ParseError::ErrorLimitExceeded(self.threshold)));
             } else {
-                let item = self.advance()?;
-                if let Err(err) = item.as_ref() {
-                    tracing::warn!(error=?err, "failed to parse row");
-                    continue;
-                } else {
-                    return Some(item);
+                match self.advance()? {
+                    Err(err) => self.rejections.reject(err),
+                    ok => return Some(ok),
                 }
             }
         }
@@ -0,0 +1,603 @@
+use crate::config::{excel::ExcelConfig, ParseConfig};
+use crate::decorate::display_ptr;
+use crate::format::character_separated::{self, resolve_headers, Coercion, Header};
+use crate::format::projection::{build_projections, Projection, Projections};
+use crate::format::{Output, ParseError, ParseResult, Parser};
+use crate::input::Input;
+use calamine::{DataType, Range, Reader, Xlsx, XlsxError};
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use doc::ptr::Pointer;
+use json::schema::types;
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+
+const MILLIS_PER_DAY: f64 = 86_400_000.0;
+
+pub struct ExcelParser;
+
+/// Returns a type-erased parser trait object for parsing Excel spreadsheets.
+pub fn new_parser() -> Box<dyn Parser> {
+    Box::new(ExcelParser)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExcelError {
+    #[error("failed to read workbook: {0}")]
+    Read(#[from] XlsxError),
+
+    #[error("the workbook does not contain a worksheet named '{0}'")]
+    MissingSheet(String),
+
+    #[error("the workbook does not contain any worksheets")]
+    NoSheets,
+
+    #[error("invalid value in sheet '{0}' cell {1}: {2}")]
+    InvalidValue(String, String, character_separated::Error),
+
+    #[error("sheet '{0}' cell {1} contains the error value: {2}")]
+    CellError(String, String, String),
+
+    #[error("sheet '{0}' cell {1} contains a number that can't be represented in JSON: {2}")]
+    NonFiniteNumber(String, String, f64),
+
+    #[error(
+        "sheet '{0}' row {1} has a value in column {2}, but the headers only define {3} columns"
+    )]
+    ExtraColumn(String, u32, String, usize),
+
+    #[error("cannot construct a JSON object from sheet '{0}' row {1} because it's impossible to create the location '{3}' within the document: {2}")]
+    InvalidStructure(String, u32, Value, String),
+}
+
+impl Parser for ExcelParser {
+    /// Xlsx files are zip archives, which are read directly by calamine.
+    fn decompress(&self) -> bool {
+        false
+    }
+
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        let iter = ExcelIter::from_config_and_input(config, content)?;
+        Ok(Box::new(iter))
+    }
+}
+
+struct ExcelIter {
+    workbook: Xlsx<BufReader<File>>,
+    config: ExcelConfig,
+    projections: Projections,
+    sheet_location: Option<Pointer>,
+    /// The names of the sheets that have yet to be read, in reverse order.
+    remaining_sheets: Vec<String>,
+    current: Option<SheetRows>,
+}
+
+/// The rows of a single worksheet, along with the headers that apply to them.
+struct SheetRows {
+    name: String,
+    range: Range<DataType>,
+    headers: Vec<Header>,
+    /// The zero-based index of the next row to be read.
+    next_row: u32,
+}
+
+impl ExcelIter {
+    fn from_config_and_input(
+        config: &ParseConfig,
+        content: Input,
+    ) -> Result<ExcelIter, ParseError> {
+        let projections = build_projections(config)?;
+        let excel_config = config.excel.clone().unwrap_or_default();
+
+        // Xlsx files are zip archives, which can only be read from a seekable file. Streams will be
+        // buffered to a temporary file first.
+        let file = content.into_file()?;
+        let workbook: Xlsx<_> = Xlsx::new(BufReader::new(file))
+            .map_err(|err| ParseError::Parse(Box::new(ExcelError::Read(err))))?;
+
+        let sheet_names = workbook.sheet_names().to_vec();
+        tracing::debug!(sheets = ?sheet_names, "read workbook");
+        let mut remaining_sheets = if excel_config.all_sheets {
+            sheet_names
+        } else if let Some(name) = excel_config.sheet.as_ref() {
+            if !sheet_names.contains(name) {
+                return Err(ParseError::Parse(Box::new(ExcelError::MissingSheet(
+                    name.clone(),
+                ))));
+            }
+            vec![name.clone()]
+        } else {
+            let first = sheet_names
+                .into_iter()
+                .next()
+                .ok_or_else(|| ParseError::Parse(Box::new(ExcelError::NoSheets)))?;
+            vec![first]
+        };
+        remaining_sheets.reverse();
+
+        let sheet_location = excel_config
+            .sheet_name_location()
+            .as_ref()
+            .map(Pointer::from);
+
+        Ok(ExcelIter {
+            workbook,
+            config: excel_config,
+            projections,
+            sheet_location,
+            remaining_sheets,
+            current: None,
+        })
+    }
+
+    /// Reads the next worksheet from the workbook and resolves its headers.
+    fn open_sheet(&mut self, name: String) -> Result<SheetRows, ExcelError> {
+        let range = self
+            .workbook
+            .worksheet_range(&name)
+            .ok_or_else(|| ExcelError::MissingSheet(name.clone()))??;
+
+        let end_col = range.end().map(|(_, col)| col + 1).unwrap_or_default();
+        let header_row = self.config.resolved_header_row();
+        let header_names = if !self.config.headers.is_empty() {
+            self.config.headers.clone()
+        } else {
+            // Columns without a name in the header row are named by their column letters, so that
+            // their values aren't silently dropped. If there's no header row at all, then every
+            // column is named this way.
+            (0..end_col)
+                .map(|col| {
+                    let cell = match header_row {
+                        0 => None,
+                        n => range.get_value((n - 1, col)),
+                    };
+                    match cell {
+                        None | Some(DataType::Empty) => column_letters(col),
+                        Some(DataType::String(s)) if s.trim().is_empty() => column_letters(col),
+                        Some(other) => other.to_string(),
+                    }
+                })
+                .collect()
+        };
+        tracing::debug!(sheet = %name, nColumns = header_names.len(), "resolved sheet headers");
+        let headers = resolve_headers(header_names, &self.projections, Coercion::default());
+
+        Ok(SheetRows {
+            name,
+            range,
+            headers,
+            // Rows are skipped through the header row, which is numbered starting at 1.
+            next_row: header_row,
+        })
+    }
+
+    fn row_to_json(&self, sheet: &SheetRows, row: u32) -> Result<Value, ExcelError> {
+        let mut json = Value::Object(serde_json::Map::with_capacity(sheet.headers.len()));
+        let end_col = sheet
+            .range
+            .end()
+            .map(|(_, col)| col + 1)
+            .unwrap_or_default();
+
+        for col in 0..end_col.max(sheet.headers.len() as u32) {
+            let cell = sheet
+                .range
+                .get_value((row, col))
+                .unwrap_or(&DataType::Empty);
+
+            let header = match sheet.headers.get(col as usize) {
+                Some(header) => header,
+                None if is_empty(cell) => continue,
+                None => {
+                    return Err(ExcelError::ExtraColumn(
+                        sheet.name.clone(),
+                        row + 1,
+                        column_letters(col),
+                        sheet.headers.len(),
+                    ))
+                }
+            };
+
+            let value = match cell_to_json(sheet, row, col, header, cell)? {
+                Some(value) => value,
+                None => continue,
+            };
+            set_location(
+                sheet,
+                row,
+                &header.projection.target_location,
+                &mut json,
+                value,
+            )?;
+        }
+
+        if let Some(location) = self.sheet_location.as_ref() {
+            set_location(
+                sheet,
+                row,
+                location,
+                &mut json,
+                Value::String(sheet.name.clone()),
+            )?;
+        }
+        Ok(json)
+    }
+}
+
+impl Iterator for ExcelIter {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                let name = self.remaining_sheets.pop()?;
+                match self.open_sheet(name) {
+                    Ok(sheet) => self.current = Some(sheet),
+                    Err(err) => return Some(Err(ParseError::Parse(Box::new(err)))),
+                }
+            }
+            let mut sheet = self.current.take().unwrap();
+            let end_row = sheet
+                .range
+                .end()
+                .map(|(row, _)| row + 1)
+                .unwrap_or_default();
+
+            // Blank rows are common in spreadsheets, and are skipped rather than being output as
+            // empty documents.
+            while sheet.next_row < end_row && is_blank_row(&sheet.range, sheet.next_row) {
+                sheet.next_row += 1;
+            }
+            if sheet.next_row >= end_row {
+                continue;
+            }
+
+            let row = sheet.next_row;
+            sheet.next_row += 1;
+            let result = self
+                .row_to_json(&sheet, row)
+                .map_err(|err| ParseError::Parse(Box::new(err)));
+            self.current = Some(sheet);
+            return Some(result);
+        }
+    }
+}
+
+/// Converts a single cell into a JSON value, or `None` if the cell is empty and the column isn't
+/// required. Values are kept as their native types where the projection allows it, and are
+/// otherwise parsed from their string representations the same way as CSV values.
+fn cell_to_json(
+    sheet: &SheetRows,
+    row: u32,
+    col: u32,
+    header: &Header,
+    cell: &DataType,
+) -> Result<Option<Value>, ExcelError> {
+    let projection = &header.projection;
+    let reference = || format!("{}{}", column_letters(col), row + 1);
+    let parse = |s: &str| header.parse(s).map(Some);
+
+    let result = match cell {
+        DataType::Empty if projection.must_exist => parse(""),
+        DataType::Empty => Ok(None),
+        DataType::String(s) => parse(s),
+        DataType::Bool(b) if allows(projection, types::BOOLEAN) => Ok(Some(Value::Bool(*b))),
+        DataType::Bool(b) => parse(&b.to_string()),
+        DataType::Int(i) if allows(projection, types::INTEGER) => Ok(Some(Value::from(*i))),
+        DataType::Int(i) => parse(&i.to_string()),
+        // JSON has no representation of NaN or infinity.
+        DataType::Float(f) if !f.is_finite() => {
+            return Err(ExcelError::NonFiniteNumber(
+                sheet.name.clone(),
+                reference(),
+                *f,
+            ))
+        }
+        // Excel stores all numbers as floats, so integral values are converted to integers.
+        DataType::Float(f)
+            if f.fract() == 0.0
+                && f.abs() < i64::MAX as f64
+                && allows(projection, types::INTEGER) =>
+        {
+            Ok(Some(Value::from(*f as i64)))
+        }
+        DataType::Float(f) if allows(projection, types::FRACTIONAL) => Ok(Some(Value::from(*f))),
+        DataType::Float(f) => parse(&f.to_string()),
+        DataType::DateTime(serial) if allows(projection, types::STRING) => {
+            match excel_datetime_to_rfc3339(*serial) {
+                Some(formatted) => Ok(Some(Value::String(formatted))),
+                None => parse(&serial.to_string()),
+            }
+        }
+        DataType::DateTime(serial) => parse(&serial.to_string()),
+        DataType::Error(err) => {
+            return Err(ExcelError::CellError(
+                sheet.name.clone(),
+                reference(),
+                err.to_string(),
+            ))
+        }
+    };
+    result.map_err(|err| ExcelError::InvalidValue(sheet.name.clone(), reference(), err))
+}
+
+fn set_location(
+    sheet: &SheetRows,
+    row: u32,
+    location: &Pointer,
+    json: &mut Value,
+    value: Value,
+) -> Result<(), ExcelError> {
+    if let Some(loc) = location.create(json) {
+        *loc = value;
+        Ok(())
+    } else {
+        Err(ExcelError::InvalidStructure(
+            sheet.name.clone(),
+            row + 1,
+            json.clone(),
+            display_ptr(location),
+        ))
+    }
+}
+
+fn allows(projection: &Projection, ty: types::Set) -> bool {
+    projection
+        .possible_types
+        .map(|t| t.overlaps(ty))
+        .unwrap_or(true)
+}
+
+fn is_empty(cell: &DataType) -> bool {
+    match cell {
+        DataType::Empty => true,
+        DataType::String(s) => s.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_blank_row(range: &Range<DataType>, row: u32) -> bool {
+    let end_col = range.end().map(|(_, col)| col + 1).unwrap_or_default();
+    (0..end_col).all(|col| range.get_value((row, col)).map(is_empty).unwrap_or(true))
+}
+
+/// Returns the spreadsheet-style name of the zero-based column index, e.g. `A`, `Z`, `AA`.
+fn column_letters(col: u32) -> String {
+    let mut n = col + 1;
+    let mut letters = Vec::new();
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push(b'A' + rem as u8);
+        n = (n - 1) / 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// Converts an Excel date serial number, which is the fractional number of days since
+/// 1899-12-30, into an RFC3339 timestamp. Excel doesn't store time zones, so these are assumed to
+/// be UTC.
+fn excel_datetime_to_rfc3339(serial: f64) -> Option<String> {
+    if !serial.is_finite() {
+        return None;
+    }
+    let millis = (serial * MILLIS_PER_DAY).round() as i64;
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30)?.and_hms_opt(0, 0, 0)?;
+    let ts = epoch.checked_add_signed(Duration::milliseconds(millis))?;
+    let ts = DateTime::<Utc>::from_utc(ts, Utc);
+    Some(ts.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use std::io::{Cursor, Write};
+
+    /// Builds a minimal xlsx workbook. Each sheet is given as a name and a list of rows, where each
+    /// row is a list of `(cell reference, cell xml)`.
+    fn workbook(sheets: &[(&str, &[&[(&str, &str)]])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let mut sheet_elems = String::new();
+        let mut rels = String::new();
+        for (i, (name, rows)) in sheets.iter().enumerate() {
+            let id = i + 1;
+            sheet_elems.push_str(&format!(
+                r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+                name, id, id
+            ));
+            rels.push_str(&format!(
+                r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{}.xml"/>"#,
+                id, id
+            ));
+
+            let mut data = String::new();
+            for (row_num, row) in rows.iter().enumerate() {
+                data.push_str(&format!(r#"<row r="{}">"#, row_num + 1));
+                for (reference, cell) in row.iter() {
+                    data.push_str(&cell.replace("REF", reference));
+                }
+                data.push_str("</row>");
+            }
+            writer
+                .start_file(format!("xl/worksheets/sheet{}.xml", id), Default::default())
+                .unwrap();
+            write!(
+                writer,
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{}</sheetData></worksheet>"#,
+                data
+            )
+            .unwrap();
+        }
+
+        writer
+            .start_file("xl/workbook.xml", Default::default())
+            .unwrap();
+        write!(
+            writer,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{}</sheets></workbook>"#,
+            sheet_elems
+        )
+        .unwrap();
+        writer
+            .start_file("xl/_rels/workbook.xml.rels", Default::default())
+            .unwrap();
+        write!(
+            writer,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{}</Relationships>"#,
+            rels
+        )
+        .unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    const STR: &str = r#"<c r="REF" t="inlineStr"><is><t>VALUE</t></is></c>"#;
+
+    fn s(value: &str) -> String {
+        STR.replace("VALUE", value)
+    }
+
+    fn n(value: &str) -> String {
+        format!(r#"<c r="REF"><v>{}</v></c>"#, value)
+    }
+
+    fn b(value: bool) -> String {
+        format!(r#"<c r="REF" t="b"><v>{}</v></c>"#, value as u8)
+    }
+
+    fn parse_workbook(config: &ParseConfig, content: Vec<u8>) -> Vec<Value> {
+        new_parser()
+            .parse(config, Input::Stream(Box::new(Cursor::new(content))))
+            .expect("failed to open workbook")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse rows")
+    }
+
+    #[test]
+    fn rows_are_converted_using_header_row() {
+        let (id, name, score, active) = (s("id"), s("name"), s("score"), s("active"));
+        let (one, bob, score_1, yes) = (n("1"), s("bob"), n("4.5"), b(true));
+        let (two, score_2, no, extra) = (n("2"), n("7"), b(false), s("unnamed"));
+        let header: &[(&str, &str)] =
+            &[("A1", &id), ("B1", &name), ("C1", &score), ("D1", &active)];
+        let row_1: &[(&str, &str)] = &[("A2", &one), ("B2", &bob), ("C2", &score_1), ("D2", &yes)];
+        // Row 3 is blank, and row 4 is missing a name but has a value in an unnamed column.
+        let row_4: &[(&str, &str)] = &[("A4", &two), ("C4", &score_2), ("D4", &no), ("E4", &extra)];
+        let content = workbook(&[("Sheet1", &[header, row_1, &[], row_4])]);
+
+        let config = ParseConfig {
+            schema: json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" },
+                    "score": { "type": "number" },
+                }
+            }),
+            ..Default::default()
+        };
+        let docs = parse_workbook(&config, content);
+        assert_eq!(
+            vec![
+                json!({"id": "1", "name": "bob", "score": 4.5, "active": true}),
+                json!({"id": "2", "score": 7, "active": false, "E": "unnamed"}),
+            ],
+            docs
+        );
+    }
+
+    #[test]
+    fn all_sheets_are_parsed_with_sheet_names() {
+        let (a, b1, a2, b2) = (s("a"), n("1"), s("b"), n("2"));
+        let first: &[(&str, &str)] = &[("A1", &a), ("A2", &b1)];
+        let second: &[(&str, &str)] = &[("A1", &a2), ("A2", &b2)];
+        let content = workbook(&[("First", &[first]), ("Second", &[second])]);
+
+        let config = ParseConfig {
+            excel: Some(ExcelConfig {
+                all_sheets: true,
+                headers: vec!["x".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let docs = parse_workbook(&config, content.clone());
+        assert_eq!(
+            vec![
+                json!({"x": "a", "_meta": {"sheet": "First"}}),
+                json!({"x": 1, "_meta": {"sheet": "First"}}),
+                json!({"x": "b", "_meta": {"sheet": "Second"}}),
+                json!({"x": 2, "_meta": {"sheet": "Second"}}),
+            ],
+            docs
+        );
+
+        let config = ParseConfig {
+            excel: Some(ExcelConfig {
+                sheet: Some("Second".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let docs = parse_workbook(&config, content);
+        assert_eq!(vec![json!({"b": 2})], docs);
+    }
+
+    #[test]
+    fn values_beyond_the_configured_headers_are_an_error() {
+        let (a, b) = (s("a"), s("b"));
+        let row: &[(&str, &str)] = &[("A1", &a), ("B1", &b)];
+        let content = workbook(&[("Sheet1", &[row])]);
+        let config = ParseConfig {
+            excel: Some(ExcelConfig {
+                headers: vec!["x".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let err = new_parser()
+            .parse(&config, Input::Stream(Box::new(Cursor::new(content))))
+            .unwrap()
+            .next()
+            .unwrap()
+            .expect_err("expected an error");
+        assert!(err.to_string().contains("column B"), "{}", err);
+    }
+
+    #[test]
+    fn non_finite_numbers_are_an_error() {
+        let (header, nan) = (s("x"), n("NaN"));
+        let rows: &[&[(&str, &str)]] = &[&[("A1", &header)], &[("A2", &nan)]];
+        let content = workbook(&[("Sheet1", rows)]);
+        let err = new_parser()
+            .parse(
+                &ParseConfig::default(),
+                Input::Stream(Box::new(Cursor::new(content))),
+            )
+            .unwrap()
+            .next()
+            .unwrap()
+            .expect_err("expected an error");
+        assert!(err.to_string().contains("cell A2"), "{}", err);
+        assert!(err.to_string().contains("NaN"), "{}", err);
+    }
+
+    #[test]
+    fn excel_dates_are_converted_to_rfc3339() {
+        assert_eq!(
+            Some("1970-01-01T00:00:00Z".to_string()),
+            excel_datetime_to_rfc3339(25569.0)
+        );
+        assert_eq!(
+            Some("2021-06-15T12:30:00Z".to_string()),
+            excel_datetime_to_rfc3339(44362.520833333336)
+        );
+        assert_eq!(None, excel_datetime_to_rfc3339(f64::NAN));
+    }
+
+    #[test]
+    fn column_letters_are_generated() {
+        for (col, expected) in &[(0, "A"), (25, "Z"), (26, "AA"), (51, "AZ"), (702, "AAA")] {
+            assert_eq!(*expected, column_letters(*col));
+        }
+    }
+}
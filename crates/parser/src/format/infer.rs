@@ -0,0 +1,305 @@
+//! Infers a complete recommended `ParseConfig` by sampling the start of the content. This goes
+//! further than `resolve_config`, by also inferring format-specific configuration and a JSON schema
+//! for the parsed records, so that the result can be used as a starting point for hand-written
+//! configuration.
+use super::character_separated::{sniff_dialect, CSV_NULLS};
+use super::{avro, detect_format, parser_for, resolve_config, ParseError, DIALECT_SAMPLE_SIZE};
+use crate::config::csv::CharacterSeparatedConfig;
+use crate::input::{archive_entries, is_tar_archive, ArchiveEntries, ArchiveEntry, Input};
+use crate::{Compression, Format, ParseConfig};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The maximum number of records that are parsed in order to infer the schema.
+const MAX_SAMPLE_RECORDS: usize = 1000;
+
+/// Returns the `config`, with any unset fields filled in based on a sample of the `content`. The
+/// schema is only inferred if the `config` doesn't already have one.
+pub fn infer_config(config: &ParseConfig, content: Input) -> Result<ParseConfig, ParseError> {
+    let (resolved, content) = resolve_config(config, content)?;
+    let mut inferred = config.clone();
+    inferred.format = resolved.format;
+    inferred.compression = resolved.compression;
+
+    let decompress = resolved
+        .format
+        .map(|f| parser_for(f).decompress())
+        .unwrap_or(true);
+    let mut format = resolved.format;
+    let content = match resolved.compression.filter(|_| decompress) {
+        Some(compression) if compression.is_archive() => {
+            return infer_archive(config, inferred, archive_entries(content, compression)?);
+        }
+        Some(compression) => {
+            let (prefix, decompressed) = content.decompressed(compression)?.peek(512)?;
+            if is_tar_archive(&prefix) {
+                let entries = archive_entries(decompressed, Compression::TarArchive)?;
+                return infer_archive(config, inferred, entries);
+            }
+            format = format.or_else(|| detect_format(&prefix));
+            decompressed
+        }
+        None => content,
+    };
+    let format = format.ok_or(ParseError::CannotInferFormat)?;
+    inferred.format = Some(format);
+
+    let (sample, content) = content.peek(DIALECT_SAMPLE_SIZE)?;
+    let complete = sample.len() < DIALECT_SAMPLE_SIZE;
+    match format {
+        Format::Csv => infer_dialect(&mut inferred.csv, format, &sample, complete),
+        Format::Tsv => infer_dialect(&mut inferred.tsv, format, &sample, complete),
+        _ => {}
+    }
+
+    if inferred.schema.is_null() && format == Format::Avro {
+        // Avro content embeds its own schema, which describes the records more precisely than a
+        // schema that's inferred from a sample of them.
+        inferred.schema = avro::infer_schema(&inferred, content)?;
+    } else if inferred.schema.is_null() {
+        // The content has already been decompressed, so the compression must not be applied again.
+        let mut parse_config = ParseConfig::default().override_from(&inferred);
+        parse_config.compression = None;
+        let output = parser_for(format).parse(&parse_config, content)?;
+
+        let text_values = matches!(
+            format,
+            Format::Csv | Format::Tsv | Format::W3cExtendedLog | Format::FixedWidth
+        );
+        let mut shape = Shape::default();
+        for result in output.take(MAX_SAMPLE_RECORDS) {
+            match result {
+                Ok(mut doc) => {
+                    if text_values {
+                        coerce_strings(&mut doc);
+                    }
+                    shape.add(&doc);
+                }
+                // A truncated record at the end of a partial sample isn't a problem.
+                Err(_) if shape.count > 0 => break,
+                Err(err) => return Err(err),
+            }
+        }
+        if shape.count > 0 {
+            inferred.schema = shape.to_schema();
+        }
+    }
+    tracing::info!(%format, "finished inferring config");
+    Ok(inferred)
+}
+
+/// Infers the config of an archive based on its first file only, so files that differ from the
+/// first aren't represented in the inferred schema. The format is left unset unless it was given
+/// explicitly, so that the format of each file will still be inferred from its own filename.
+fn infer_archive(
+    config: &ParseConfig,
+    mut inferred: ParseConfig,
+    mut entries: ArchiveEntries,
+) -> Result<ParseConfig, ParseError> {
+    let ArchiveEntry { name, content } = entries.next().ok_or(ParseError::CannotInferFormat)??;
+    tracing::info!("inferring config from archive entry: {:?}", name);
+
+    let mut entry_config = config.clone();
+    entry_config.filename = Some(name);
+    entry_config.compression = None;
+    entry_config.content_encoding = None;
+    entry_config.content_type = None;
+    let entry = infer_config(&entry_config, content)?;
+
+    inferred.format = config.format;
+    inferred.schema = entry.schema;
+    inferred.csv = entry.csv;
+    inferred.tsv = entry.tsv;
+    Ok(inferred)
+}
+
+/// Fills in any unset fields of the `section` with the sniffed dialect of the sample.
+fn infer_dialect(
+    section: &mut Option<CharacterSeparatedConfig>,
+    format: Format,
+    sample: &[u8],
+    complete: bool,
+) {
+    sniff_dialect(format, sample, complete)
+        .fill_unset(section.get_or_insert_with(Default::default));
+}
+
+/// Text formats output every value as a string unless the schema allows other types. So each string
+/// is replaced with whatever the parser would coerce it to, if the schema were to allow any type.
+fn coerce_strings(doc: &mut Value) {
+    match doc {
+        Value::Object(properties) => properties.values_mut().for_each(coerce_strings),
+        Value::String(s) if CSV_NULLS.contains(&s.as_str()) => *doc = Value::Null,
+        Value::String(s) if s == "true" || s == "false" => *doc = Value::Bool(s == "true"),
+        Value::String(s) => {
+            if let Ok(n) = serde_json::from_str::<serde_json::Number>(s) {
+                *doc = Value::Number(n);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The accumulated shape of all of the values seen at a particular location.
+#[derive(Default)]
+struct Shape {
+    /// The number of values that have been added.
+    count: usize,
+    types: BTreeSet<&'static str>,
+    strings: usize,
+    /// The number of strings that are valid RFC3339 date-times.
+    date_times: usize,
+    /// The number of object values, which is used to determine which properties are required.
+    objects: usize,
+    properties: BTreeMap<String, Shape>,
+    items: Option<Box<Shape>>,
+}
+
+impl Shape {
+    fn add(&mut self, value: &Value) {
+        self.count += 1;
+        match value {
+            Value::Null => {
+                self.types.insert("null");
+            }
+            Value::Bool(_) => {
+                self.types.insert("boolean");
+            }
+            Value::Number(n) if n.is_f64() => {
+                self.types.insert("number");
+            }
+            Value::Number(_) => {
+                self.types.insert("integer");
+            }
+            Value::String(s) => {
+                self.types.insert("string");
+                self.strings += 1;
+                if chrono::DateTime::parse_from_rfc3339(s).is_ok() {
+                    self.date_times += 1;
+                }
+            }
+            Value::Array(items) => {
+                self.types.insert("array");
+                let shape = self.items.get_or_insert_with(Default::default);
+                for item in items {
+                    shape.add(item);
+                }
+            }
+            Value::Object(properties) => {
+                self.types.insert("object");
+                self.objects += 1;
+                for (name, value) in properties {
+                    self.properties.entry(name.clone()).or_default().add(value);
+                }
+            }
+        }
+    }
+
+    fn to_schema(&self) -> Value {
+        let mut types = self.types.clone();
+        // Integers are also numbers, so there's no need to list both.
+        if types.contains("number") {
+            types.remove("integer");
+        }
+        let mut schema = Map::new();
+        schema.insert(
+            "type".to_string(),
+            match types.len() {
+                1 => json!(types.iter().next().unwrap()),
+                _ => json!(types),
+            },
+        );
+        if self.strings > 0 && self.strings == self.date_times {
+            schema.insert("format".to_string(), json!("date-time"));
+        }
+        if types.contains("object") {
+            let properties = self
+                .properties
+                .iter()
+                .map(|(name, shape)| (name.clone(), shape.to_schema()))
+                .collect::<Map<_, _>>();
+            let required = self
+                .properties
+                .iter()
+                .filter(|(_, shape)| shape.count == self.objects)
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            schema.insert("properties".to_string(), Value::Object(properties));
+            schema.insert("required".to_string(), json!(required));
+        }
+        if let Some(items) = self.items.as_ref().filter(|items| items.count > 0) {
+            schema.insert("items".to_string(), items.to_schema());
+        }
+        Value::Object(schema)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::csv::Char;
+
+    fn input(content: &[u8]) -> Input {
+        Input::Stream(Box::new(std::io::Cursor::new(content.to_vec())))
+    }
+
+    #[test]
+    fn csv_config_and_schema_are_inferred() {
+        let config = ParseConfig {
+            filename: Some("data.csv".to_string()),
+            ..Default::default()
+        };
+        let content = b"id|name|score|seen\n1|ann|1.5|2021-06-15T12:30:00Z\n2|bob|2|\n";
+        let inferred = infer_config(&config, input(content)).expect("failed to infer");
+
+        assert_eq!(Some(Format::Csv), inferred.format);
+        let csv = inferred.csv.expect("csv config should be inferred");
+        assert_eq!(Some(Char(b'|')), csv.delimiter);
+        assert!(csv.headers.is_empty());
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer" },
+                    "name": { "type": "string" },
+                    "score": { "type": "number" },
+                    "seen": { "type": ["null", "string"], "format": "date-time" },
+                },
+                "required": ["id", "name", "score", "seen"],
+            }),
+            inferred.schema
+        );
+    }
+
+    #[test]
+    fn configured_values_take_precedence() {
+        let config = ParseConfig {
+            format: Some(Format::Json),
+            schema: json!({"type": "object"}),
+            ..Default::default()
+        };
+        let inferred =
+            infer_config(&config, input(br#"{"a": 1} {"b": [true]}"#)).expect("failed to infer");
+        assert_eq!(Some(Format::Json), inferred.format);
+        assert_eq!(json!({"type": "object"}), inferred.schema);
+        assert!(inferred.csv.is_none());
+
+        let config = ParseConfig {
+            format: Some(Format::Json),
+            ..Default::default()
+        };
+        let inferred =
+            infer_config(&config, input(br#"{"a": 1} {"b": [true]}"#)).expect("failed to infer");
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "a": { "type": "integer" },
+                    "b": { "type": "array", "items": { "type": "boolean" } },
+                },
+                "required": [],
+            }),
+            inferred.schema
+        );
+    }
+}
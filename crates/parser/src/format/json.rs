@@ -1,7 +1,11 @@
 //! Parser for the json format. This will accept any stream of JSON values separated by whitespace.
 //! It allows any amount of whitespace (including newlines) within and in between records.
-use super::{Input, Output, ParseError, Parser};
+//! Alternatively, a `recordsPointer` may be configured, in which case the items of the array at
+//! that location within each document are output as the records.
+use super::{Input, Output, ParseError, ParseResult, Parser, RecordError};
 use crate::ParseConfig;
+use doc::ptr::{Pointer, Token};
+use std::io::BufRead;
 
 struct JsonParser;
 
@@ -9,8 +13,20 @@ pub fn new_parser() -> Box<dyn Parser> {
     Box::new(JsonParser)
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum JsonPointerError {
+    #[error("the document has no location matching the records pointer '{0}'")]
+    NotFound(String),
+
+    #[error("the location of the records pointer '{0}' is not an array")]
+    NotAnArray(String),
+
+    #[error("invalid JSON at line {line}: expected {expected}")]
+    Syntax { line: u64, expected: &'static str },
+}
+
 impl Parser for JsonParser {
-    fn parse(&self, _config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
         // The JSON RFC (RFC 4627) specifies that JSON content is "unicode", but explicitly allows
         // for UTF-16 and 32 encoding schemes in addition to the default of UTF-8. Technically,
         // we're being too permissive here since `transcode_non_utf8` will accept basically any
@@ -21,8 +37,427 @@ impl Parser for JsonParser {
             // going to look for a BOM.
             .transcode_non_utf8(None, 32)?
             .into_buffered_stream(8192);
+
+        let records_pointer = config
+            .json
+            .as_ref()
+            .and_then(|json| json.records_pointer.as_ref());
+        if let Some(pointer) = records_pointer {
+            return Ok(Box::new(ArrayItems {
+                reader: input,
+                pointer: pointer.as_ref().to_string(),
+                offset: 0,
+                line: 1,
+                enclosing: Vec::new(),
+                state: State::Document,
+            }));
+        }
+
         let deser = serde_json::de::Deserializer::from_reader(input).into_iter();
         let wrapped = deser.map(|r| r.map_err(Into::into));
         Ok(Box::new(wrapped))
     }
 }
+
+enum State {
+    /// Expecting the start of a document, or the end of the content.
+    Document,
+    /// Within the records array, expecting either an item or the end of the array.
+    Items { first: bool },
+    /// Set once the end of the content is reached, or after an error.
+    Done,
+}
+
+/// Outputs the items of the array at the records pointer within each document. The content is
+/// scanned without being parsed, except for the property names that lead to the array, so that only
+/// a single item needs to be held in memory at any given time.
+struct ArrayItems {
+    reader: Box<dyn BufRead>,
+    pointer: String,
+    /// The number of bytes that have been consumed.
+    offset: u64,
+    /// The current line number, starting at 1.
+    line: u64,
+    /// The opening characters of the objects and arrays that enclose the records array.
+    enclosing: Vec<u8>,
+    state: State,
+}
+
+impl Iterator for ArrayItems {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_item() {
+            Ok(Some(item)) => Some(item),
+            Ok(None) => None,
+            Err(err) => {
+                self.state = State::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl ArrayItems {
+    /// Returns the next item, or `None` at the end of the content. The outer error is for problems
+    /// that prevent reading any further, while the inner error is for an item that's invalid.
+    fn next_item(&mut self) -> Result<Option<ParseResult>, ParseError> {
+        loop {
+            match self.state {
+                State::Done => return Ok(None),
+                State::Document => {
+                    if self.skip_whitespace()?.is_none() {
+                        self.state = State::Done;
+                        return Ok(None);
+                    }
+                    self.find_records()?;
+                    self.state = State::Items { first: true };
+                }
+                State::Items { first } => {
+                    if !self.next_element(b']', first)? {
+                        self.skip_enclosing()?;
+                        self.state = State::Document;
+                        continue;
+                    }
+                    self.state = State::Items { first: false };
+
+                    self.skip_whitespace()?;
+                    let (byte_offset, line) = (self.offset, self.line);
+                    let mut raw = Vec::new();
+                    self.read_value(Some(&mut raw))?;
+                    let item = serde_json::from_slice(&raw).map_err(|err| {
+                        ParseError::from(RecordError {
+                            error: err.into(),
+                            byte_offset: Some(byte_offset),
+                            line: Some(line),
+                            raw: Some(raw),
+                        })
+                    });
+                    return Ok(Some(item));
+                }
+            }
+        }
+    }
+
+    /// Reads from the start of a document until just after the opening of the records array.
+    fn find_records(&mut self) -> Result<(), ParseError> {
+        let pointer = Pointer::from_str(&self.pointer);
+        for token in pointer.iter() {
+            let found = match self.skip_whitespace()? {
+                Some(b'{') => {
+                    self.consume(1);
+                    self.enclosing.push(b'{');
+                    let name = match token {
+                        Token::Index(index) => index.to_string(),
+                        Token::Property(property) => property.to_string(),
+                        Token::NextIndex => "-".to_string(),
+                    };
+                    self.find_property(&name)?
+                }
+                Some(b'[') => {
+                    self.consume(1);
+                    self.enclosing.push(b'[');
+                    match token {
+                        Token::Index(index) => self.find_index(index)?,
+                        _ => false,
+                    }
+                }
+                _ => false,
+            };
+            if !found {
+                return Err(self.pointer_error(JsonPointerError::NotFound));
+            }
+        }
+        match self.skip_whitespace()? {
+            Some(b'[') => {
+                self.consume(1);
+                Ok(())
+            }
+            _ => Err(self.pointer_error(JsonPointerError::NotAnArray)),
+        }
+    }
+
+    /// Reads the members of the current object until the value of the property with the given
+    /// `name`. Returns false if the object doesn't have that property.
+    fn find_property(&mut self, name: &str) -> Result<bool, ParseError> {
+        let mut first = true;
+        while self.next_element(b'}', first)? {
+            first = false;
+            if self.read_property_name()? == name {
+                return Ok(true);
+            }
+            self.read_value(None)?;
+        }
+        Ok(false)
+    }
+
+    /// Reads the items of the current array until the one at `index`. Returns false if the array
+    /// doesn't have that many items.
+    fn find_index(&mut self, index: usize) -> Result<bool, ParseError> {
+        let mut i = 0;
+        while self.next_element(b']', i == 0)? {
+            if i == index {
+                return Ok(true);
+            }
+            self.read_value(None)?;
+            i += 1;
+        }
+        Ok(false)
+    }
+
+    /// Reads the remainder of the objects and arrays that enclose the records array, so that the
+    /// next document can be read.
+    fn skip_enclosing(&mut self) -> Result<(), ParseError> {
+        while let Some(open) = self.enclosing.pop() {
+            let close = if open == b'{' { b'}' } else { b']' };
+            while self.next_element(close, false)? {
+                if open == b'{' {
+                    self.read_property_name()?;
+                }
+                self.read_value(None)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Advances to the next element of an object or array, consuming the separating comma unless
+    /// it's the `first` element. Returns false, after consuming the `close` character, if there
+    /// are no more elements.
+    fn next_element(&mut self, close: u8, first: bool) -> Result<bool, ParseError> {
+        match self.skip_whitespace()? {
+            Some(b) if b == close => {
+                self.consume(1);
+                Ok(false)
+            }
+            Some(b',') if !first => {
+                self.consume(1);
+                Ok(true)
+            }
+            Some(_) if first => Ok(true),
+            _ => Err(self.syntax_error(if close == b'}' {
+                "',' or '}'"
+            } else {
+                "',' or ']'"
+            })),
+        }
+    }
+
+    /// Reads an object property name along with the colon that follows it.
+    fn read_property_name(&mut self) -> Result<String, ParseError> {
+        let mut raw = Vec::new();
+        if self.skip_whitespace()? != Some(b'"') {
+            return Err(self.syntax_error("a property name"));
+        }
+        self.read_value(Some(&mut raw))?;
+        let name = serde_json::from_slice(&raw)?;
+        if self.skip_whitespace()? != Some(b':') {
+            return Err(self.syntax_error("':'"));
+        }
+        self.consume(1);
+        Ok(name)
+    }
+
+    /// Reads a single value, appending its bytes to `raw` if it's given. The value is only scanned
+    /// far enough to find where it ends, so it may still be invalid.
+    fn read_value(&mut self, mut raw: Option<&mut Vec<u8>>) -> Result<(), ParseError> {
+        if self.skip_whitespace()?.is_none() {
+            return Err(self.syntax_error("a value"));
+        }
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut len = 0;
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                // Only scalars, such as a number at the very end of the content, can end at EOF.
+                if depth == 0 && !in_string && len > 0 {
+                    return Ok(());
+                }
+                return Err(self.syntax_error("the end of the value"));
+            }
+
+            let mut end = None;
+            for (i, &b) in buf.iter().enumerate() {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if b == b'\\' {
+                        escaped = true;
+                    } else if b == b'"' {
+                        in_string = false;
+                        if depth == 0 {
+                            end = Some(i + 1);
+                            break;
+                        }
+                    }
+                    continue;
+                }
+                match b {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth == 0 => {
+                        end = Some(i);
+                        break;
+                    }
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            end = Some(i + 1);
+                            break;
+                        }
+                    }
+                    b',' | b' ' | b'\t' | b'\n' | b'\r' if depth == 0 => {
+                        end = Some(i);
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            let n = end.unwrap_or(buf.len());
+            if let Some(raw) = raw.as_mut() {
+                raw.extend_from_slice(&buf[..n]);
+            }
+            self.line += buf[..n].iter().filter(|b| **b == b'\n').count() as u64;
+            len += n;
+            self.consume(n);
+
+            if end.is_some() {
+                if len == 0 {
+                    return Err(self.syntax_error("a value"));
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    /// Consumes any whitespace, and returns the next byte without consuming it.
+    fn skip_whitespace(&mut self) -> Result<Option<u8>, ParseError> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            let whitespace = buf
+                .iter()
+                .take_while(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+                .count();
+            if whitespace == 0 {
+                return Ok(buf.first().copied());
+            }
+            self.line += buf[..whitespace].iter().filter(|b| **b == b'\n').count() as u64;
+            self.consume(whitespace);
+        }
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.reader.consume(n);
+        self.offset += n as u64;
+    }
+
+    fn syntax_error(&self, expected: &'static str) -> ParseError {
+        ParseError::Parse(Box::new(JsonPointerError::Syntax {
+            line: self.line,
+            expected,
+        }))
+    }
+
+    fn pointer_error(&self, error: fn(String) -> JsonPointerError) -> ParseError {
+        ParseError::Parse(Box::new(error(self.pointer.clone())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::json::JsonConfig;
+    use serde_json::{json, Value};
+
+    fn parse(pointer: &str, content: &str) -> Vec<Result<Value, ParseError>> {
+        let config = ParseConfig {
+            json: Some(JsonConfig {
+                records_pointer: Some(pointer.into()),
+            }),
+            ..Default::default()
+        };
+        let input = Input::Stream(Box::new(std::io::Cursor::new(content.as_bytes().to_vec())));
+        new_parser().parse(&config, input).unwrap().collect()
+    }
+
+    fn values(results: Vec<Result<Value, ParseError>>) -> Vec<Value> {
+        results
+            .into_iter()
+            .map(|r| r.expect("failed to parse"))
+            .collect()
+    }
+
+    #[test]
+    fn items_of_a_top_level_array_are_output() {
+        let results = parse("", "[1, \"two\", {\"three\": [3]}, null, -4.5e1]");
+        assert_eq!(
+            vec![
+                json!(1),
+                json!("two"),
+                json!({"three": [3]}),
+                json!(null),
+                json!(-45.0)
+            ],
+            values(results)
+        );
+        assert!(parse("", " [ ] ").is_empty());
+    }
+
+    #[test]
+    fn items_of_a_nested_array_are_output_from_each_document() {
+        let content = r#"
+            {"meta": {"note": "a ] \" { tricky"}, "data": [{"id": 1}, {"id": 2}], "after": [[]]}
+            {"data": [{"id": 3}], "meta": null}
+            {"data": []}
+        "#;
+        assert_eq!(
+            vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})],
+            values(parse("/data", content))
+        );
+
+        let content = r#"{"pages": [{"rows": ["a"]}, {"rows": ["b", "c"]}]}"#;
+        assert_eq!(
+            vec![json!("b"), json!("c")],
+            values(parse("/pages/1/rows", content))
+        );
+    }
+
+    #[test]
+    fn invalid_items_are_individual_errors() {
+        let mut results = parse("/data", "{\"data\": [1,\n{\"a\": tru}, 3]}").into_iter();
+        assert_eq!(json!(1), results.next().unwrap().unwrap());
+        match results.next().unwrap() {
+            Err(ParseError::Record(record)) => {
+                assert_eq!(Some(13), record.byte_offset);
+                assert_eq!(Some(2), record.line);
+                assert_eq!(Some(b"{\"a\": tru}".to_vec()), record.raw);
+            }
+            other => panic!("expected a record error, got: {:?}", other),
+        }
+        assert_eq!(json!(3), results.next().unwrap().unwrap());
+        assert!(results.next().is_none());
+    }
+
+    #[test]
+    fn missing_or_invalid_records_location_is_an_error() {
+        let results = parse("/data", r#"{"items": []}"#);
+        assert_eq!(1, results.len());
+        assert!(matches!(
+            &results[0],
+            Err(ParseError::Parse(err)) if err.to_string().contains("no location")
+        ));
+
+        let results = parse("/data", r#"{"data": {"id": 1}}"#);
+        assert!(matches!(
+            &results[0],
+            Err(ParseError::Parse(err)) if err.to_string().contains("not an array")
+        ));
+
+        let results = parse("", "[1, 2");
+        assert_eq!(3, results.len());
+        assert_eq!(json!(2), *results[1].as_ref().unwrap());
+        assert!(results[2].is_err());
+    }
+}
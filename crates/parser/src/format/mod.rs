@@ -1,16 +1,31 @@
 mod avro;
 mod character_separated;
+mod dead_letter;
+mod error_buffer;
+mod excel;
+mod infer;
 mod json;
+mod parquet;
 mod projection;
+mod protobuf;
+mod xml;
 
 use crate::config::ErrorThreshold;
 use crate::decorate::{AddFieldError, Decorator};
-use crate::input::{detect_compression, CompressionError, Input};
+use crate::format::character_separated::sniff_dialect;
+use crate::format::dead_letter::DeadLetters;
+use crate::input::{
+    archive_entries, detect_compression, is_tar_archive, ArchiveEntries, ArchiveEntry,
+    CompressionError, Input,
+};
 use crate::{Compression, Format, ParseConfig};
 
 use serde_json::Value;
 use std::io::{self, Write};
 
+pub use self::error_buffer::Rejections;
+pub use self::infer::infer_config;
+
 /// Error type returned by all parse operations.
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
@@ -45,8 +60,35 @@ pub enum ParseError {
 
     #[error("error limit exceeded")]
     ErrorLimitExceeded(ErrorThreshold),
+
+    #[error("{0}")]
+    Record(Box<RecordError>),
+}
+
+/// An error that pertains to a single record, along with whatever information the parser has about
+/// where the record came from. This information is written to the dead-letter output.
+#[derive(Debug, thiserror::Error)]
+#[error("{error}")]
+pub struct RecordError {
+    pub error: ParseError,
+    /// The offset of the start of the record, after any decompression and transcoding.
+    pub byte_offset: Option<u64>,
+    /// The line number of the start of the record, starting at 1.
+    pub line: Option<u64>,
+    /// The raw content of the record.
+    pub raw: Option<Vec<u8>>,
 }
 
+impl From<RecordError> for ParseError {
+    fn from(err: RecordError) -> ParseError {
+        ParseError::Record(Box::new(err))
+    }
+}
+
+/// The number of bytes at the start of character-separated content that are used to sniff its
+/// dialect.
+const DIALECT_SAMPLE_SIZE: usize = 64 * 1024;
+
 /// Runs format inference if the config does not specify a `format`. The expectation is that more
 /// complex formats will also need to inspect the content in order to determine a recommended
 /// parser configuration, and that this function will also drive that process. For example, the CSV
@@ -59,20 +101,20 @@ pub fn resolve_config(
     mut content: Input,
 ) -> Result<(ParseConfig, Input), ParseError> {
     let mut resolved = ParseConfig::default().override_from(config);
-    if let Some(f) = config.format {
-        tracing::debug!("using provided format: {}", f);
-    } else {
-        let inferred = determine_format(&resolved).ok_or_else(|| ParseError::CannotInferFormat)?;
-        tracing::info!("inferred format: {}", inferred);
-        resolved.format = Some(inferred);
-    }
 
     if let Some(c) = config.compression {
         tracing::debug!(compression = %c, "using provided compression");
     } else {
         let mut inferred = determine_compression(&resolved);
-        if inferred.is_none() {
-            let (bytes, new_input) = content.peek(32)?;
+        // Formats like xlsx are themselves zip archives, so the magic number would be mistaken for
+        // compression if the parser for the format is one that reads the raw content.
+        let raw_format = determine_format(&resolved)
+            .map(|f| !parser_for(f).decompress())
+            .unwrap_or(false);
+        if inferred.is_none() && !raw_format {
+            // Tar archives are identified by a magic string at offset 257, so we need to peek at
+            // the entire first header block instead of just the first few bytes.
+            let (bytes, new_input) = content.peek(512)?;
             content = new_input;
             inferred = detect_compression(&bytes);
         }
@@ -80,9 +122,57 @@ pub fn resolve_config(
         resolved.compression = inferred;
     }
 
+    if let Some(f) = config.format {
+        tracing::debug!("using provided format: {}", f);
+    } else {
+        let mut inferred = determine_format(&resolved);
+        if inferred.is_none() && resolved.compression.is_none() {
+            let (bytes, new_input) = content.peek(32)?;
+            content = new_input;
+            inferred = detect_format(&bytes);
+        }
+        match inferred {
+            Some(f) => {
+                tracing::info!("inferred format: {}", f);
+                resolved.format = Some(f);
+            }
+            // Compressed content may turn out to be an archive, in which case the format of each
+            // file within it is inferred separately. Otherwise, the format may still be detected
+            // from the content once it's been decompressed.
+            None if resolved.compression.is_some() => {
+                tracing::debug!("deferring format inference until content is decompressed");
+            }
+            None => return Err(ParseError::CannotInferFormat),
+        }
+    }
+
     Ok((resolved, content))
 }
 
+/// Character-separated formats have many dialects, so any options that aren't configured are
+/// sniffed from the start of the `content`, which must already have been decompressed. Returns
+/// the `config` with the sniffed options filled in, or `None` if the format has no dialect.
+fn sniff_unset_dialect(
+    config: &ParseConfig,
+    format: Format,
+    content: Input,
+) -> Result<(Option<ParseConfig>, Input), ParseError> {
+    if !matches!(format, Format::Csv | Format::Tsv) {
+        return Ok((None, content));
+    }
+    let (sample, content) = content.peek(DIALECT_SAMPLE_SIZE)?;
+    let dialect = sniff_dialect(format, &sample, sample.len() < DIALECT_SAMPLE_SIZE);
+    tracing::debug!(?dialect, "sniffed dialect");
+
+    let mut sniffed = config.clone();
+    let section = match format {
+        Format::Csv => &mut sniffed.csv,
+        _ => &mut sniffed.tsv,
+    };
+    dialect.fill_unset(section.get_or_insert_with(Default::default));
+    Ok((Some(sniffed), content))
+}
+
 /// Drives the parsing process using the given configuration, input, and output streams. The
 /// `content` will be parsed according to the `config` and written in JSONL format to `dest`.
 /// The given `config` will be used to override any default or recommended values.
@@ -91,19 +181,152 @@ pub fn parse(
     content: Input,
     dest: &mut impl io::Write,
 ) -> Result<(), ParseError> {
-    let (config, content) = resolve_config(config, content)?;
-    tracing::debug!(config = ?config, "resolved config");
-    let format = config.format.ok_or(ParseError::MissingFormat)?;
-    let parser = parser_for(format);
+    parse_with_dead_letters(config, content, dest, None)
+}
 
-    let input = if parser.decompress() && config.compression.is_some() {
-        content.decompressed(config.compression.unwrap())?
-    } else {
-        content
-    };
+/// The same as `parse`, except that any records that fail to parse are written in JSONL format to
+/// `dead_letters`, if it's provided. Each line includes the error message, along with the byte
+/// offset, line number, and raw content of the record, for formats where they're known. Records
+/// that are absorbed by an `errorThreshold` are written as they're encountered, and an error that
+/// halts parsing is written before it's returned.
+pub fn parse_with_dead_letters(
+    config: &ParseConfig,
+    content: Input,
+    dest: &mut impl io::Write,
+    dead_letters: Option<&mut dyn io::Write>,
+) -> Result<(), ParseError> {
+    let (resolved, content) = resolve_config(config, content)?;
+    tracing::debug!(config = ?resolved, "resolved config");
+
+    let mut buffer = io::BufWriter::new(dest);
+    let mut dead_letters = DeadLetters::new(dead_letters);
+    let mut record_count = 0u64;
+    let result = parse_resolved(
+        config,
+        &resolved,
+        content,
+        None,
+        &mut buffer,
+        &mut record_count,
+        &mut dead_letters,
+    );
+    // Flush the records that were successfully parsed, even if parsing ultimately failed.
+    buffer.flush()?;
+    result?;
+    tracing::info!(
+        record_count = record_count,
+        rejected_count = dead_letters.count(),
+        "successfully finished parsing"
+    );
+    Ok(())
+}
+
+/// Parses `content` using a `config` that has already been resolved from the `user_config`. If the
+/// content is an archive, then each file within it will be parsed separately. The `entry_name` is
+/// the name of the archive entry that `content` was read from, if any.
+fn parse_resolved(
+    user_config: &ParseConfig,
+    config: &ParseConfig,
+    mut content: Input,
+    entry_name: Option<&str>,
+    dest: &mut impl io::Write,
+    record_count: &mut u64,
+    dead_letters: &mut DeadLetters,
+) -> Result<(), ParseError> {
+    let mut format = config.format;
+    let decompress = format.map(|f| parser_for(f).decompress()).unwrap_or(true);
+
+    if let Some(compression) = config.compression.filter(|_| decompress) {
+        if compression.is_archive() {
+            let entries = archive_entries(content, compression)?;
+            return parse_archive(
+                user_config,
+                config,
+                entries,
+                dest,
+                record_count,
+                dead_letters,
+            );
+        }
+        let (prefix, decompressed) = content.decompressed(compression)?.peek(512)?;
+        // Compressed tarballs (e.g. `.tar.gz`) can only be identified once they've been
+        // decompressed.
+        if is_tar_archive(&prefix) {
+            tracing::debug!(%compression, "decompressed content is a tar archive");
+            let entries = archive_entries(decompressed, Compression::TarArchive)?;
+            return parse_archive(
+                user_config,
+                config,
+                entries,
+                dest,
+                record_count,
+                dead_letters,
+            );
+        }
+        format = format.or_else(|| detect_format(&prefix));
+        content = decompressed;
+    }
+
+    let format = format.ok_or(ParseError::CannotInferFormat)?;
+    let (sniffed, content) = sniff_unset_dialect(config, format, content)?;
+    let config = sniffed.as_ref().unwrap_or(config);
+    let rejections = Rejections::collect();
+    let output = parser_for(format).parse_with_rejections(config, content, rejections.clone())?;
+    let mut decorator = Decorator::from_config(config);
+    if let Some(name) = entry_name {
+        decorator = decorator.for_archive_entry(config, name);
+    }
+    format_output(
+        config.filename.as_deref(),
+        &decorator,
+        output,
+        &rejections,
+        dest,
+        record_count,
+        dead_letters,
+    )
+}
+
+/// Parses each file within an archive, in the order they appear. Each file gets its own resolved
+/// config, so that its format is inferred from its own filename rather than the name of the
+/// archive. If the `user_config` specifies a format, then it's used for every file. Otherwise,
+/// any file whose format can't be inferred falls back to the format inferred for the archive as a
+/// whole, if there is one.
+fn parse_archive(
+    user_config: &ParseConfig,
+    archive_config: &ParseConfig,
+    entries: ArchiveEntries,
+    dest: &mut impl io::Write,
+    record_count: &mut u64,
+    dead_letters: &mut DeadLetters,
+) -> Result<(), ParseError> {
+    for entry in entries {
+        let ArchiveEntry { name, content } = entry?;
+        tracing::info!("parsing archive entry: {:?}", name);
+
+        let mut entry_config = user_config.clone();
+        entry_config.filename = Some(name.clone());
+        // The compression and content type of the archive don't apply to the files within it.
+        entry_config.compression = None;
+        entry_config.content_encoding = None;
+        entry_config.content_type = None;
+        if entry_config.format.is_none() {
+            let defaulted = ParseConfig::default().override_from(&entry_config);
+            entry_config.format = determine_format(&defaulted).or(archive_config.format);
+        }
 
-    let output = parser.parse(&config, input)?;
-    format_output(&config, output, dest)
+        let (resolved, content) = resolve_config(&entry_config, content)?;
+        parse_resolved(
+            &entry_config,
+            &resolved,
+            content,
+            Some(&name),
+            dest,
+            record_count,
+            dead_letters,
+        )?;
+    }
+    Ok(())
 }
 
 fn parser_for(format: Format) -> Box<dyn Parser> {
@@ -113,6 +336,11 @@ fn parser_for(format: Format) -> Box<dyn Parser> {
         Format::Tsv => character_separated::new_tsv_parser(),
         Format::W3cExtendedLog => character_separated::new_w3c_extended_log_parser(),
         Format::Avro => avro::new_parser(),
+        Format::Parquet => parquet::new_parser(),
+        Format::Excel => excel::new_parser(),
+        Format::FixedWidth => character_separated::new_fixed_width_parser(),
+        Format::Protobuf => protobuf::new_parser(),
+        Format::Xml => xml::new_parser(),
     }
 }
 
@@ -138,28 +366,55 @@ pub trait Parser {
 
     /// Parse the given `content` using the `config`, which will already have been fully resolved.
     fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError>;
+
+    /// The same as `parse`, except that records which are absorbed by an `errorThreshold` are sent
+    /// to the `rejections`. Parsers that don't support an error threshold never reject records, and
+    /// can rely on this default implementation.
+    fn parse_with_rejections(
+        &self,
+        config: &ParseConfig,
+        content: Input,
+        _rejections: Rejections,
+    ) -> Result<Output, ParseError> {
+        self.parse(config, content)
+    }
 }
 
-/// Takes the output of a parser and writes it to the given destination, generally stdout.
+/// Takes the output of a parser and writes it to the given destination, generally stdout. The
+/// `record_count` is shared across all of the outputs written to the same destination, so that
+/// record offsets remain monotonic when an archive contains multiple files, while the offset
+/// within each individual output starts over at 0. Records that fail to parse, including those
+/// that the parser sent to `rejections`, are written to the `dead_letters`, and don't count
+/// towards either offset.
 fn format_output(
-    config: &ParseConfig,
+    filename: Option<&str>,
+    decorator: &Decorator,
     output: Output,
+    rejections: &Rejections,
     dest: &mut impl io::Write,
+    record_count: &mut u64,
+    dead_letters: &mut DeadLetters,
 ) -> Result<(), ParseError> {
-    let decorator = Decorator::from_config(config);
-    let mut buffer = io::BufWriter::new(dest);
-    let mut record_count = 0u64;
-
-    for result in output {
-        let mut value = result?;
+    for (entry_offset, result) in output.enumerate() {
+        for err in rejections.take() {
+            dead_letters.reject(filename, &err)?;
+        }
+        let mut value = match result {
+            Ok(value) => value,
+            Err(err) => {
+                dead_letters.reject(filename, &err)?;
+                return Err(err);
+            }
+        };
 
-        decorator.add_fields(record_count, &mut value)?;
-        serde_json::to_writer(&mut buffer, &value)?;
-        buffer.write_all(&[b'\n'])?;
-        record_count += 1;
+        decorator.add_fields(*record_count, entry_offset as u64, &mut value)?;
+        serde_json::to_writer(&mut *dest, &value)?;
+        dest.write_all(&[b'\n'])?;
+        *record_count += 1;
+    }
+    for err in rejections.take() {
+        dead_letters.reject(filename, &err)?;
     }
-    buffer.flush()?;
-    tracing::info!(record_count = record_count, "successfully finished parsing");
     Ok(())
 }
 
@@ -184,6 +439,17 @@ fn determine_format(config: &ParseConfig) -> Option<Format> {
         })
 }
 
+/// Checks for a "magic number" at the start of the content, and returns a corresponding format if
+/// one is detected. This is only used as a fallback when the format cannot be determined from the
+/// config, since most formats don't have any magic number.
+fn detect_format(prefix: &[u8]) -> Option<Format> {
+    if prefix.starts_with(b"PAR1") {
+        Some(Format::Parquet)
+    } else {
+        None
+    }
+}
+
 fn extensions(filename: &str) -> impl Iterator<Item = &str> {
     let start = filename
         .char_indices()
@@ -223,7 +489,11 @@ fn determine_compression(config: &ParseConfig) -> Option<Compression> {
 
 fn compression_from_content_encoding(content_encoding: &str) -> Option<Compression> {
     match content_encoding.trim() {
-        "gzip" => Some(Compression::Gzip),
+        "gzip" | "x-gzip" => Some(Compression::Gzip),
+        "zstd" => Some(Compression::Zstd),
+        "bzip2" | "x-bzip2" => Some(Compression::Bzip2),
+        "xz" | "x-xz" => Some(Compression::Xz),
+        "lz4" | "x-lz4" => Some(Compression::Lz4),
         // TODO: Add support for deflate, br, and compress
         // deflate, confusingly, actually maps to zlib (rfc 1950)
         other => {
@@ -244,6 +514,10 @@ fn compression_from_content_type(content_type: &str) -> Option<Compression> {
         .and_then(|ct| match ct.essence_str() {
             "application/gzip" => Some(Compression::Gzip),
             "application/zip" => Some(Compression::ZipArchive),
+            "application/zstd" => Some(Compression::Zstd),
+            "application/x-bzip2" => Some(Compression::Bzip2),
+            "application/x-xz" => Some(Compression::Xz),
+            "application/x-lz4" => Some(Compression::Lz4),
             _ => None,
         })
 }
@@ -252,6 +526,10 @@ fn compression_from_filename(filename: &str) -> Option<Compression> {
     extensions(filename).find_map(|ext| match ext {
         "gz" => Some(Compression::Gzip),
         "zip" => Some(Compression::ZipArchive),
+        "zst" | "zstd" => Some(Compression::Zstd),
+        "bz2" => Some(Compression::Bzip2),
+        "xz" => Some(Compression::Xz),
+        "lz4" => Some(Compression::Lz4),
         _ => None,
     })
 }
@@ -300,6 +578,37 @@ mod test {
         assert_eq!(Compression::ZipArchive, result);
     }
 
+    #[test]
+    fn additional_compression_formats_are_determined_from_config() {
+        let cases = &[
+            ("some.csv.zst", Compression::Zstd),
+            ("some.csv.bz2", Compression::Bzip2),
+            ("some.csv.xz", Compression::Xz),
+            ("some.csv.lz4", Compression::Lz4),
+        ];
+        for (filename, expected) in cases {
+            let conf = ParseConfig {
+                filename: Some(filename.to_string()),
+                ..Default::default()
+            };
+            assert_eq!(Some(*expected), determine_compression(&conf));
+        }
+
+        let conf = ParseConfig {
+            filename: Some("some.csv".to_string()),
+            content_encoding: Some("zstd".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(Some(Compression::Zstd), determine_compression(&conf));
+
+        let conf = ParseConfig {
+            filename: Some("some.csv".to_string()),
+            content_type: Some("application/x-xz".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(Some(Compression::Xz), determine_compression(&conf));
+    }
+
     #[test]
     fn format_is_determined_from_file_extension() {
         let mut conf = ParseConfig {
@@ -326,6 +635,150 @@ mod test {
         assert_format_eq(None, &conf);
     }
 
+    #[test]
+    fn format_is_detected_from_magic_number() {
+        assert_eq!(Some(Format::Parquet), detect_format(b"PAR1\x15\x04\x15"));
+        assert_eq!(None, detect_format(b"PAR"));
+        assert_eq!(None, detect_format(b"{\"PAR1\": true}"));
+
+        let conf = ParseConfig {
+            filename: Some("data.parquet".to_string()),
+            ..Default::default()
+        };
+        assert_format_eq(Some(Format::Parquet), &conf);
+    }
+
+    #[test]
+    fn xlsx_files_are_not_treated_as_zip_archives() {
+        let conf = ParseConfig {
+            filename: Some("report.xlsx".to_string()),
+            ..Default::default()
+        };
+        // Xlsx files begin with the same magic number as any other zip archive.
+        let content = Input::Stream(Box::new(io::Cursor::new(b"PK\x03\x04\x14\x00".to_vec())));
+        let (resolved, _) = resolve_config(&conf, content).expect("failed to resolve config");
+        assert_eq!(Some(Format::Excel), resolved.format);
+        assert_eq!(None, resolved.compression);
+    }
+
+    #[test]
+    fn files_within_tar_archives_are_parsed_using_their_own_formats() {
+        use flate2::write::GzEncoder;
+
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Default::default()));
+        for (path, content) in &[
+            ("export/a.csv", "id,name\n1,foo\n2,bar\n"),
+            ("export/b.jsonl", "{\"id\": 3, \"name\": \"baz\"}\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, content.as_bytes())
+                .unwrap();
+        }
+        let tarball = builder.into_inner().unwrap().finish().unwrap();
+
+        let config = ParseConfig {
+            filename: Some("export.tar.gz".to_string()),
+            add_record_offset: Some("/offset".into()),
+            ..Default::default()
+        };
+        let input = Input::Stream(Box::new(io::Cursor::new(tarball)));
+        let mut dest = Vec::new();
+        parse(&config, input, &mut dest).expect("failed to parse tarball");
+
+        let actual = dest
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                serde_json::json!({"id": "1", "name": "foo", "offset": 0}),
+                serde_json::json!({"id": "2", "name": "bar", "offset": 1}),
+                serde_json::json!({"id": 3, "name": "baz", "offset": 2}),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn dialect_of_compressed_csv_is_sniffed_after_decompression() {
+        use flate2::write::GzEncoder;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(b"id;name\n1;foo\n2;bar\n").unwrap();
+        let config = ParseConfig {
+            filename: Some("data.csv.gz".to_string()),
+            ..Default::default()
+        };
+        let input = Input::Stream(Box::new(io::Cursor::new(encoder.finish().unwrap())));
+        let mut dest = Vec::new();
+        parse(&config, input, &mut dest).expect("failed to parse");
+
+        let actual = dest
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                serde_json::json!({"id": "1", "name": "foo"}),
+                serde_json::json!({"id": "2", "name": "bar"}),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn files_within_zip_archives_are_parsed_using_their_own_formats() {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        for (path, content) in &[
+            ("a.csv", "id,name\n1,foo\n2,bar\n"),
+            ("b.json", "{\"id\": 3, \"name\": \"baz\"}\n"),
+        ] {
+            writer.start_file(*path, Default::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        let archive = writer.finish().unwrap().into_inner();
+
+        let config = ParseConfig {
+            add_record_offset: Some("/offset".into()),
+            add_archive_entry: Some("/_meta/entry".into()),
+            ..Default::default()
+        };
+        let input = Input::Stream(Box::new(io::Cursor::new(archive)));
+        let mut dest = Vec::new();
+        parse(&config, input, &mut dest).expect("failed to parse zip archive");
+
+        let actual = dest
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                serde_json::json!({
+                    "id": "1", "name": "foo", "offset": 0,
+                    "_meta": {"entry": {"name": "a.csv", "offset": 0}},
+                }),
+                serde_json::json!({
+                    "id": "2", "name": "bar", "offset": 1,
+                    "_meta": {"entry": {"name": "a.csv", "offset": 1}},
+                }),
+                serde_json::json!({
+                    "id": 3, "name": "baz", "offset": 2,
+                    "_meta": {"entry": {"name": "b.json", "offset": 0}},
+                }),
+            ],
+            actual
+        );
+    }
+
     fn assert_format_eq(expected: Option<Format>, config: &ParseConfig) {
         let actual = determine_format(config);
         assert_eq!(
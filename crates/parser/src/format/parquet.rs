@@ -0,0 +1,372 @@
+use crate::config::ParseConfig;
+use crate::decorate::display_ptr;
+use crate::format::avro::{self, to_secs_and_nanos, MICROS_PER_SEC, MILLIS_PER_SEC};
+use crate::format::projection::{build_projections, Projection};
+use crate::format::{Output, ParseError, ParseResult, Parser};
+use crate::input::Input;
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use json::schema::types;
+use num_bigint::BigInt;
+use parquet::data_type::Decimal;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::reader::RowIter;
+use parquet::record::{Field, Row};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+pub struct ParquetParser;
+
+/// Returns a type-erased parser trait object for parsing parquet files.
+pub fn new_parser() -> Box<dyn Parser> {
+    Box::new(ParquetParser)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParquetError {
+    #[error(transparent)]
+    Read(#[from] parquet::errors::ParquetError),
+
+    #[error("invalid floating point value '{0}' for column '{1}'")]
+    InvalidFloat(String, String),
+
+    #[error("unable to convert parquet to json because the projected location '{0}' (column '{1}') conflicts with the document structure: {2}")]
+    ImpossibleDocument(String, String, Value),
+
+    /// Like avro, date-like values in parquet are logical types on top of integer primitives, so
+    /// they may be out of range. This error is returned in that case.
+    #[error("the column '{2}' value {0} is out of range for a parquet {1}")]
+    DateTimeOverflow(i64, &'static str, String),
+}
+
+impl Parser for ParquetParser {
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        let iter = ParquetIter::from_config_and_input(config, content)?;
+        Ok(Box::new(iter))
+    }
+}
+
+struct ParquetIter {
+    rows: RowIter<'static>,
+    projections: BTreeMap<String, Projection>,
+}
+
+impl ParquetIter {
+    fn from_config_and_input(
+        config: &ParseConfig,
+        content: Input,
+    ) -> Result<ParquetIter, ParseError> {
+        let projections = build_projections(config)?;
+
+        // Parquet files store their metadata in a footer at the end of the file, so they can only
+        // be read from a seekable file. Streams will be buffered to a temporary file first.
+        let file = content.into_file()?;
+        let reader = SerializedFileReader::new(file)
+            .map_err(|err| ParseError::Parse(Box::new(ParquetError::Read(err))))?;
+
+        let file_meta = reader.metadata().file_metadata();
+        tracing::debug!(
+            num_rows = file_meta.num_rows(),
+            num_row_groups = reader.num_row_groups(),
+            parquet_schema = ?file_meta.schema(),
+            "parsed parquet footer"
+        );
+
+        // Projections only apply to the top-level columns of the parquet schema. Nested groups,
+        // lists, and maps are converted into the corresponding JSON structures.
+        let mut resolved = BTreeMap::new();
+        for field in file_meta.schema_descr().root_schema().get_fields() {
+            let projection = projections.lookup(field.name());
+            resolved.insert(field.name().to_string(), projection);
+        }
+        tracing::debug!(projections = ?resolved, "resolved projections for parquet schema");
+
+        Ok(ParquetIter {
+            rows: reader.into_iter(),
+            projections: resolved,
+        })
+    }
+
+    fn row_to_json(&self, row: Row) -> Result<Value, ParquetError> {
+        let mut json = Value::Object(serde_json::Map::with_capacity(row.len()));
+
+        for (column_name, field) in row.get_column_iter() {
+            let projection = self
+                .projections
+                .get(column_name)
+                .expect("missing projection for parquet column");
+            let allow_string_repr = projection
+                .possible_types
+                .map(|t| t.overlaps(types::STRING))
+                .unwrap_or(true);
+            let json_value = parquet_to_json(column_name, field, allow_string_repr)?;
+
+            if let Some(loc) = projection.target_location.create(&mut json) {
+                *loc = json_value;
+            } else {
+                return Err(ParquetError::ImpossibleDocument(
+                    display_ptr(&projection.target_location),
+                    column_name.clone(),
+                    json.clone(),
+                ));
+            }
+        }
+        Ok(json)
+    }
+}
+
+impl Iterator for ParquetIter {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next()?;
+        let result = self
+            .row_to_json(row)
+            .map_err(|err| ParseError::Parse(Box::new(err)));
+        Some(result)
+    }
+}
+
+/// Converts a parquet Field to a json Value. Logical types are handled the same way as they are
+/// for avro: timestamps and dates are converted to RFC3339 strings by default, and decimals are
+/// converted to exact string representations. If the JSON schema doesn't permit strings at a
+/// given location, then these are instead converted to JSON numbers.
+fn parquet_to_json(
+    column_name: &str,
+    field: &Field,
+    allow_string_repr: bool,
+) -> Result<Value, ParquetError> {
+    match field {
+        Field::Null => Ok(Value::Null),
+        Field::Bool(b) => Ok(Value::Bool(*b)),
+        Field::Byte(i) => Ok(Value::from(*i)),
+        Field::Short(i) => Ok(Value::from(*i)),
+        Field::Int(i) => Ok(Value::from(*i)),
+        Field::Long(i) => Ok(Value::from(*i)),
+        Field::UByte(i) => Ok(Value::from(*i)),
+        Field::UShort(i) => Ok(Value::from(*i)),
+        Field::UInt(i) => Ok(Value::from(*i)),
+        Field::ULong(i) => Ok(Value::from(*i)),
+        // See the comment in `avro_to_json` regarding the handling of NAN and +/-inf.
+        Field::Float(f) => float_to_json(column_name, *f as f64, allow_string_repr),
+        Field::Double(d) => float_to_json(column_name, *d, allow_string_repr),
+        Field::Str(s) => Ok(Value::String(s.clone())),
+        Field::Bytes(b) if allow_string_repr => Ok(Value::String(base64::encode(b.data()))),
+        Field::Bytes(b) => Ok(Value::Array(
+            b.data().iter().copied().map(Value::from).collect(),
+        )),
+        Field::Decimal(d) if allow_string_repr => Ok(Value::String(decimal_to_string(d))),
+        Field::Decimal(d) => {
+            let num = serde_json::from_str::<serde_json::Number>(&decimal_to_string(d))
+                .expect("decimal string is always a valid json number");
+            Ok(Value::Number(num))
+        }
+
+        Field::Date(d) if allow_string_repr => {
+            // Parquet dates are the number of days since the unix epoch.
+            const SECS_PER_DAY: i64 = 86_400;
+            let days = *d as i64;
+            let ts = days
+                .checked_mul(SECS_PER_DAY)
+                .and_then(|secs| NaiveDateTime::from_timestamp_opt(secs, 0))
+                .ok_or_else(|| {
+                    ParquetError::DateTimeOverflow(days, "date", column_name.to_string())
+                })?;
+            Ok(Value::String(ts.date().to_string()))
+        }
+        Field::TimestampMillis(t) if allow_string_repr => {
+            rfc3339_from_unix_epoch("timestamp-millis", column_name, *t as i64, MILLIS_PER_SEC)
+        }
+        Field::TimestampMicros(t) if allow_string_repr => {
+            rfc3339_from_unix_epoch("timestamp-micros", column_name, *t as i64, MICROS_PER_SEC)
+        }
+        Field::Date(i) => Ok(Value::from(*i as i64)),
+        Field::TimestampMillis(i) | Field::TimestampMicros(i) => Ok(Value::from(*i as i64)),
+
+        // Within nested structures, we always allow types to be represented as strings, for the
+        // same reasons as in `avro_to_json`.
+        Field::Group(row) => row
+            .get_column_iter()
+            .map(|(k, v)| parquet_to_json(column_name, v, true).map(|jv| (k.clone(), jv)))
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(Value::Object),
+        Field::ListInternal(list) => list
+            .elements()
+            .iter()
+            .map(|i| parquet_to_json(column_name, i, true))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Field::MapInternal(map) => map
+            .entries()
+            .iter()
+            .map(|(k, v)| {
+                // JSON object keys must be strings, so non-string map keys are converted using
+                // their display representation.
+                let key = match k {
+                    Field::Str(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                parquet_to_json(column_name, v, true).map(|jv| (key, jv))
+            })
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(Value::Object),
+    }
+}
+
+fn float_to_json(
+    column_name: &str,
+    f: f64,
+    allow_string_repr: bool,
+) -> Result<Value, ParquetError> {
+    match serde_json::Number::from_f64(f) {
+        Some(num) => Ok(Value::Number(num)),
+        None if allow_string_repr => Ok(Value::String(f.to_string())),
+        None => Err(ParquetError::InvalidFloat(
+            f.to_string(),
+            column_name.to_string(),
+        )),
+    }
+}
+
+fn rfc3339_from_unix_epoch(
+    parquet_type: &'static str,
+    column: &str,
+    duration: i64,
+    units_per_second: i64,
+) -> Result<Value, ParquetError> {
+    let (secs, nanos) = to_secs_and_nanos(duration, units_per_second);
+    let ts = NaiveDateTime::from_timestamp_opt(secs, nanos).ok_or_else(|| {
+        ParquetError::DateTimeOverflow(duration, parquet_type, column.to_string())
+    })?;
+    let ts = DateTime::<Utc>::from_utc(ts, Utc);
+    Ok(Value::String(
+        ts.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    ))
+}
+
+/// Returns the exact decimal representation of the given value. Parquet decimals are stored as
+/// big-endian two's complement unscaled integers, along with a scale that gives the number of
+/// digits after the decimal point.
+fn decimal_to_string(decimal: &Decimal) -> String {
+    let unscaled = BigInt::from_signed_bytes_be(decimal.data());
+    avro::decimal_to_string(&unscaled, decimal.scale() as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parquet::column::writer::ColumnWriter;
+    use parquet::data_type::ByteArray;
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::{FileWriter, SerializedFileWriter};
+    use parquet::schema::parser::parse_message_type;
+    use std::io::Seek;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_decimal_to_string() {
+        let test_cases: &[(i32, i32, &str)] = &[
+            (12345, 2, "123.45"),
+            (-12345, 2, "-123.45"),
+            (5, 3, "0.005"),
+            (-5, 3, "-0.005"),
+            (0, 2, "0.00"),
+            (42, 0, "42"),
+            (42, -2, "4200"),
+        ];
+        for (unscaled, scale, expected) in test_cases {
+            let decimal = Decimal::from_i32(*unscaled, 9, *scale);
+            assert_eq!(
+                *expected,
+                decimal_to_string(&decimal),
+                "unscaled: {}, scale: {}",
+                unscaled,
+                scale
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversion_of_parquet_logical_types() {
+        let schema = Arc::new(
+            parse_message_type(
+                "message therecord {
+                    REQUIRED INT64 id;
+                    REQUIRED BYTE_ARRAY name (UTF8);
+                    REQUIRED INT32 price (DECIMAL(9, 2));
+                    REQUIRED INT32 day (DATE);
+                    REQUIRED INT64 ts_string (TIMESTAMP_MILLIS);
+                    REQUIRED INT64 ts (TIMESTAMP_MILLIS);
+                }",
+            )
+            .unwrap(),
+        );
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), schema, props).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column_index = 0;
+        while let Some(mut column) = row_group.next_column().unwrap() {
+            match (column_index, &mut column) {
+                (0, ColumnWriter::Int64ColumnWriter(w)) => {
+                    w.write_batch(&[1, 2], None, None).unwrap();
+                }
+                (1, ColumnWriter::ByteArrayColumnWriter(w)) => {
+                    let names = vec![ByteArray::from("first"), ByteArray::from("second")];
+                    w.write_batch(&names, None, None).unwrap();
+                }
+                (2, ColumnWriter::Int32ColumnWriter(w)) => {
+                    w.write_batch(&[12345, -5], None, None).unwrap();
+                }
+                (3, ColumnWriter::Int32ColumnWriter(w)) => {
+                    w.write_batch(&[23456, 0], None, None).unwrap();
+                }
+                (4, ColumnWriter::Int64ColumnWriter(w))
+                | (5, ColumnWriter::Int64ColumnWriter(w)) => {
+                    w.write_batch(&[1_607_483_647_000, 1_607_483_647_123], None, None)
+                        .unwrap();
+                }
+                _ => panic!("unexpected column at index {}", column_index),
+            }
+            row_group.close_column(column).unwrap();
+            column_index += 1;
+        }
+        writer.close_row_group(row_group).unwrap();
+        writer.close().unwrap();
+        file.seek(std::io::SeekFrom::Start(0)).unwrap();
+
+        let config = ParseConfig {
+            schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "ts": {"type": "integer"}
+                }
+            }),
+            ..Default::default()
+        };
+        let results = ParquetIter::from_config_and_input(&config, Input::File(file))
+            .expect("parse failed")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse rows");
+
+        let expected = vec![
+            serde_json::json!({
+                "id": 1,
+                "name": "first",
+                "price": "123.45",
+                "day": "2034-03-22",
+                "ts_string": "2020-12-09T03:14:07Z",
+                "ts": 1_607_483_647_000i64,
+            }),
+            serde_json::json!({
+                "id": 2,
+                "name": "second",
+                "price": "-0.05",
+                "day": "1970-01-01",
+                "ts_string": "2020-12-09T03:14:07.123Z",
+                "ts": 1_607_483_647_123i64,
+            }),
+        ];
+        assert_eq!(expected, results);
+    }
+}
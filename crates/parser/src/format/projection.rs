@@ -19,6 +19,8 @@ use unicode_normalization::UnicodeNormalization;
 pub struct Projection {
     /// The possible JSON types for this location, if any type information could be inferred.
     pub possible_types: Option<types::Set>,
+    /// The `format` annotation of string values at this location, such as `date-time`, if any.
+    pub format: Option<String>,
     /// True only if this location must exist in order for a JSON document to validate against the
     /// schema. Otherwise, false.
     pub must_exist: bool,
@@ -32,6 +34,7 @@ impl Projection {
             target_location,
             must_exist: exists == Exists::Must,
             possible_types: Some(shape.type_),
+            format: shape.string.format.clone(),
         }
     }
 }
@@ -123,6 +126,7 @@ impl Projections {
             );
             Projection {
                 possible_types: None,
+                format: None,
                 must_exist: false,
                 target_location: ptr,
             }
@@ -328,6 +332,7 @@ mod test {
         fn projection(ty: Option<types::Set>, must_exist: bool, ptr: &str) -> Projection {
             Projection {
                 possible_types: ty,
+                format: None,
                 must_exist,
                 target_location: Pointer::from_str(ptr),
             }
@@ -0,0 +1,318 @@
+use crate::config::{protobuf::ProtobufConfig, ParseConfig};
+use crate::format::{Output, ParseError, ParseResult, Parser};
+use crate::input::Input;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use std::io::{self, Read};
+
+/// The maximum size of a single message. Lengths beyond this are much more likely to be the result
+/// of reading something that isn't actually a length-delimited stream than they are to be real.
+const MAX_MESSAGE_LEN: u64 = 256 * 1024 * 1024;
+
+pub struct ProtobufParser;
+
+/// Returns a type-erased parser trait object for parsing length-delimited protobuf messages.
+pub fn new_parser() -> Box<dyn Parser> {
+    Box::new(ProtobufParser)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProtobufError {
+    #[error("the protobuf format requires the `message` type to be configured")]
+    MissingMessage,
+
+    #[error("the protobuf format requires either `fileDescriptorSet` or `fileDescriptorSetPath` to be configured")]
+    MissingDescriptors,
+
+    #[error("failed to decode base64 fileDescriptorSet: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("failed to read fileDescriptorSetPath '{0}': {1}")]
+    ReadDescriptors(String, io::Error),
+
+    #[error("invalid fileDescriptorSet: {0}")]
+    InvalidDescriptors(#[from] prost_reflect::DescriptorError),
+
+    #[error("the fileDescriptorSet does not contain a message type named '{0}'")]
+    UnknownMessage(String),
+
+    #[error("message {0} has a length of {1} bytes, which exceeds the maximum of {2} bytes")]
+    MessageTooLarge(u64, u64, u64),
+
+    #[error("failed to decode message {0}: {1}")]
+    Decode(u64, prost::DecodeError),
+
+    #[error("failed to convert message {0} to JSON: {1}")]
+    Json(u64, serde_json::Error),
+}
+
+impl Parser for ProtobufParser {
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        let pb_config = config.protobuf.clone().unwrap_or_default();
+        let descriptor =
+            message_descriptor(&pb_config).map_err(|err| ParseError::Parse(Box::new(err)))?;
+        tracing::debug!(message = %descriptor.full_name(), "resolved protobuf message type");
+
+        Ok(Box::new(ProtobufIter {
+            descriptor,
+            reader: content.into_buffered_stream(64 * 1024),
+            buffer: Vec::new(),
+            message_num: 0,
+        }))
+    }
+}
+
+/// Returns the descriptor of the configured message type, from the configured descriptor set.
+fn message_descriptor(config: &ProtobufConfig) -> Result<MessageDescriptor, ProtobufError> {
+    let name = config
+        .message
+        .as_deref()
+        .ok_or(ProtobufError::MissingMessage)?;
+    let descriptor_bytes = if let Some(encoded) = config.file_descriptor_set.as_ref() {
+        base64::decode(encoded.trim())?
+    } else if let Some(path) = config.file_descriptor_set_path.as_ref() {
+        std::fs::read(path).map_err(|err| ProtobufError::ReadDescriptors(path.clone(), err))?
+    } else {
+        return Err(ProtobufError::MissingDescriptors);
+    };
+
+    let pool = DescriptorPool::decode(descriptor_bytes.as_slice())?;
+    // Allow the name to be given with a leading `.`, as it appears within descriptors.
+    let name = name.trim_start_matches('.');
+    pool.get_message_by_name(name)
+        .ok_or_else(|| ProtobufError::UnknownMessage(name.to_string()))
+}
+
+struct ProtobufIter {
+    descriptor: MessageDescriptor,
+    reader: Box<dyn io::BufRead>,
+    buffer: Vec<u8>,
+    /// The number of messages that have been read, which is used to identify messages in errors.
+    message_num: u64,
+}
+
+impl ProtobufIter {
+    fn next_message(&mut self) -> Result<Option<serde_json::Value>, ParseError> {
+        let len = match read_varint(&mut self.reader)? {
+            Some(len) => len,
+            None => return Ok(None),
+        };
+        self.message_num += 1;
+        let message_num = self.message_num;
+        if len > MAX_MESSAGE_LEN {
+            return Err(ParseError::Parse(Box::new(ProtobufError::MessageTooLarge(
+                message_num,
+                len,
+                MAX_MESSAGE_LEN,
+            ))));
+        }
+
+        self.buffer.resize(len as usize, 0);
+        self.reader.read_exact(&mut self.buffer)?;
+
+        let message = DynamicMessage::decode(self.descriptor.clone(), self.buffer.as_slice())
+            .map_err(|err| ParseError::Parse(Box::new(ProtobufError::Decode(message_num, err))))?;
+        // Serialization uses the canonical proto3 JSON mapping.
+        let json = serde_json::to_value(&message)
+            .map_err(|err| ParseError::Parse(Box::new(ProtobufError::Json(message_num, err))))?;
+        Ok(Some(json))
+    }
+}
+
+impl Iterator for ProtobufIter {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
+
+/// Reads a base 128 varint from the `reader`. Returns `Ok(None)` if the reader is already at EOF,
+/// which is the only place where EOF is expected.
+fn read_varint(reader: &mut impl Read) -> io::Result<Option<u64>> {
+    let mut value = 0u64;
+    for i in 0..10 {
+        let mut byte = [0u8];
+        if reader.read(&mut byte)? == 0 {
+            if i == 0 {
+                return Ok(None);
+            }
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        value |= ((byte[0] & 0x7F) as u64) << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(value));
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "varint length prefix is longer than 10 bytes",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use prost::Message;
+    use prost_reflect::Value as ProtoValue;
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+    use serde_json::json;
+
+    fn field(name: &str, number: i32, ty: Type, label: Label) -> FieldDescriptorProto {
+        // protoc always populates json_name, and prost-reflect relies on it when serializing.
+        let mut json_name = String::new();
+        for (i, part) in name.split('_').enumerate() {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(c) if i != 0 => json_name.extend(c.to_uppercase().chain(chars)),
+                Some(c) => json_name.extend(std::iter::once(c).chain(chars)),
+                None => {}
+            }
+        }
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            json_name: Some(json_name),
+            number: Some(number),
+            r#type: Some(ty as i32),
+            label: Some(label as i32),
+            ..Default::default()
+        }
+    }
+
+    fn descriptor_set() -> Vec<u8> {
+        let file = FileDescriptorProto {
+            name: Some("test.proto".to_string()),
+            package: Some("acme".to_string()),
+            syntax: Some("proto3".to_string()),
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Kind".to_string()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("KIND_UNSPECIFIED".to_string()),
+                        number: Some(0),
+                        ..Default::default()
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("KIND_CLICK".to_string()),
+                        number: Some(1),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            message_type: vec![DescriptorProto {
+                name: Some("Event".to_string()),
+                field: vec![
+                    field("event_id", 1, Type::Int64, Label::Optional),
+                    field("user_name", 2, Type::String, Label::Optional),
+                    FieldDescriptorProto {
+                        type_name: Some(".acme.Kind".to_string()),
+                        ..field("kind", 3, Type::Enum, Label::Optional)
+                    },
+                    field("tags", 4, Type::String, Label::Repeated),
+                    field("active", 5, Type::Bool, Label::Optional),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        FileDescriptorSet { file: vec![file] }.encode_to_vec()
+    }
+
+    fn config(descriptors: &[u8]) -> ParseConfig {
+        ParseConfig {
+            protobuf: Some(ProtobufConfig {
+                message: Some("acme.Event".to_string()),
+                file_descriptor_set: Some(base64::encode(descriptors)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn length_delimited_messages_are_converted_to_json() {
+        let descriptors = descriptor_set();
+        let config = config(&descriptors);
+        let pool = DescriptorPool::decode(descriptors.as_slice()).unwrap();
+        let desc = pool.get_message_by_name("acme.Event").unwrap();
+
+        let mut content = Vec::new();
+        let mut first = DynamicMessage::new(desc.clone());
+        first.set_field_by_name("event_id", ProtoValue::I64(9_007_199_254_740_993));
+        first.set_field_by_name("user_name", ProtoValue::String("ann".to_string()));
+        first.set_field_by_name("kind", ProtoValue::EnumNumber(1));
+        first.set_field_by_name(
+            "tags",
+            ProtoValue::List(vec![ProtoValue::String("a".to_string())]),
+        );
+        first.set_field_by_name("active", ProtoValue::Bool(true));
+        content.extend(first.encode_length_delimited_to_vec());
+        // A message with only default values is encoded as zero bytes.
+        content.extend(DynamicMessage::new(desc).encode_length_delimited_to_vec());
+
+        let input = Input::Stream(Box::new(io::Cursor::new(content)));
+        let results = new_parser()
+            .parse(&config, input)
+            .expect("failed to init parser")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse");
+        assert_eq!(
+            vec![
+                json!({
+                    "eventId": "9007199254740993",
+                    "userName": "ann",
+                    "kind": "KIND_CLICK",
+                    "tags": ["a"],
+                    "active": true,
+                }),
+                json!({}),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn truncated_messages_are_errors() {
+        let config = config(&descriptor_set());
+        // The length prefix says 5 bytes, but only 2 follow.
+        let input = Input::Stream(Box::new(io::Cursor::new(vec![5u8, 0x08, 0x01])));
+        let err = new_parser()
+            .parse(&config, input)
+            .unwrap()
+            .next()
+            .unwrap()
+            .expect_err("expected an error");
+        assert!(matches!(err, ParseError::Io(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn unknown_message_types_are_rejected() {
+        let mut config = config(&descriptor_set());
+        config.protobuf.as_mut().unwrap().message = Some("acme.Nope".to_string());
+        let input = Input::Stream(Box::new(io::Cursor::new(Vec::new())));
+        let err = new_parser()
+            .parse(&config, input)
+            .err()
+            .expect("expected an error");
+        assert!(err.to_string().contains("acme.Nope"), "{}", err);
+    }
+
+    #[test]
+    fn varints_are_read() {
+        for (bytes, expected) in &[
+            (&[0x00][..], Some(0)),
+            (&[0x96, 0x01][..], Some(150)),
+            (&[][..], None),
+        ] {
+            let actual = read_varint(&mut io::Cursor::new(*bytes)).unwrap();
+            assert_eq!(*expected, actual);
+        }
+        // Truncated and overlong varints are both errors.
+        assert!(read_varint(&mut io::Cursor::new(&[0x96][..])).is_err());
+        assert!(read_varint(&mut io::Cursor::new(&[0xFF; 11][..])).is_err());
+    }
+}
@@ -0,0 +1,464 @@
+//! XML documents, where each element at a configured path is output as a separate record. The
+//! document is read as a stream of events, so only a single record needs to be held in memory at
+//! any given time.
+use crate::config::ParseConfig;
+use crate::format::error_buffer::ParseErrorBuffer;
+use crate::format::{Output, ParseError, ParseResult, Parser, Rejections};
+use crate::input::Input;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde_json::{Map, Value};
+use std::io::BufRead;
+
+const DEFAULT_ATTRIBUTE_PREFIX: &str = "@";
+const DEFAULT_TEXT_KEY: &str = "#text";
+
+pub struct XmlParser;
+
+/// Returns a type-erased parser trait object for parsing XML documents.
+pub fn new_parser() -> Box<dyn Parser> {
+    Box::new(XmlParser)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum XmlError {
+    #[error("the xml format requires a `recordPath` to be configured, such as '/feed/entry'")]
+    MissingRecordPath,
+
+    #[error("invalid xml document: {0}")]
+    Syntax(quick_xml::Error),
+
+    #[error("the document ended before the end of record {0}")]
+    UnexpectedEof(u64),
+
+    #[error("record {0} is invalid: {1}")]
+    InvalidRecord(u64, quick_xml::Error),
+}
+
+impl Parser for XmlParser {
+    fn parse(&self, config: &ParseConfig, content: Input) -> Result<Output, ParseError> {
+        self.parse_with_rejections(config, content, Rejections::discard())
+    }
+
+    fn parse_with_rejections(
+        &self,
+        config: &ParseConfig,
+        content: Input,
+        rejections: Rejections,
+    ) -> Result<Output, ParseError> {
+        let xml_config = config.xml.clone().unwrap_or_default();
+        let record_path = xml_config
+            .record_path
+            .as_deref()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if record_path.is_empty() {
+            return Err(ParseError::Parse(Box::new(XmlError::MissingRecordPath)));
+        }
+
+        let input = content.transcode_non_utf8(None, 2048)?;
+        let mut reader = Reader::from_reader(input.into_buffered_stream(64 * 1024));
+        // Whitespace between elements is only formatting, and would otherwise be added to the text
+        // of each element.
+        reader.config_mut().trim_text(true);
+
+        let output = XmlIter {
+            reader,
+            buffer: Vec::new(),
+            record_path,
+            attribute_prefix: xml_config
+                .attribute_prefix
+                .unwrap_or_else(|| DEFAULT_ATTRIBUTE_PREFIX.to_string()),
+            text_key: xml_config
+                .text_key
+                .unwrap_or_else(|| DEFAULT_TEXT_KEY.to_string()),
+            path: Vec::new(),
+            record_num: 0,
+            done: false,
+        };
+        let iterator = if let Some(threshold) = xml_config.error_threshold {
+            Box::new(ParseErrorBuffer::new(output, threshold, rejections)) as Output
+        } else {
+            Box::new(output) as Output
+        };
+        Ok(iterator)
+    }
+}
+
+/// An owned representation of the XML events that are relevant to records. Errors in attributes and
+/// text are kept separately from errors in reading the document, since they only invalidate the
+/// record that contains them.
+enum Node {
+    Open {
+        name: String,
+        attributes: Result<Map<String, Value>, quick_xml::Error>,
+        empty: bool,
+    },
+    Text(Result<String, quick_xml::Error>),
+    Close,
+    Eof,
+}
+
+struct XmlIter {
+    reader: Reader<Box<dyn BufRead>>,
+    buffer: Vec<u8>,
+    /// The element names that identify each record.
+    record_path: Vec<String>,
+    attribute_prefix: String,
+    text_key: String,
+    /// The names of the elements that are currently open, outside of any record.
+    path: Vec<String>,
+    /// The number of records that have been read, which is used to identify records in errors.
+    record_num: u64,
+    /// Set once the end of the document is reached, or after an error from which the reader cannot
+    /// recover.
+    done: bool,
+}
+
+impl XmlIter {
+    fn next_node(&mut self) -> Result<Node, quick_xml::Error> {
+        loop {
+            self.buffer.clear();
+            let node = match self.reader.read_event_into(&mut self.buffer)? {
+                Event::Start(start) => Node::Open {
+                    name: element_name(&start),
+                    attributes: attributes(&start, &self.attribute_prefix),
+                    empty: false,
+                },
+                Event::Empty(start) => Node::Open {
+                    name: element_name(&start),
+                    attributes: attributes(&start, &self.attribute_prefix),
+                    empty: true,
+                },
+                Event::Text(text) => Node::Text(text.unescape().map(|t| t.into_owned())),
+                Event::CData(data) => {
+                    Node::Text(Ok(String::from_utf8_lossy(&data.into_inner()).into_owned()))
+                }
+                Event::End(_) => Node::Close,
+                Event::Eof => Node::Eof,
+                // Comments, processing instructions, and declarations aren't part of any record.
+                _ => continue,
+            };
+            return Ok(node);
+        }
+    }
+
+    fn fatal(&mut self, err: XmlError) -> ParseResult {
+        self.done = true;
+        Err(ParseError::Parse(Box::new(err)))
+    }
+
+    /// Reads the remainder of a record, given the attributes of its opening element. The whole
+    /// record is always consumed, even if it's invalid, so that parsing can continue with the next
+    /// one.
+    fn read_record(
+        &mut self,
+        attributes: Result<Map<String, Value>, quick_xml::Error>,
+        empty: bool,
+    ) -> ParseResult {
+        let mut error = None;
+        let mut stack = vec![Element::new(String::new(), attributes, &mut error)];
+
+        // Empty elements are already complete, so there is nothing more to read.
+        if !empty {
+            loop {
+                let node = match self.next_node() {
+                    Ok(node) => node,
+                    Err(err) => return self.fatal(XmlError::Syntax(err)),
+                };
+                match node {
+                    Node::Open {
+                        name,
+                        attributes,
+                        empty,
+                    } => {
+                        let element = Element::new(name, attributes, &mut error);
+                        if empty {
+                            stack.last_mut().unwrap().add_child(element, &self.text_key);
+                        } else {
+                            stack.push(element);
+                        }
+                    }
+                    Node::Text(Ok(text)) => stack.last_mut().unwrap().text.push_str(&text),
+                    Node::Text(Err(err)) => {
+                        error.get_or_insert(err);
+                    }
+                    Node::Close if stack.len() == 1 => break,
+                    Node::Close => {
+                        let element = stack.pop().unwrap();
+                        stack.last_mut().unwrap().add_child(element, &self.text_key);
+                    }
+                    Node::Eof => return self.fatal(XmlError::UnexpectedEof(self.record_num)),
+                }
+            }
+        }
+
+        if let Some(err) = error {
+            return Err(ParseError::Parse(Box::new(XmlError::InvalidRecord(
+                self.record_num,
+                err,
+            ))));
+        }
+        Ok(stack.pop().unwrap().into_record(&self.text_key))
+    }
+}
+
+impl Iterator for XmlIter {
+    type Item = ParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let node = match self.next_node() {
+                Ok(node) => node,
+                Err(err) => return Some(self.fatal(XmlError::Syntax(err))),
+            };
+            match node {
+                Node::Open {
+                    name,
+                    attributes,
+                    empty,
+                } => {
+                    self.path.push(name);
+                    if self.path == self.record_path {
+                        self.record_num += 1;
+                        let result = self.read_record(attributes, empty);
+                        self.path.pop();
+                        return Some(result);
+                    }
+                    if empty {
+                        self.path.pop();
+                    }
+                }
+                Node::Close => {
+                    self.path.pop();
+                }
+                Node::Text(_) => {}
+                Node::Eof => self.done = true,
+            }
+        }
+        None
+    }
+}
+
+fn element_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.name().as_ref()).into_owned()
+}
+
+fn attributes(start: &BytesStart, prefix: &str) -> Result<Map<String, Value>, quick_xml::Error> {
+    let mut map = Map::new();
+    for attr in start.attributes() {
+        let attr = attr?;
+        let key = String::from_utf8_lossy(attr.key.as_ref());
+        // Namespace declarations describe the document rather than the data within it.
+        if key == "xmlns" || key.starts_with("xmlns:") {
+            continue;
+        }
+        let value = attr.unescape_value()?;
+        map.insert(
+            format!("{}{}", prefix, key),
+            Value::String(value.into_owned()),
+        );
+    }
+    Ok(map)
+}
+
+/// An element that's in the process of being converted to JSON.
+struct Element {
+    name: String,
+    object: Map<String, Value>,
+    text: String,
+}
+
+impl Element {
+    fn new(
+        name: String,
+        attributes: Result<Map<String, Value>, quick_xml::Error>,
+        error: &mut Option<quick_xml::Error>,
+    ) -> Element {
+        let object = attributes.unwrap_or_else(|err| {
+            error.get_or_insert(err);
+            Map::new()
+        });
+        Element {
+            name,
+            object,
+            text: String::new(),
+        }
+    }
+
+    /// Adds a child element as a property. Elements that are repeated within the same parent are
+    /// collected into an array.
+    fn add_child(&mut self, mut child: Element, text_key: &str) {
+        let name = std::mem::take(&mut child.name);
+        let value = child.into_value(text_key);
+        match self.object.get_mut(&name) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => {
+                let first = existing.take();
+                *existing = Value::Array(vec![first, value]);
+            }
+            None => {
+                self.object.insert(name, value);
+            }
+        }
+    }
+
+    /// Elements having only text are output as plain strings, and elements having no content at
+    /// all are output as `null`.
+    fn into_value(self, text_key: &str) -> Value {
+        if self.object.is_empty() {
+            if self.text.is_empty() {
+                Value::Null
+            } else {
+                Value::String(self.text)
+            }
+        } else {
+            self.into_record(text_key)
+        }
+    }
+
+    /// Records are always output as objects, even if they only contain text.
+    fn into_record(mut self, text_key: &str) -> Value {
+        if !self.text.is_empty() {
+            self.object
+                .insert(text_key.to_string(), Value::String(self.text));
+        }
+        Value::Object(self.object)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::xml::XmlConfig;
+    use crate::config::ErrorThreshold;
+    use serde_json::json;
+
+    fn config(record_path: &str) -> ParseConfig {
+        ParseConfig {
+            xml: Some(XmlConfig {
+                record_path: Some(record_path.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn parse(config: &ParseConfig, content: &str) -> Vec<Result<Value, ParseError>> {
+        let input = Input::Stream(Box::new(std::io::Cursor::new(content.as_bytes().to_vec())));
+        new_parser()
+            .parse(config, input)
+            .expect("failed to init parser")
+            .collect()
+    }
+
+    #[test]
+    fn elements_at_the_record_path_are_converted_to_json() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+                <title>Not a record</title>
+                <entry id="1">
+                    <title>First &amp; foremost</title>
+                    <link href="https://example.com/1"/>
+                    <category term="a"/>
+                    <category term="b"/>
+                    <summary type="html"><![CDATA[<p>hi</p>]]></summary>
+                    <empty></empty>
+                </entry>
+                <!-- a comment -->
+                <entry>plain</entry>
+                <other><entry>nested at a different path</entry></other>
+                <entry id="3"/>
+            </feed>"#;
+        let results = parse(&config("/feed/entry"), content)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse");
+        assert_eq!(
+            vec![
+                json!({
+                    "@id": "1",
+                    "title": "First & foremost",
+                    "link": {"@href": "https://example.com/1"},
+                    "category": [{"@term": "a"}, {"@term": "b"}],
+                    "summary": {"@type": "html", "#text": "<p>hi</p>"},
+                    "empty": null,
+                }),
+                json!({"#text": "plain"}),
+                json!({"@id": "3"}),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn attribute_prefix_and_text_key_are_configurable() {
+        let config = ParseConfig {
+            xml: Some(XmlConfig {
+                record_path: Some("/rows/row".to_string()),
+                attribute_prefix: Some("_".to_string()),
+                text_key: Some("value".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let results = parse(
+            &config,
+            r#"<rows><row n="1"><cell unit="kg">5</cell></row></rows>"#,
+        )
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("failed to parse");
+        assert_eq!(
+            vec![json!({"_n": "1", "cell": {"_unit": "kg", "value": "5"}})],
+            results
+        );
+    }
+
+    #[test]
+    fn invalid_records_are_skipped_within_the_error_threshold() {
+        let mut buffered = config("/a/b");
+        buffered.xml.as_mut().unwrap().error_threshold = Some(ErrorThreshold::new(50).unwrap());
+        let content = r#"<a><b>1</b><b x="&bogus;">2</b><b>3</b><b>4</b></a>"#;
+        let results = parse(&buffered, content)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to parse");
+        assert_eq!(
+            vec![
+                json!({"#text": "1"}),
+                json!({"#text": "3"}),
+                json!({"#text": "4"})
+            ],
+            results
+        );
+
+        // Without a threshold, the invalid record is an error, but parsing can still continue.
+        let results = parse(&config("/a/b"), content);
+        assert_eq!(4, results.len());
+        let err = results[1].as_ref().expect_err("expected an error");
+        assert!(err.to_string().contains("record 2"), "{}", err);
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn malformed_documents_stop_parsing() {
+        let results = parse(&config("/a/b"), "<a><b>1</b><b>2</c></a>");
+        assert_eq!(2, results.len());
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn record_path_is_required() {
+        let input = Input::Stream(Box::new(std::io::Cursor::new(b"<a/>".to_vec())));
+        let err = new_parser()
+            .parse(&ParseConfig::default(), input)
+            .err()
+            .expect("expected an error");
+        assert!(err.to_string().contains("recordPath"), "{}", err);
+    }
+}
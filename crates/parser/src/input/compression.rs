@@ -1,13 +1,20 @@
 use super::Input;
 use crate::config::Compression;
+use bzip2::read::MultiBzDecoder;
 use flate2::read::GzDecoder;
+use lz4_flex::frame::FrameDecoder as Lz4Decoder;
 use std::boxed::Box;
+use std::cell::UnsafeCell;
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
 use std::pin::Pin;
+use std::rc::Rc;
+use tar::{Archive as TarArchive, Entries as TarEntries, Entry as TarEntry};
+use xz2::read::XzDecoder;
 use zip::read::{ZipArchive, ZipFile};
 use zip::result::ZipError;
+use zstd::stream::read::Decoder as ZstdDecoder;
 
 /// Checks for a "magic number" at the start of the content, and returns a corresponding
 /// compression format if one is detected.
@@ -16,18 +23,67 @@ pub fn detect_compression(prefix: &[u8]) -> Option<Compression> {
         Some(Compression::Gzip)
     } else if prefix.starts_with(&[0x50, 0x4B, 0x03, 0x04]) {
         Some(Compression::ZipArchive)
+    } else if prefix.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]) {
+        Some(Compression::Zstd)
+    } else if prefix.starts_with(b"BZh") {
+        Some(Compression::Bzip2)
+    } else if prefix.starts_with(&[0xFD, b'7', b'z', b'X', b'Z', 0x00]) {
+        Some(Compression::Xz)
+    } else if prefix.starts_with(&[0x04, 0x22, 0x4D, 0x18]) {
+        Some(Compression::Lz4)
+    } else if is_tar_archive(prefix) {
+        Some(Compression::TarArchive)
     } else {
         None
     }
 }
 
+/// Returns true if the content begins with a tar header. Both POSIX (ustar) and GNU tar headers
+/// have a magic string beginning with "ustar" at offset 257 of the first 512 byte header block.
+/// The content of compressed tarballs (e.g. `.tar.gz`) can be checked after it's decompressed.
+pub fn is_tar_archive(prefix: &[u8]) -> bool {
+    prefix.len() >= 262 && &prefix[257..262] == b"ustar"
+}
+
 pub fn decompress_input(
     input: Input,
     compression: Compression,
 ) -> Result<Box<dyn Read>, CompressionError> {
     match compression {
-        Compression::ZipArchive => decompress_zip_archive(input.into_file()?),
         Compression::Gzip => decompress_gzip(input.into_stream()),
+        Compression::Zstd => decompress_zstd(input.into_stream()),
+        // Multi-stream decoders are used for bzip2 and xz so that files produced by parallel
+        // compressors like `pbzip2` and `pixz`, or by simply concatenating compressed files, are
+        // decompressed in their entirety. This matches the behavior of the gzip decoder.
+        Compression::Bzip2 => Ok(Box::new(MultiBzDecoder::new(input.into_stream()))),
+        Compression::Xz => Ok(Box::new(XzDecoder::new_multi_decoder(input.into_stream()))),
+        Compression::Lz4 => Ok(Box::new(Lz4Decoder::new(input.into_stream()))),
+        Compression::ZipArchive | Compression::TarArchive => {
+            Err(CompressionError::RequiresEntries(compression))
+        }
+    }
+}
+
+/// A single file within an archive.
+pub struct ArchiveEntry {
+    /// The path of the file within the archive, which is used for format inference.
+    pub name: String,
+    pub content: Input,
+}
+
+/// Iterator over the files within an archive, in the order they appear.
+pub type ArchiveEntries = Box<dyn Iterator<Item = Result<ArchiveEntry, CompressionError>>>;
+
+/// Returns an iterator over each of the files within the archive, which are returned as separate
+/// inputs so that each one can be parsed individually.
+pub fn archive_entries(
+    input: Input,
+    compression: Compression,
+) -> Result<ArchiveEntries, CompressionError> {
+    match compression {
+        Compression::ZipArchive => Ok(Box::new(ZipEntryIter::new(input.into_file()?)?)),
+        Compression::TarArchive => Ok(Box::new(TarEntryIter::new(input.into_stream())?)),
+        other => Err(CompressionError::NotAnArchive(other)),
     }
 }
 
@@ -39,6 +95,12 @@ pub enum CompressionError {
     ZipArchive(#[from] ZipError),
     #[error("failed to decompress content using compression format: {0}")]
     InvalidCompression(Compression),
+    #[error("{0} archives must be read one entry at a time")]
+    RequiresEntries(Compression),
+    #[error("compression format {0} is not an archive")]
+    NotAnArchive(Compression),
+    #[error("the previous archive entry must be dropped before reading the next one")]
+    EntryInUse,
 }
 
 fn decompress_gzip(stream: Box<dyn Read>) -> Result<Box<dyn Read>, CompressionError> {
@@ -50,117 +112,360 @@ fn decompress_gzip(stream: Box<dyn Read>) -> Result<Box<dyn Read>, CompressionEr
     }
 }
 
-fn decompress_zip_archive(zip_file: File) -> Result<Box<dyn Read>, CompressionError> {
-    let archive = ZipArchive::new(zip_file)?;
-    tracing::debug!(total_entries = archive.len(), "decompressing zip archive");
-    Ok(Box::new(ArchiveReader::new(archive)))
+fn decompress_zstd(stream: Box<dyn Read>) -> Result<Box<dyn Read>, CompressionError> {
+    // The zstd decoder reads frames lazily, so an invalid frame header will be reported by the
+    // first call to `read`, rather than here.
+    let decoder = ZstdDecoder::new(stream)?;
+    Ok(Box::new(decoder))
 }
 
-/// Reads all the file contents of a zip archive, in the order they appear in the zip file.
-struct ArchiveReader {
-    /// We manually coerce these into 'static lifetimes to satisfy the borrow checker. This is safe
-    /// as long as:
-    /// - no references to `current` ever get returned outside of it's functions, and
-    /// - the `ZipFile` is dropped before the `ZipArchive` (rust drops struct fields in the order
-    ///   they were declared).
-    current: Option<ZipFile<'static>>,
-    /// ArchiveReader is a self-referential struct, where each `current` `ZipFile` contains
-    /// references to fields within `archive`. In order for this to be safe, we pin `archive`
-    /// to a single unmovable location within memory, and ensure that it's dropped after `current`.
-    archive: Pin<Box<ZipArchive<File>>>,
+/// Iterates the files of a zip archive, in the order they appear in the zip file. Zip archives
+/// store their directory at the end of the file, so they can only be read from a seekable file.
+struct ZipEntryIter {
+    /// The archive is shared with each of the `ZipEntryReader`s that are returned, so that it's
+    /// guaranteed to outlive them. Each `ZipFile` holds a mutable reference to the archive, so
+    /// only a single entry may be read at a time. This is checked by `next`, which returns an
+    /// error if the previously returned entry has not yet been dropped.
+    archive: Rc<UnsafeCell<ZipArchive<File>>>,
     next_index: usize,
-    file_count: usize,
 }
 
-impl ArchiveReader {
-    fn new(archive: ZipArchive<File>) -> Self {
-        ArchiveReader {
-            archive: Box::pin(archive),
+impl ZipEntryIter {
+    fn new(zip_file: File) -> Result<Self, CompressionError> {
+        let archive = ZipArchive::new(zip_file)?;
+        tracing::debug!(total_entries = archive.len(), "reading zip archive");
+        Ok(ZipEntryIter {
+            archive: Rc::new(UnsafeCell::new(archive)),
             next_index: 0,
-            file_count: 0,
-            current: None,
+        })
+    }
+}
+
+impl Iterator for ZipEntryIter {
+    type Item = Result<ArchiveEntry, CompressionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if Rc::strong_count(&self.archive) > 1 {
+            return Some(Err(CompressionError::EntryInUse));
+        }
+        // This is safe because we've just checked that there are no other references to the
+        // archive, and the reference won't escape this function except as part of a
+        // `ZipEntryReader`, which holds onto the `Rc` so that the count stays accurate.
+        let archive = unsafe { &mut *self.archive.get() };
+
+        while self.next_index < archive.len() {
+            let entry = match archive.by_index(self.next_index) {
+                Ok(e) => e,
+                Err(err) => return Some(Err(err.into())),
+            };
+            self.next_index += 1;
+
+            if !should_include_archive_member(&entry) {
+                tracing::debug!("skipping zip entry: {:?}", entry.name());
+                continue;
+            }
+            let name = entry.name().to_string();
+            tracing::debug!(size = entry.size(), "reading zip entry: {:?}", name);
+
+            // This transmute is safe as long as the archive outlives the entry, which is
+            // guaranteed by the reader holding a reference to the `Rc`.
+            let entry = unsafe { std::mem::transmute::<ZipFile<'_>, ZipFile<'static>>(entry) };
+            let reader = ZipEntryReader {
+                entry,
+                _archive: self.archive.clone(),
+            };
+            return Some(Ok(ArchiveEntry {
+                name,
+                content: Input::Stream(Box::new(reader)),
+            }));
         }
+        None
     }
 }
 
-impl io::Read for ArchiveReader {
+struct ZipEntryReader {
+    /// Declared first so that it's dropped before `_archive`.
+    entry: ZipFile<'static>,
+    _archive: Rc<UnsafeCell<ZipArchive<File>>>,
+}
+
+impl io::Read for ZipEntryReader {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.entry.read(buf)
+    }
+}
+
+/// A tar archive that's pinned in place and shared between its entry readers.
+type SharedTarArchive = Rc<Pin<Box<TarArchive<Box<dyn Read>>>>>;
+
+/// Iterates the files of a tar archive, which is read from a stream. Unlike zip archives, tar
+/// archives don't require random access, so each entry is streamed directly from the underlying
+/// reader without first buffering the content to a file.
+struct TarEntryIter {
+    /// This is a self-referential struct, since `entries` borrows from `archive`. It's declared
+    /// first in order to ensure that it's dropped first.
+    entries: TarEntries<'static, Box<dyn Read>>,
+    /// The archive is shared with each of the `TarEntryReader`s that are returned, so that it's
+    /// guaranteed to outlive them. Entries must be read sequentially, since they all read from
+    /// the same underlying stream, so only a single entry may be read at a time. As with zip
+    /// archives, `next` returns an error if the previously returned entry has not yet been dropped.
+    /// Otherwise, moving on to the next entry will skip over any unread content of the previous one.
+    archive: SharedTarArchive,
+}
+
+impl TarEntryIter {
+    fn new(stream: Box<dyn Read>) -> Result<Self, CompressionError> {
+        let mut archive = Box::pin(TarArchive::new(stream));
+        let entries = archive.entries()?;
+        // This transmute is safe because the archive is heap allocated and pinned, and it's
+        // guaranteed to be dropped after `entries` and all the entries that it returns.
+        let entries = unsafe {
+            std::mem::transmute::<TarEntries<'_, Box<dyn Read>>, TarEntries<'static, Box<dyn Read>>>(
+                entries,
+            )
+        };
+        Ok(TarEntryIter {
+            entries,
+            archive: Rc::new(archive),
+        })
+    }
+}
+
+impl Iterator for TarEntryIter {
+    type Item = Result<ArchiveEntry, CompressionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each entry reads from the archive's stream, and advancing `entries` while the previous
+        // entry is alive would alias it. The count is accurate because each `TarEntryReader` holds
+        // onto the `Rc`.
+        if Rc::strong_count(&self.archive) > 1 {
+            return Some(Err(CompressionError::EntryInUse));
+        }
+
         loop {
-            // Find the next entry in the archive, if we're not in the middle of one.
-            while self.current.is_none() && self.next_index < self.archive.len() {
-                let entry = self
-                    .archive
-                    .by_index(self.next_index)
-                    .map_err(zip_into_io_err)?;
-                if should_include_archive_member(&entry) {
-                    self.file_count += 1;
-                    tracing::trace!(
-                        file_num = self.file_count,
-                        "reading zip file: {:?}",
-                        entry.name()
-                    );
-
-                    // If the zip archive contains multiple files, we concatenate the binary
-                    // content of each. This matches the behavior of `unzip -p archive.zip`.
-                    // There's a pretty significant chance that the parser is going to barf, since
-                    // many formats won't be able to handle this. So this warning will hopefully
-                    // make it easy to find the root cause of those errors.
-                    if self.file_count == 2 {
-                        tracing::warn!("concatenating multiple files from zip archive");
-                    }
-                    // This transmute is safe as long as we don't ever return any references to it,
-                    // since we're guaranteed to drop the entry before we drop the ZipArchive.
-                    self.current =
-                        Some(unsafe { std::mem::transmute::<ZipFile<'_>, ZipFile<'static>>(entry) })
-                }
-                self.next_index += 1;
-            }
+            let entry = match self.entries.next()? {
+                Ok(e) => e,
+                Err(err) => return Some(Err(err.into())),
+            };
+            let name = entry
+                .path()
+                .ok()
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default();
 
-            // At this point, if there's no current entry, then we've finished the archive.
-            if let Some(mut reader) = self.current.take() {
-                match reader.read(buf) {
-                    Ok(0) => { /* Don't put the reader back. Loop around and try again. */ }
-                    other => {
-                        // put the reader back, even if the result is an error. This ensures that
-                        // ErrorKind::Interrupted errors can be retried, though in all honestly
-                        // those are probably already retried by the ZipFile.
-                        self.current = Some(reader);
-                        return other;
-                    }
-                }
-            } else {
-                return Ok(0);
+            if !entry.header().entry_type().is_file() || !is_visible_file(&name) {
+                tracing::debug!(entry_type = ?entry.header().entry_type(), "skipping tar entry: {:?}", name);
+                continue;
             }
+            tracing::debug!(size = ?entry.header().size().ok(), "reading tar entry: {:?}", name);
+
+            let reader = TarEntryReader {
+                entry,
+                _archive: self.archive.clone(),
+            };
+            return Some(Ok(ArchiveEntry {
+                name,
+                content: Input::Stream(Box::new(reader)),
+            }));
         }
     }
 }
 
-fn zip_into_io_err(zip_err: ZipError) -> io::Error {
-    match zip_err {
-        ZipError::Io(ioe) => ioe,
-        other => io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!("invalid zip archive: {}", other),
-        ),
+struct TarEntryReader {
+    /// Declared first so that it's dropped before `_archive`.
+    entry: TarEntry<'static, Box<dyn Read>>,
+    _archive: SharedTarArchive,
+}
+
+impl io::Read for TarEntryReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.entry.read(buf)
     }
 }
 
 fn should_include_archive_member(entry: &ZipFile) -> bool {
+    entry.is_file() && is_visible_file(entry.name())
+}
+
+fn is_visible_file(path: &str) -> bool {
     // OSX users will often end up with extra hidden files in their archives. An example is the
     // `.DS_Store` files that apple puts everywhere, but we've also seen `__MACOSX/.*`. So we
     // filter out any hidden files (those whose name begins with a '.').
-    entry.is_file()
-        && Path::new(entry.name())
-            .file_name()
-            .and_then(|n| n.to_str())
-            .map(|name| !name.starts_with("."))
-            .unwrap_or_else(|| {
-                // If we got here, it's because the zip entry has a path that ends with '..' or
-                // something like that, which seems unusual enough to be worth logging.
-                tracing::warn!(
-                    "skipping zip entry: {:?} since the filename does not appear to be valid",
-                    entry.name()
-                );
-                false
-            })
+    Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(|name| !name.starts_with("."))
+        .unwrap_or_else(|| {
+            // If we got here, it's because the archive entry has a path that ends with '..' or
+            // something like that, which seems unusual enough to be worth logging.
+            tracing::warn!(
+                "skipping archive entry: {:?} since the filename does not appear to be valid",
+                path
+            );
+            false
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    const CONTENT: &[u8] = b"a,b,c\n1,2,3\n4,5,6\n";
+
+    fn compress(compression: Compression, content: &[u8]) -> Vec<u8> {
+        match compression {
+            Compression::Gzip => {
+                let mut w = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+                w.write_all(content).unwrap();
+                w.finish().unwrap()
+            }
+            Compression::Zstd => zstd::encode_all(content, 0).unwrap(),
+            Compression::Bzip2 => {
+                let mut w = bzip2::write::BzEncoder::new(Vec::new(), Default::default());
+                w.write_all(content).unwrap();
+                w.finish().unwrap()
+            }
+            Compression::Xz => {
+                let mut w = xz2::write::XzEncoder::new(Vec::new(), 6);
+                w.write_all(content).unwrap();
+                w.finish().unwrap()
+            }
+            Compression::Lz4 => {
+                let mut w = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                w.write_all(content).unwrap();
+                w.finish().unwrap()
+            }
+            Compression::ZipArchive | Compression::TarArchive => {
+                panic!("archives are not supported by this test")
+            }
+        }
+    }
+
+    fn tarball(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, *content).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn zip_archive_entries_are_read_individually() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, content) in &[
+            ("a.csv", "a,b\n1,2\n"),
+            ("__MACOSX/._a.csv", "nope"),
+            ("b.json", "{\"c\": 3}\n"),
+        ] {
+            writer.start_file(*path, Default::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        let archive = writer.finish().unwrap().into_inner();
+        assert_eq!(Some(Compression::ZipArchive), detect_compression(&archive));
+
+        let input = Input::Stream(Box::new(Cursor::new(archive)));
+        let mut entries = archive_entries(input, Compression::ZipArchive).unwrap();
+
+        let first = entries.next().unwrap().unwrap();
+        assert_eq!("a.csv", first.name);
+        // Attempting to read the next entry while the first is still in use is an error.
+        assert!(matches!(
+            entries.next(),
+            Some(Err(CompressionError::EntryInUse))
+        ));
+        let mut content = String::new();
+        first
+            .content
+            .into_stream()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!("a,b\n1,2\n", content);
+
+        let second = entries.next().unwrap().unwrap();
+        assert_eq!("b.json", second.name);
+        std::mem::drop(second);
+        assert!(entries.next().is_none());
+    }
+
+    #[test]
+    fn tar_archive_entries_are_read_individually() {
+        let archive = tarball(&[
+            ("data/a.csv", b"a,b\n1,2\n"),
+            ("data/.DS_Store", b"nope"),
+            ("data/b.json", b"{\"c\": 3}\n"),
+        ]);
+        assert_eq!(Some(Compression::TarArchive), detect_compression(&archive));
+
+        let input = Input::Stream(Box::new(Cursor::new(archive.clone())));
+        let mut entries = archive_entries(input, Compression::TarArchive).unwrap();
+
+        let first = entries.next().unwrap().unwrap();
+        assert_eq!("data/a.csv", first.name);
+        // Attempting to read the next entry while the first is still in use is an error.
+        assert!(matches!(
+            entries.next(),
+            Some(Err(CompressionError::EntryInUse))
+        ));
+        std::mem::drop(first);
+        assert_eq!("data/b.json", entries.next().unwrap().unwrap().name);
+
+        let input = Input::Stream(Box::new(Cursor::new(archive)));
+        let entries = archive_entries(input, Compression::TarArchive).unwrap();
+
+        let mut actual = Vec::new();
+        for entry in entries {
+            let entry = entry.unwrap();
+            let mut content = String::new();
+            entry
+                .content
+                .into_stream()
+                .read_to_string(&mut content)
+                .unwrap();
+            actual.push((entry.name, content));
+        }
+        assert_eq!(
+            vec![
+                ("data/a.csv".to_string(), "a,b\n1,2\n".to_string()),
+                ("data/b.json".to_string(), "{\"c\": 3}\n".to_string()),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn compressed_streams_are_detected_and_decompressed() {
+        for compression in &[
+            Compression::Gzip,
+            Compression::Zstd,
+            Compression::Bzip2,
+            Compression::Xz,
+            Compression::Lz4,
+        ] {
+            let compressed = compress(*compression, CONTENT);
+            assert_eq!(
+                Some(*compression),
+                detect_compression(&compressed),
+                "failed to detect {}",
+                compression
+            );
+
+            let input = Input::Stream(Box::new(Cursor::new(compressed)));
+            let mut reader = decompress_input(input, *compression).unwrap();
+            let mut actual = Vec::new();
+            reader.read_to_end(&mut actual).unwrap();
+            assert_eq!(CONTENT, actual.as_slice(), "mismatch for {}", compression);
+        }
+    }
+
+    #[test]
+    fn uncompressed_content_is_not_detected() {
+        assert_eq!(None, detect_compression(CONTENT));
+        assert_eq!(None, detect_compression(b"BZ"));
+        assert_eq!(None, detect_compression(b""));
+    }
 }
@@ -1,5 +1,6 @@
 mod compression;
 mod encoding;
+mod recording;
 
 use crate::config::{Compression, EncodingRef};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
@@ -7,8 +8,12 @@ use std::fs::File;
 use std::io::{self, Read, Seek};
 use tempfile::tempfile;
 
-pub use self::compression::{detect_compression, CompressionError};
+pub use self::compression::{
+    archive_entries, detect_compression, is_tar_archive, ArchiveEntries, ArchiveEntry,
+    CompressionError,
+};
 pub use self::encoding::{detect_encoding, TranscodingReader};
+pub use self::recording::{RawBytes, RecordingReader};
 
 /// Type of content input provided to parsers.
 pub enum Input {
@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+/// Discarded bytes are only removed from the front of the buffer once there are at least this
+/// many of them, so that the remaining bytes don't need to be shifted after every record.
+const COMPACT_THRESHOLD: usize = 64 * 1024;
+
+/// A reader that keeps a copy of the bytes that are read through it, so that the raw content of a
+/// record can be recovered after a parser that does its own buffering has failed to parse it.
+/// Callers must periodically call `RawBytes::discard_before` to avoid retaining the whole input.
+pub struct RecordingReader<R> {
+    inner: R,
+    raw: RawBytes,
+}
+
+impl<R: io::Read> RecordingReader<R> {
+    /// Returns the new reader, along with a handle that can be used to access the bytes that have
+    /// been read through it.
+    pub fn new(inner: R) -> (RecordingReader<R>, RawBytes) {
+        let raw = RawBytes::default();
+        let reader = RecordingReader {
+            inner,
+            raw: raw.clone(),
+        };
+        (reader, raw)
+    }
+}
+
+impl<R: io::Read> io::Read for RecordingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.raw.0.borrow_mut().bytes.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A shared handle to the bytes that have been read by a `RecordingReader`, which are addressed by
+/// their offset from the start of the stream.
+#[derive(Clone, Default)]
+pub struct RawBytes(Rc<RefCell<Buffer>>);
+
+#[derive(Default)]
+struct Buffer {
+    /// The offset within the stream of the first byte in `bytes`.
+    start: u64,
+    bytes: Vec<u8>,
+}
+
+impl RawBytes {
+    /// Returns a copy of the bytes from `start` until `end`. Any portion of that range that has
+    /// already been discarded, or that hasn't been read yet, is omitted.
+    pub fn get(&self, start: u64, end: u64) -> Vec<u8> {
+        let buffer = self.0.borrow();
+        let len = buffer.bytes.len();
+        let begin = (start.saturating_sub(buffer.start) as usize).min(len);
+        let end = (end.saturating_sub(buffer.start) as usize).min(len);
+        buffer.bytes[begin..end.max(begin)].to_vec()
+    }
+
+    /// Indicates that bytes before the given `offset` will never be requested again.
+    pub fn discard_before(&self, offset: u64) {
+        let mut buffer = self.0.borrow_mut();
+        let n = (offset.saturating_sub(buffer.start) as usize).min(buffer.bytes.len());
+        if n >= COMPACT_THRESHOLD {
+            buffer.bytes.drain(..n);
+            buffer.start += n as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn raw_bytes_are_addressed_by_stream_offset() {
+        let content = (0..COMPACT_THRESHOLD + 10)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let (mut reader, raw) = RecordingReader::new(io::Cursor::new(content.clone()));
+        let mut sink = Vec::new();
+        reader.read_to_end(&mut sink).unwrap();
+
+        assert_eq!(&content[3..7], raw.get(3, 7).as_slice());
+        raw.discard_before(COMPACT_THRESHOLD as u64 + 2);
+        let offset = COMPACT_THRESHOLD as u64;
+        assert_eq!(
+            &content[offset as usize + 2..],
+            raw.get(offset, offset + 100).as_slice()
+        );
+        assert!(raw.get(0, 5).is_empty());
+    }
+}
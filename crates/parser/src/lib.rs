@@ -3,6 +3,12 @@ mod decorate;
 mod format;
 mod input;
 
-pub use self::config::{csv, Compression, ErrorThreshold, Format, JsonPointer, ParseConfig};
-pub use self::format::{parse, Output, ParseError, Parser};
+pub use self::config::{
+    avro, csv, excel, fixed_width, json, protobuf, xml, Compression, ErrorThreshold, Format,
+    JsonPointer, ParseConfig,
+};
+pub use self::format::{
+    infer_config, parse, parse_with_dead_letters, Output, ParseError, Parser, RecordError,
+    Rejections,
+};
 pub use self::input::Input;
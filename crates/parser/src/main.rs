@@ -1,4 +1,4 @@
-use parser::{parse, Input, ParseConfig};
+use parser::{infer_config, parse_with_dead_letters, Input, ParseConfig};
 use std::fs::File;
 use std::io;
 use std::mem::ManuallyDrop;
@@ -21,6 +21,11 @@ pub struct Args {
 pub enum Command {
     /// Parse the given `--file` (stdin by default) and print the parsed records in jsonl format.
     Parse(ParseArgs),
+    /// Samples the given `--file` (stdin by default) and prints a recommended configuration for
+    /// parsing it, including the format, format-specific options, and a JSON schema for the
+    /// records. Any options in the `--config-file` are retained as-is. For archives, only the
+    /// first file is sampled.
+    Infer(InferArgs),
     /// Prints a JSON schema of the configuration file.
     Spec,
 }
@@ -40,6 +45,26 @@ pub struct ParseArgs {
     /// Note that that's not actually implemented yet, but that's the intent of this option.
     #[structopt(long = "file", default_value = "-")]
     pub file: String,
+
+    /// Path to a file where records that fail to parse will be written in jsonl format, including
+    /// the error and the raw content of each record, where it's known. An already open file
+    /// descriptor can be used by passing a path like `/dev/fd/3`. By default, rejected records are
+    /// only logged.
+    #[structopt(long = "dead-letter-file")]
+    pub dead_letter_file: Option<String>,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct InferArgs {
+    /// Path to a configuration file containing any options that are already known. Inferred
+    /// values will only be used for options that are not specified in this file.
+    #[structopt(long = "config-file", env = "PARSE_CONFIG_FILE")]
+    pub config_file: Option<String>,
+
+    /// Path to a file with the data to sample. Defaults to '-', which represents stdin.
+    /// Passing a value other that '-' will default the filename in the config to the given file.
+    #[structopt(long = "file", default_value = "-")]
+    pub file: String,
 }
 
 fn main() {
@@ -80,31 +105,54 @@ fn main() {
         Command::Parse(parse_args) => {
             do_parse(&parse_args);
         }
+        Command::Infer(infer_args) => do_infer(&infer_args),
         Command::Spec => do_spec(),
     }
 }
 
-#[tracing::instrument]
-fn do_parse(parse_args: &ParseArgs) {
-    let mut config = parse_args
-        .config_file
-        .as_ref()
+/// Loads the config file, if there is one, and opens the input file. The `file` is used as the
+/// filename in the config, unless it specifies one already.
+fn load_input(config_file: Option<&String>, file: &str) -> (ParseConfig, Input) {
+    let mut config = config_file
         .map(|file| ParseConfig::load(file).or_bail("failed to load config file"))
         .unwrap_or_default();
-    let input: Input = if parse_args.file == "-" {
+    let input: Input = if file == "-" {
         Input::Stream(Box::new(io::stdin()))
     } else {
         if config.filename.is_none() {
-            config.filename = Some(parse_args.file.clone());
+            config.filename = Some(file.to_string());
         }
-        Input::File(File::open(parse_args.file.as_str()).or_bail("failed to open file"))
+        Input::File(File::open(file).or_bail("failed to open file"))
     };
+    (config, input)
+}
+
+#[tracing::instrument]
+fn do_parse(parse_args: &ParseArgs) {
+    let (config, input) = load_input(parse_args.config_file.as_ref(), &parse_args.file);
     // Rust's normal Stdout is line buffered and uses a mutex. We don't want any of that, so this
     // creates a plain unbuffered writer from the raw file descriptor, which the internet assures
     // me will always be 1. The ManuallyDrop is critical here, because you *can* close stdout,
     // which would happen automatically when a File is dropped.
     let mut stdout = ManuallyDrop::new(unsafe { File::from_raw_fd(1) });
-    parse(&config, input, stdout.deref_mut()).or_bail("parsing failed");
+    let mut dead_letters = parse_args
+        .dead_letter_file
+        .as_ref()
+        .map(|path| File::create(path).or_bail("failed to open dead letter file"));
+    parse_with_dead_letters(
+        &config,
+        input,
+        stdout.deref_mut(),
+        dead_letters.as_mut().map(|f| f as &mut dyn io::Write),
+    )
+    .or_bail("parsing failed");
+}
+
+#[tracing::instrument]
+fn do_infer(infer_args: &InferArgs) {
+    let (config, input) = load_input(infer_args.config_file.as_ref(), &infer_args.file);
+    let inferred = infer_config(&config, input).or_bail("failed to infer config");
+    serde_json::to_writer_pretty(io::stdout(), &inferred).or_bail("failed to write config");
 }
 
 fn do_spec() {
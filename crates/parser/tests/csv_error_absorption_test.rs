@@ -2,7 +2,8 @@ mod testutil;
 
 use std::io::{Cursor, Seek, Write};
 
-use parser::{csv, ErrorThreshold, Input, ParseConfig};
+use parser::{csv, parse_with_dead_letters, ErrorThreshold, Input, ParseConfig};
+use serde_json::Value;
 use testutil::run_test;
 
 #[test]
@@ -87,6 +88,68 @@ fn occasional_bad_data_test() {
     run_test(&config, csv.as_input()).assert_failure(50);
 }
 
+#[test]
+fn rejected_rows_are_written_to_dead_letters() {
+    let mut csv = EphemeralCsv::default();
+    let config = csv.parse_config(ErrorThreshold::new(10).unwrap().into());
+
+    csv.write_rows(1, &|_i| "n,n_squared".to_owned());
+    csv.write_rows(50, &|i| {
+        if i == 20 {
+            format!("{},{},unexpected-column!", i, i * i)
+        } else {
+            format!("{},{}", i, i * i)
+        }
+    });
+
+    let mut output = Vec::new();
+    let mut dead_letters = Vec::new();
+    parse_with_dead_letters(
+        &config,
+        csv.as_input(),
+        &mut output,
+        Some(&mut dead_letters),
+    )
+    .expect("parsing failed");
+    assert_eq!(
+        49,
+        output
+            .split(|b| *b == b'\n')
+            .filter(|l| !l.is_empty())
+            .count()
+    );
+
+    let letter: Value = serde_json::from_slice(&dead_letters).expect("invalid dead letter");
+    assert_eq!("data.csv", letter["filename"]);
+    assert_eq!(22, letter["line"]);
+    assert_eq!(
+        base64::encode("20,400,unexpected-column!\n"),
+        letter["raw"].as_str().unwrap()
+    );
+    assert!(letter["error"]
+        .as_str()
+        .unwrap()
+        .contains("row 21 has 3 columns"));
+
+    // Exceeding the threshold still fails, but all of the rejected rows are written first.
+    csv.write_rows(10, &|i| format!("{},{},unexpected-column!", i, i * i));
+    let mut dead_letters = Vec::new();
+    let result = parse_with_dead_letters(
+        &config,
+        csv.as_input(),
+        &mut std::io::sink(),
+        Some(&mut dead_letters),
+    );
+    assert!(result.is_err());
+    assert_eq!(
+        11,
+        dead_letters
+            .split(|b| *b == b'\n')
+            .filter(|l| !l.is_empty())
+            .count()
+    );
+}
+
 #[derive(Debug)]
 struct EphemeralCsv {
     data: Cursor<Vec<u8>>,
@@ -32,23 +32,16 @@ fn w3c_extended_log_file_is_parsed() {
 }
 
 /// The file `requires-explicit-quote.csv` has 206 columns. The header row does not use any quote
-/// characters, and so we don't automatically determine the correct quote character because we only
-/// look at the first 2KiB, which in this case is all unquoted headers. So this test asserts that
-/// we fail to parse the file, but that it succeeds when the quote character is provided in the
-/// config.
+/// characters, and so the first 2KiB of the file, which are all unquoted headers, aren't enough to
+/// determine the quote character. The dialect is sniffed from a larger sample, which includes the
+/// quoted data row, so the file can be parsed with or without an explicit quote character.
 #[test]
-fn csv_requires_explicit_quote() {
+fn csv_quote_is_sniffed_beyond_the_header_row() {
     let path = "tests/examples/requires-explicit-quote.csv";
     let no_quote = ParseConfig {
         filename: Some(path.to_string()),
         ..Default::default()
     };
-
-    {
-        let input = input_for_file(path);
-        run_test(&no_quote, input).assert_failure(0);
-    }
-
     let with_quote = ParseConfig {
         filename: Some(path.to_string()),
         csv: Some(parser::csv::CharacterSeparatedConfig {
@@ -57,12 +50,14 @@ fn csv_requires_explicit_quote() {
         }),
         ..Default::default()
     };
-    let same_input = input_for_file(path);
-    let output = run_test(&with_quote, same_input);
-    output.assert_success(1);
-    // Confirm the number of columns as a way of confirming that we're using the correct quote in
-    // the parse configuration.
-    assert_eq!(206, output.parsed[0].as_object().unwrap().len());
+
+    for config in &[no_quote, with_quote] {
+        let output = run_test(config, input_for_file(path));
+        output.assert_success(1);
+        // Confirm the number of columns as a way of confirming that we're using the correct quote
+        // in the parse configuration.
+        assert_eq!(206, output.parsed[0].as_object().unwrap().len());
+    }
 }
 
 fn assert_file_is_parsed(file: PathBuf) {
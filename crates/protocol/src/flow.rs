@@ -672,6 +672,13 @@ pub mod combine_api {
         /// returned documents. If empty, no placeholder is inserted.
         #[prost(string, tag="5")]
         pub uuid_placeholder_ptr: ::prost::alloc::string::String,
+        /// Should documents be validated against the formats of the schema
+        /// (for example, "date-time" or "email"), rather than only annotated
+        /// with them? This applies only to CombineAPI combiners, as used by
+        /// captures and materializations. Derivations and their registers
+        /// don't assert formats.
+        #[prost(bool, tag="6")]
+        pub assert_formats: bool,
     }
     /// Stats holds statistics relating to one or more combiner transactions.
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -708,6 +715,9 @@ pub mod combine_api {
         DrainedFields = 8,
         /// Drain stats, sent after all documents have been drained. (Rust -> Go)
         Stats = 9,
+        /// Next drained document is a fully reduced deletion tombstone (Rust -> Go).
+        /// Its key should be deleted, rather than updated, wherever it's stored.
+        DrainedDeletedDocument = 10,
     }
 }
 /// DeriveAPI is a meta-message which name spaces messages of the Derive API
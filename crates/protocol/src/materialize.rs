@@ -257,6 +257,11 @@ pub mod transaction_request {
         /// Exists is true if this document as previously been loaded or stored.
         #[prost(bool, repeated, tag="6")]
         pub exists: ::prost::alloc::vec::Vec<bool>,
+        /// Delete is true if this document is a deletion tombstone of its key,
+        /// which should be removed from the store rather than updated.
+        /// If empty, no documents are deletions.
+        #[prost(bool, repeated, tag="7")]
+        pub delete: ::prost::alloc::vec::Vec<bool>,
     }
     /// Mark the end of the Store phase, and if the remote store is authoritative,
     /// instruct it to commit its transaction.
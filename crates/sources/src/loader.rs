@@ -313,7 +313,7 @@ impl<F: Fetcher> Loader<F> {
                 Keyword::Application(app, child) => {
                     // Does |app| map to an external URL that's not contained by this CompiledSchema?
                     let uri = match app {
-                        Application::Ref(uri) => {
+                        Application::Ref(uri) | Application::DynamicRef(uri) => {
                             // $ref applications often use #fragment suffixes which indicate
                             // a sub-schema of the base schema document to use.
                             let mut uri = uri.clone();
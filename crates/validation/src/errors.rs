@@ -157,9 +157,9 @@ pub enum Error {
         rhs_version: String,
         rhs_scope: Url,
     },
-    #[error("derivation's initial register is invalid against its schema: {}", serde_json::to_string_pretty(.0).unwrap())]
+    #[error("derivation's initial register is invalid against its schema: {0}")]
     RegisterInitialInvalid(doc::FailedValidation),
-    #[error("test ingest document is invalid against the collection schema: {}", serde_json::to_string_pretty(.0).unwrap())]
+    #[error("test ingest document is invalid against the collection schema: {0}")]
     IngestDocInvalid(doc::FailedValidation),
     #[error("{entity} {name} bindings duplicate the endpoint resource {resource} at {rhs_scope}")]
     BindingDuplicatesResource {
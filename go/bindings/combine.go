@@ -19,6 +19,8 @@ type CombineCallback = func(
 	// Is this document fully reduced (it included a ReduceLeft operation),
 	// or only partially reduced (from CombineRight operations only)?
 	full bool,
+	// Is this fully reduced document a deletion tombstone of its key?
+	deleted bool,
 	// Encoded JSON document, with a UUID placeholder if that was requested.
 	doc json.RawMessage,
 	// Packed tuple.Tuple of the document key.
@@ -62,6 +64,7 @@ func (c *Combine) Configure(
 	uuidPtr string,
 	keyPtrs []string,
 	fieldPtrs []string,
+	assertFormats bool,
 ) error {
 	combineConfigureCounter.WithLabelValues(fqn, collection.String()).Inc()
 	c.metrics = newCombineMetrics(fqn, collection)
@@ -75,6 +78,7 @@ func (c *Combine) Configure(
 			KeyPtr:             keyPtrs,
 			FieldPtrs:          fieldPtrs,
 			UuidPlaceholderPtr: uuidPtr,
+			AssertFormats:      assertFormats,
 		})
 
 	return pollExpectNoOutput(c.svc)
@@ -161,8 +165,11 @@ func drainCombineToCallback(
 
 	for len(*out) >= 3 {
 		var doc = svc.arenaSlice((*out)[0])
+		var code = pf.CombineAPI_Code((*out)[0].code)
+
 		if err = cb(
-			pf.CombineAPI_Code((*out)[0].code) == pf.CombineAPI_DRAINED_REDUCED_DOCUMENT,
+			code == pf.CombineAPI_DRAINED_REDUCED_DOCUMENT || code == pf.CombineAPI_DRAINED_DELETED_DOCUMENT,
+			code == pf.CombineAPI_DRAINED_DELETED_DOCUMENT,
 			doc,                       // Doc.
 			svc.arenaSlice((*out)[1]), // Packed key.
 			svc.arenaSlice((*out)[2]), // Packed fields.
@@ -59,12 +59,13 @@ func TestValidationFailuresAreLogged(t *testing.T) {
 		collection.UuidPtr,
 		collection.KeyPtrs,
 		nil,
+		false,
 	)
 	require.NoError(t, err)
 
 	require.NoError(t, combiner.CombineRight(json.RawMessage(`{"i": "not an int"}`)))
 
-	_, err = combiner.Drain(func(_ bool, raw json.RawMessage, packedKey, packedFields []byte) error {
+	_, err = combiner.Drain(func(_, _ bool, raw json.RawMessage, packedKey, packedFields []byte) error {
 		require.Fail(t, "expected combine callback not to be called")
 		return fmt.Errorf("not a real error")
 	})
@@ -74,24 +75,11 @@ func TestValidationFailuresAreLogged(t *testing.T) {
 	logPublisher.RequireEventsMatching(t, []testutil.TestLogEvent{
 		{
 			Level: log.ErrorLevel,
-			Message: `document is invalid: {
-  "basic_output": {
-    "errors": [
-      {
-        "absoluteKeywordLocation": "file:///int-strings.flow.yaml?ptr=/collections/int-strings/schema#/properties/i",
-        "error": "Invalid(Type(\"integer\"))",
-        "instanceLocation": "/i",
-        "keywordLocation": "#/properties/i"
-      }
-    ],
-    "valid": false
-  },
-  "document": {
-    "i": "not an int"
-  }
-}`,
+			Message: `document is invalid: 1 validation error(s):
+  /i: must be of type "integer", but found "not an int"
+    schema: #/properties/i/type (file:///int-strings.flow.yaml?ptr=/collections/int-strings/schema#/properties/i)`,
 			Fields: map[string]interface{}{
-				"error":     `{"CombineError":{"PreReduceValidation":{"document":{"i":"not an int"},"basic_output":{"errors":[{"absoluteKeywordLocation":"file:///int-strings.flow.yaml?ptr=/collections/int-strings/schema#/properties/i","error":"Invalid(Type(\"integer\"))","instanceLocation":"/i","keywordLocation":"#/properties/i"}],"valid":false}}}}`,
+				"error":     `{"CombineError":{"PreReduceValidation":{"document":{"i":"not an int"},"errors":[{"instanceLocation":"/i","keywordLocation":"#/properties/i/type","absoluteKeywordLocation":"file:///int-strings.flow.yaml?ptr=/collections/int-strings/schema#/properties/i","expected":"must be of type \"integer\"","actual":"\"not an int\""}]}}}`,
 				"logSource": "combine",
 			},
 		},
@@ -140,6 +128,7 @@ func TestCombineBindings(t *testing.T) {
 				collection.UuidPtr,
 				collection.KeyPtrs,
 				[]string{"/s/1", "/i"},
+				false,
 			)
 			require.NoError(t, err)
 		}
@@ -168,7 +157,7 @@ func expectCombineCallback(t *testing.T) CombineCallback {
 		{42, []string{"two", "three"}},
 	}
 
-	return func(_ bool, raw json.RawMessage, packedKey, packedFields []byte) error {
+	return func(_, _ bool, raw json.RawMessage, packedKey, packedFields []byte) error {
 		t.Log("doc", string(raw))
 
 		var doc struct {
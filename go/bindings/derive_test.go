@@ -289,7 +289,7 @@ func TestDeriveWithIncResetPublish(t *testing.T) {
 	}
 
 	var drainError = func(t *testing.T, d *Derive) string {
-		var stats, err = d.Drain(func(_ bool, _ json.RawMessage, _, _ []byte) error {
+		var stats, err = d.Drain(func(_, _ bool, _ json.RawMessage, _, _ []byte) error {
 			t.Error("not called")
 			return nil
 		})
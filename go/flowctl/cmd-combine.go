@@ -21,13 +21,14 @@ import (
 )
 
 type cmdCombine struct {
-	Directory   string                `long:"directory" default:"." description:"Build directory"`
-	Network     string                `long:"network" default:"host" description:"The Docker network that connector containers are given access to."`
-	Source      string                `long:"source" required:"true" description:"Catalog source file or URL to build"`
-	Collection  string                `long:"collection" required:"true" description:"The name of the collection from which to take the schema and key"`
-	MaxDocs     uint64                `long:"max-docs" default:"0" description:"Maximum number of documents to add to the combiner before draining it. If 0, then there is no maximum"`
-	Log         mbp.LogConfig         `group:"Logging" namespace:"log" env-namespace:"LOG"`
-	Diagnostics mbp.DiagnosticsConfig `group:"Debug" namespace:"debug" env-namespace:"DEBUG"`
+	Directory     string                `long:"directory" default:"." description:"Build directory"`
+	Network       string                `long:"network" default:"host" description:"The Docker network that connector containers are given access to."`
+	Source        string                `long:"source" required:"true" description:"Catalog source file or URL to build"`
+	Collection    string                `long:"collection" required:"true" description:"The name of the collection from which to take the schema and key"`
+	MaxDocs       uint64                `long:"max-docs" default:"0" description:"Maximum number of documents to add to the combiner before draining it. If 0, then there is no maximum"`
+	AssertFormats bool                  `long:"assert-formats" description:"Validate, rather than only annotate, known JSON schema formats of combined documents"`
+	Log           mbp.LogConfig         `group:"Logging" namespace:"log" env-namespace:"LOG"`
+	Diagnostics   mbp.DiagnosticsConfig `group:"Debug" namespace:"debug" env-namespace:"DEBUG"`
 }
 
 func (cmd cmdCombine) Execute(_ []string) error {
@@ -99,6 +100,7 @@ func (cmd cmdCombine) Execute(_ []string) error {
 		"",
 		collection.KeyPtrs,
 		nil,
+		cmd.AssertFormats,
 	)
 
 	type FlowDoc struct {
@@ -166,7 +168,7 @@ func (cmd cmdCombine) Execute(_ []string) error {
 }
 
 func drainToStdout(combiner *bindings.Combine) (*pf.CombineAPI_Stats, error) {
-	return combiner.Drain(func(full bool, doc json.RawMessage, packedKey []byte, packedFields []byte) error {
+	return combiner.Drain(func(_, _ bool, doc json.RawMessage, packedKey []byte, packedFields []byte) error {
 		fmt.Println(string(doc))
 		return nil
 	})
@@ -282,6 +282,7 @@ type StoreRequest struct {
 	Values   tuple.Tuple     `json:"values"`
 	Document json.RawMessage `json:"document"`
 	Exists   bool            `json:"exists"`
+	Delete   bool            `json:"delete,omitempty"`
 }
 
 type TxnRequest struct {
@@ -346,6 +347,7 @@ func jsonWriteLoop(
 					Values:   storeIt.Values,
 					Document: storeIt.RawJSON,
 					Exists:   storeIt.Exists,
+					Delete:   storeIt.Delete,
 				},
 			}); err != nil {
 				return fmt.Errorf("encoding Load: %w", err)
@@ -15,9 +15,10 @@ type Combiner interface {
 	CombineRight(json.RawMessage) error
 	// Drain the Combiner of its documents.
 	// |full| is true if this document is a full reduction (ReduceLeft was called).
+	// |deleted| is true if this fully reduced document is a deletion tombstone.
 	// |packedKey| is the FoundationDB tuple encoding of the document key.
 	// |packedValues| are materialized fields of the materialization.
-	Drain(func(full bool, doc json.RawMessage, packedKey, packedValues []byte) error) (*CombineAPI_Stats, error)
+	Drain(func(full, deleted bool, doc json.RawMessage, packedKey, packedValues []byte) error) (*CombineAPI_Stats, error)
 	// Destroy the Combiner.
 	Destroy()
 }
@@ -30,10 +31,11 @@ type MockCombiner struct {
 	Combined  []json.RawMessage
 	Destroyed bool
 
-	drainFull   []bool
-	drainKeys   [][]byte
-	drainValues [][]byte
-	drainDocs   []json.RawMessage
+	drainFull    []bool
+	drainDeleted []bool
+	drainKeys    [][]byte
+	drainValues  [][]byte
+	drainDocs    []json.RawMessage
 }
 
 // ReduceLeft appends the document into Reduced.
@@ -51,21 +53,32 @@ func (c *MockCombiner) CombineRight(doc json.RawMessage) error {
 // AddDrainFixture arranges for the document to be returned on a future call to Drain.
 // The |doc| must encode to JSON without error, or AddDrainFixture panics.
 func (c *MockCombiner) AddDrainFixture(full bool, doc interface{}, key, values tuple.Tuple) {
+	c.addDrainFixture(full, false, doc, key, values)
+}
+
+// AddDrainDeletionFixture arranges for the fully reduced deletion tombstone
+// to be returned on a future call to Drain.
+func (c *MockCombiner) AddDrainDeletionFixture(doc interface{}, key, values tuple.Tuple) {
+	c.addDrainFixture(true, true, doc, key, values)
+}
+
+func (c *MockCombiner) addDrainFixture(full, deleted bool, doc interface{}, key, values tuple.Tuple) {
 	var raw, err = json.Marshal(doc)
 	if err != nil {
 		panic(err)
 	}
 
 	c.drainFull = append(c.drainFull, full)
+	c.drainDeleted = append(c.drainDeleted, deleted)
 	c.drainKeys = append(c.drainKeys, key.Pack())
 	c.drainValues = append(c.drainValues, values.Pack())
 	c.drainDocs = append(c.drainDocs, raw)
 }
 
 // Drain invokes the callback with pre-arranged fixtures.
-func (c *MockCombiner) Drain(fn func(full bool, doc json.RawMessage, packedKey, packedValues []byte) error) (*CombineAPI_Stats, error) {
+func (c *MockCombiner) Drain(fn func(full, deleted bool, doc json.RawMessage, packedKey, packedValues []byte) error) (*CombineAPI_Stats, error) {
 	for i := range c.drainFull {
-		if err := fn(c.drainFull[i], c.drainDocs[i], c.drainKeys[i], c.drainValues[i]); err != nil {
+		if err := fn(c.drainFull[i], c.drainDeleted[i], c.drainDocs[i], c.drainKeys[i], c.drainValues[i]); err != nil {
 			return nil, err
 		}
 	}
@@ -87,6 +100,7 @@ func (c *MockCombiner) Drain(fn func(full bool, doc json.RawMessage, packedKey,
 	stats.Out = dAndB(c.drainDocs)
 
 	c.drainFull = nil
+	c.drainDeleted = nil
 	c.drainKeys = nil
 	c.drainValues = nil
 	c.drainDocs = nil
@@ -267,19 +267,23 @@ const (
 	CombineAPI_DRAINED_FIELDS CombineAPI_Code = 8
 	// Drain stats, sent after all documents have been drained. (Rust -> Go)
 	CombineAPI_STATS CombineAPI_Code = 9
+	// Next drained document is a fully reduced deletion tombstone (Rust -> Go).
+	// Its key should be deleted, rather than updated, wherever it's stored.
+	CombineAPI_DRAINED_DELETED_DOCUMENT CombineAPI_Code = 10
 )
 
 var CombineAPI_Code_name = map[int32]string{
-	0: "INVALID",
-	1: "CONFIGURE",
-	2: "REDUCE_LEFT",
-	3: "COMBINE_RIGHT",
-	4: "DRAIN",
-	5: "DRAINED_COMBINED_DOCUMENT",
-	6: "DRAINED_REDUCED_DOCUMENT",
-	7: "DRAINED_KEY",
-	8: "DRAINED_FIELDS",
-	9: "STATS",
+	0:  "INVALID",
+	1:  "CONFIGURE",
+	2:  "REDUCE_LEFT",
+	3:  "COMBINE_RIGHT",
+	4:  "DRAIN",
+	5:  "DRAINED_COMBINED_DOCUMENT",
+	6:  "DRAINED_REDUCED_DOCUMENT",
+	7:  "DRAINED_KEY",
+	8:  "DRAINED_FIELDS",
+	9:  "STATS",
+	10: "DRAINED_DELETED_DOCUMENT",
 }
 
 var CombineAPI_Code_value = map[string]int32{
@@ -293,6 +297,7 @@ var CombineAPI_Code_value = map[string]int32{
 	"DRAINED_KEY":               7,
 	"DRAINED_FIELDS":            8,
 	"STATS":                     9,
+	"DRAINED_DELETED_DOCUMENT":  10,
 }
 
 func (x CombineAPI_Code) String() string {
@@ -1932,7 +1937,13 @@ type CombineAPI_Config struct {
 	FieldPtrs []string `protobuf:"bytes,4,rep,name=field_ptrs,json=fieldPtrs,proto3" json:"field_ptrs,omitempty"`
 	// JSON-Pointer at which a placeholder UUID should be inserted into
 	// returned documents. If empty, no placeholder is inserted.
-	UuidPlaceholderPtr   string   `protobuf:"bytes,5,opt,name=uuid_placeholder_ptr,json=uuidPlaceholderPtr,proto3" json:"uuid_placeholder_ptr,omitempty"`
+	UuidPlaceholderPtr string `protobuf:"bytes,5,opt,name=uuid_placeholder_ptr,json=uuidPlaceholderPtr,proto3" json:"uuid_placeholder_ptr,omitempty"`
+	// Should documents be validated against the formats of the schema
+	// (for example, "date-time" or "email"), rather than only annotated
+	// with them? This applies only to CombineAPI combiners, as used by
+	// captures and materializations. Derivations and their registers
+	// don't assert formats.
+	AssertFormats        bool     `protobuf:"varint,6,opt,name=assert_formats,json=assertFormats,proto3" json:"assert_formats,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -2976,267 +2987,266 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/flow/flow.proto", fileDescriptor_d0677502142fec31) }
 
 var fileDescriptor_d0677502142fec31 = []byte{
-	// 4149 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd4, 0x5a, 0xcb, 0x6f, 0x23, 0xd9,
-	0x5a, 0xef, 0x8a, 0xdf, 0x9f, 0x63, 0xa7, 0x72, 0x92, 0x9e, 0x71, 0x7b, 0xfa, 0x76, 0xba, 0xdd,
-	0xf3, 0xe8, 0x07, 0xe3, 0x0c, 0x3d, 0x3d, 0xaf, 0x1e, 0x9a, 0x2b, 0x3f, 0x2a, 0x9d, 0xea, 0x76,
-	0x6c, 0x4f, 0xd9, 0x99, 0x99, 0xbe, 0x12, 0x14, 0x95, 0xaa, 0x13, 0xa7, 0x26, 0xe5, 0x2a, 0x73,
-	0xaa, 0x9c, 0xe9, 0x5c, 0x16, 0x88, 0xbb, 0x42, 0x17, 0x90, 0xe0, 0x6e, 0x98, 0xe5, 0x70, 0x17,
-	0x2c, 0x58, 0x20, 0x71, 0x97, 0x08, 0x81, 0x10, 0x9b, 0xb9, 0x42, 0x48, 0x23, 0x5d, 0xd8, 0x66,
-	0xc4, 0x85, 0x05, 0x88, 0xff, 0x20, 0xb0, 0x40, 0xe7, 0x51, 0x0f, 0x3b, 0xee, 0x4e, 0xf7, 0x20,
-	0x01, 0x77, 0x93, 0xd4, 0xf9, 0x5e, 0x75, 0x1e, 0xbf, 0xef, 0x71, 0xbe, 0x32, 0x5c, 0x1e, 0x79,
-	0x9b, 0x13, 0xe2, 0x05, 0x9e, 0xe9, 0x39, 0xfe, 0xe6, 0xbe, 0xe3, 0x7d, 0xce, 0xfe, 0xd4, 0x19,
-	0x0d, 0xa5, 0xe9, 0x73, 0xf5, 0xca, 0x1e, 0xf1, 0x0e, 0x31, 0x89, 0xe4, 0xa2, 0x07, 0x2e, 0x55,
-	0xbd, 0x6a, 0x7a, 0xae, 0x3f, 0x1d, 0x3f, 0x43, 0x62, 0x7d, 0xe4, 0x8d, 0x3c, 0xf6, 0xb8, 0x49,
-	0x9f, 0x38, 0xb5, 0x76, 0x1f, 0x32, 0x03, 0xc7, 0x36, 0x31, 0x5a, 0x87, 0xcc, 0x1e, 0x1e, 0xd9,
-	0x6e, 0x45, 0xba, 0x2a, 0xdd, 0x28, 0x69, 0x7c, 0x80, 0x64, 0x48, 0x61, 0xd7, 0xaa, 0x2c, 0x31,
-	0x1a, 0x7d, 0xbc, 0xb7, 0xfc, 0xf5, 0x9f, 0x6c, 0x5c, 0xf8, 0xc3, 0x1f, 0x6f, 0x5c, 0xf8, 0xe2,
-	0xc7, 0x1b, 0x17, 0x6a, 0xbf, 0x2b, 0x41, 0x61, 0x77, 0x57, 0x6d, 0xf7, 0x0d, 0x12, 0xf8, 0xe8,
-	0x97, 0x00, 0x4d, 0x88, 0x67, 0x4d, 0x4d, 0x4c, 0x74, 0xc3, 0xb5, 0xf4, 0x7d, 0xc7, 0x18, 0xf9,
-	0xcc, 0x60, 0x56, 0x93, 0x43, 0x4e, 0xc3, 0xb5, 0xb6, 0x28, 0x1d, 0x7d, 0x08, 0x19, 0xd3, 0xf1,
-	0xcc, 0x43, 0x66, 0x3d, 0xdb, 0x7c, 0xed, 0xf4, 0x64, 0xe3, 0xda, 0xc8, 0xab, 0x8f, 0x8c, 0xef,
-	0xe3, 0x20, 0xc0, 0x75, 0x0b, 0x1f, 0x6d, 0x9a, 0x1e, 0xc1, 0x9b, 0x63, 0xec, 0xfb, 0xc6, 0x08,
-	0xd7, 0x5b, 0x54, 0x58, 0xe3, 0x3a, 0xf7, 0xe4, 0x7f, 0xfb, 0x72, 0x43, 0x9a, 0x99, 0xca, 0x43,
-	0x80, 0x8e, 0x31, 0xde, 0xb3, 0x8c, 0xc1, 0x04, 0x9b, 0xe8, 0x0a, 0x40, 0x70, 0x3c, 0xc1, 0xbe,
-	0x49, 0xec, 0x49, 0xc0, 0xa6, 0x50, 0xd0, 0x12, 0x14, 0xf4, 0x12, 0x64, 0x09, 0x1e, 0x7b, 0x01,
-	0x66, 0x6f, 0x2f, 0x68, 0x62, 0x74, 0x2f, 0x4d, 0xed, 0xd6, 0xfe, 0x23, 0x0d, 0xb9, 0xc1, 0xc1,
-	0x74, 0x7f, 0xdf, 0xc1, 0xe8, 0x3b, 0x00, 0x23, 0xe2, 0x4d, 0x27, 0xba, 0x6b, 0x8c, 0xb1, 0xb0,
-	0x54, 0x60, 0x94, 0xae, 0x31, 0xc6, 0xe8, 0x43, 0x58, 0xf5, 0xbd, 0x29, 0x31, 0xb1, 0x6e, 0x7a,
-	0x8e, 0x83, 0xcd, 0xc0, 0xf6, 0x5c, 0x6e, 0xb3, 0x59, 0x3e, 0x3d, 0xd9, 0x80, 0x56, 0x44, 0xd5,
-	0x64, 0x2e, 0x18, 0x53, 0xd0, 0xc3, 0x48, 0x79, 0x62, 0x90, 0xc0, 0xa6, 0x34, 0xbf, 0x92, 0xba,
-	0x2a, 0xdd, 0x28, 0xde, 0x79, 0xb9, 0x1e, 0x9d, 0x5f, 0xc7, 0xd8, 0xc3, 0xce, 0x00, 0x53, 0x25,
-	0x8f, 0x34, 0xd3, 0x5f, 0x9d, 0x6c, 0x5c, 0x08, 0x6d, 0xf5, 0x23, 0x35, 0xf4, 0x3a, 0xac, 0x08,
-	0x5b, 0xd3, 0xa9, 0x6d, 0xe9, 0x93, 0x80, 0x54, 0xd2, 0x6c, 0xb2, 0x25, 0x4e, 0xde, 0x9d, 0xda,
-	0x56, 0x3f, 0x20, 0x4c, 0x8e, 0x2f, 0x4d, 0x3f, 0xc4, 0xc7, 0x4c, 0x2e, 0x73, 0x35, 0xc5, 0xe4,
-	0x38, 0xf9, 0x11, 0x3e, 0x16, 0x72, 0x53, 0x1f, 0xfb, 0xba, 0x30, 0x7a, 0x88, 0x8f, 0x2b, 0xd9,
-	0xab, 0xd2, 0x8d, 0xbc, 0x56, 0xa2, 0xe4, 0x01, 0xa3, 0x3e, 0xc2, 0xc7, 0xe8, 0x3d, 0x28, 0x87,
-	0xf6, 0x1c, 0xb6, 0xff, 0x95, 0x1c, 0x5b, 0x80, 0x5c, 0x67, 0x20, 0x8e, 0xcf, 0x24, 0x7a, 0x01,
-	0x27, 0xa1, 0x5b, 0xd1, 0xe2, 0x7d, 0xf3, 0x00, 0x8f, 0x0d, 0x7d, 0x4a, 0xec, 0x4a, 0x9e, 0x4d,
-	0x59, 0xac, 0x64, 0xc0, 0xe8, 0xbb, 0xc4, 0xa6, 0xc8, 0x4a, 0x4e, 0x86, 0x2b, 0x54, 0x0a, 0x6c,
-	0x3e, 0x72, 0x3c, 0x1f, 0xae, 0x80, 0xde, 0x81, 0x97, 0x8f, 0x0c, 0xc7, 0xb6, 0x8c, 0x20, 0xb2,
-	0x6d, 0x04, 0x3a, 0xc1, 0x86, 0x55, 0x01, 0xa6, 0xb2, 0x1e, 0xb2, 0xb9, 0x42, 0x23, 0xd0, 0xb0,
-	0x61, 0xd1, 0x15, 0xef, 0xdb, 0x4e, 0x80, 0x89, 0x4e, 0x74, 0x86, 0x32, 0xbf, 0x52, 0xe4, 0x2b,
-	0xe6, 0x64, 0x8d, 0x21, 0x90, 0xc1, 0x9c, 0xda, 0xd2, 0x2d, 0xec, 0x18, 0xc7, 0xba, 0x8f, 0x4d,
-	0xcf, 0xb5, 0xfc, 0xca, 0x32, 0xf3, 0x11, 0x99, 0x72, 0xda, 0x94, 0x31, 0xe0, 0x74, 0x54, 0x85,
-	0xfc, 0x84, 0xd8, 0x1e, 0xb1, 0x83, 0xe3, 0x4a, 0x89, 0xc9, 0x44, 0x63, 0x81, 0xb6, 0x2f, 0x96,
-	0xa0, 0xfc, 0xd0, 0x9b, 0x12, 0xd7, 0x70, 0x42, 0xd0, 0xa9, 0x90, 0xfb, 0x8c, 0x53, 0x38, 0xe2,
-	0x9a, 0x9b, 0xa7, 0x27, 0x1b, 0xb7, 0x17, 0x79, 0xc7, 0x5c, 0x5c, 0xa8, 0x0b, 0x43, 0x5a, 0xa8,
-	0x8f, 0x06, 0x50, 0x34, 0x3d, 0x8f, 0x58, 0xb6, 0x6b, 0x04, 0x1e, 0x11, 0xd0, 0xfc, 0xe5, 0xd3,
-	0x93, 0x8d, 0x37, 0x17, 0x99, 0x3b, 0x13, 0x46, 0xea, 0x83, 0x03, 0x83, 0x58, 0x6a, 0x5b, 0x4b,
-	0x5a, 0x41, 0x6f, 0x42, 0x4e, 0x1c, 0xa6, 0x80, 0x6b, 0x89, 0x9f, 0xb6, 0x98, 0x7f, 0x33, 0xfd,
-	0xf5, 0xc9, 0x86, 0xa4, 0x85, 0x32, 0xdc, 0xdb, 0x26, 0x8e, 0x71, 0xcc, 0x20, 0x99, 0xd7, 0xc4,
-	0x08, 0x5d, 0x82, 0xfc, 0xde, 0xd4, 0x76, 0x2c, 0xdd, 0xb6, 0x2a, 0x19, 0x76, 0xf2, 0x39, 0x36,
-	0x56, 0x2d, 0xb1, 0x35, 0x27, 0x12, 0x40, 0x9f, 0x78, 0x9f, 0x09, 0x7f, 0x91, 0x21, 0x45, 0xf1,
-	0xca, 0x9d, 0x90, 0x3e, 0xd2, 0xb0, 0xb5, 0x6f, 0x63, 0xc7, 0x12, 0x6e, 0xcc, 0x07, 0xe8, 0x3a,
-	0x50, 0x90, 0x12, 0x7d, 0x42, 0xbc, 0x23, 0xdb, 0xc2, 0x16, 0x9b, 0x64, 0x5e, 0x5b, 0xa6, 0xc4,
-	0xbe, 0xa0, 0xa1, 0x1b, 0x20, 0xdb, 0x7e, 0xec, 0x78, 0x0c, 0xe1, 0x7c, 0x7a, 0x65, 0xdb, 0x8f,
-	0x1c, 0x8b, 0x42, 0xfc, 0x55, 0x28, 0x53, 0x49, 0x62, 0x8f, 0x0d, 0x72, 0xcc, 0xe4, 0x32, 0xdc,
-	0x9e, 0xed, 0xf7, 0x39, 0x91, 0x4a, 0xbd, 0x0d, 0x05, 0xdb, 0xdd, 0xc7, 0x04, 0xbb, 0x26, 0x66,
-	0xae, 0x52, 0xbc, 0xb3, 0xc2, 0x77, 0x45, 0x0d, 0xc9, 0xc2, 0x79, 0x63, 0xb9, 0xda, 0x9f, 0xa7,
-	0xa0, 0x10, 0xb1, 0xe9, 0x6a, 0x58, 0x8c, 0xaa, 0x48, 0xcc, 0x23, 0xf9, 0x80, 0x46, 0xa0, 0xf1,
-	0xd4, 0x0f, 0x74, 0xfc, 0xc4, 0xf6, 0x03, 0xb6, 0xd0, 0xbc, 0x56, 0xa0, 0x14, 0x85, 0x12, 0x50,
-	0x1d, 0xb2, 0x7e, 0x40, 0x6c, 0x77, 0x24, 0x8e, 0xe2, 0xa5, 0xb9, 0x97, 0xd6, 0x07, 0x8c, 0xab,
-	0x09, 0x29, 0xf6, 0x12, 0x3b, 0x70, 0xb0, 0x08, 0x0f, 0x7c, 0x80, 0xae, 0x42, 0xd1, 0x12, 0xc1,
-	0x91, 0x46, 0x30, 0x7e, 0x1a, 0x49, 0x12, 0xfa, 0x2e, 0x2c, 0x5b, 0x78, 0xdf, 0x98, 0x3a, 0x81,
-	0xfe, 0x99, 0xef, 0xb9, 0x6c, 0x89, 0x85, 0xe6, 0xe5, 0xd3, 0x93, 0x8d, 0x0a, 0x76, 0x4d, 0xcf,
-	0xb2, 0xdd, 0xd1, 0x26, 0x65, 0xd4, 0x35, 0xe3, 0xf3, 0x1d, 0x1e, 0xb3, 0xa9, 0x01, 0xa6, 0xf1,
-	0xd0, 0xf7, 0x5c, 0x8a, 0x02, 0x1f, 0x9b, 0x04, 0x07, 0x2c, 0x42, 0xe4, 0x35, 0x31, 0xaa, 0xfe,
-	0x99, 0x04, 0x59, 0x3e, 0x47, 0x74, 0x0d, 0x96, 0x4d, 0xcf, 0x0d, 0xb0, 0x1b, 0xe8, 0x74, 0xed,
-	0x6c, 0x45, 0x05, 0x0a, 0x3d, 0x46, 0x1b, 0x1e, 0x4f, 0x18, 0x96, 0xf6, 0x3d, 0x32, 0x36, 0x02,
-	0x31, 0x7f, 0x31, 0x42, 0x37, 0x41, 0x0e, 0x55, 0xc3, 0xe9, 0xb0, 0xf7, 0x14, 0xb4, 0x15, 0x41,
-	0x57, 0x04, 0x19, 0xbd, 0x02, 0x05, 0xdb, 0xd7, 0xf7, 0x0c, 0x1f, 0xbf, 0x7b, 0x57, 0x1c, 0x65,
-	0xde, 0xf6, 0x9b, 0x6c, 0xcc, 0x76, 0xdb, 0x78, 0xa2, 0x3b, 0xd8, 0x1d, 0x05, 0x07, 0x6c, 0x91,
-	0x25, 0xad, 0x30, 0x36, 0x9e, 0x74, 0x18, 0xa1, 0xf6, 0xd3, 0x14, 0x94, 0xe3, 0x08, 0xce, 0x72,
-	0x4d, 0x1d, 0x20, 0x11, 0xfb, 0xa5, 0x85, 0xb1, 0x3f, 0x21, 0x41, 0xdf, 0x90, 0x88, 0x78, 0x1c,
-	0xb8, 0x05, 0x3f, 0x8a, 0x75, 0xf7, 0xa1, 0x28, 0xd8, 0x6c, 0x9b, 0xf3, 0xcf, 0xb1, 0xcd, 0xc2,
-	0x1e, 0xdb, 0xe5, 0x4b, 0x90, 0x17, 0x71, 0x9d, 0xa6, 0x12, 0x0a, 0xa3, 0xdc, 0x21, 0x8b, 0xe8,
-	0x3e, 0x65, 0xcd, 0xe5, 0x86, 0xdc, 0x54, 0x64, 0x85, 0x9b, 0x20, 0xc7, 0x9e, 0xc0, 0x9c, 0xc8,
-	0x17, 0x69, 0x61, 0x25, 0xa2, 0x6f, 0x31, 0x32, 0x7a, 0x1f, 0x8a, 0x93, 0xc8, 0x25, 0xfd, 0x4a,
-	0xf6, 0x6a, 0x2a, 0x8e, 0xf6, 0xb1, 0xaf, 0x0a, 0xa8, 0x27, 0x45, 0xd1, 0x36, 0xac, 0x1a, 0xe6,
-	0x21, 0x5b, 0x96, 0x1e, 0xe0, 0xf1, 0xc4, 0x31, 0x02, 0xcc, 0xcf, 0xe8, 0x9c, 0xf5, 0xad, 0x18,
-	0xe6, 0x21, 0x5d, 0xdc, 0x50, 0x28, 0xa1, 0x36, 0xa0, 0x78, 0xba, 0x91, 0xa9, 0x02, 0xc3, 0xff,
-	0xc5, 0xfa, 0x7c, 0x30, 0x64, 0xd9, 0x67, 0x35, 0x52, 0x08, 0xad, 0xd4, 0x7e, 0x6f, 0x09, 0x4a,
-	0x43, 0x62, 0xb8, 0x3e, 0x85, 0x50, 0x78, 0x94, 0x16, 0x26, 0xf6, 0x91, 0xf1, 0xac, 0xa3, 0x8c,
-	0x25, 0xd0, 0x6d, 0x28, 0x04, 0xa1, 0x01, 0x11, 0x5a, 0x4b, 0xa7, 0x27, 0x1b, 0x85, 0xc8, 0xaa,
-	0x16, 0xf3, 0xcf, 0x0f, 0x9a, 0x6c, 0xc7, 0xa2, 0xa0, 0xf9, 0x0e, 0x94, 0xa6, 0x13, 0x96, 0xc3,
-	0x44, 0x5e, 0x4d, 0x3f, 0x25, 0xaf, 0x2e, 0x73, 0x31, 0x91, 0x56, 0xdf, 0x83, 0xf2, 0x64, 0xba,
-	0xe7, 0xd8, 0xfe, 0x41, 0xa8, 0x97, 0x79, 0x5a, 0x3e, 0x16, 0x72, 0x9c, 0x54, 0xfb, 0xfd, 0x14,
-	0x94, 0xdb, 0xd1, 0xd2, 0xd8, 0x76, 0xdc, 0x3b, 0x83, 0xec, 0xe2, 0x9d, 0x75, 0x6e, 0x67, 0xd6,
-	0x07, 0xc4, 0xdc, 0x93, 0x28, 0xaf, 0xc3, 0x1a, 0xc1, 0x23, 0xdb, 0xa7, 0xf9, 0xf4, 0x0c, 0xdc,
-	0x57, 0x43, 0x56, 0x9c, 0xe2, 0x3b, 0x70, 0x31, 0x92, 0xb7, 0x5d, 0x3b, 0xb0, 0x0d, 0x87, 0x3b,
-	0x40, 0xea, 0x79, 0x00, 0x12, 0xaa, 0xaa, 0x5c, 0x13, 0x7d, 0x00, 0x10, 0x6d, 0xbc, 0x5f, 0x49,
-	0x33, 0x8c, 0xae, 0xf1, 0x99, 0xcf, 0x9c, 0x78, 0x38, 0xf1, 0x58, 0x18, 0xdd, 0xa3, 0x05, 0x8d,
-	0x41, 0xac, 0x18, 0x57, 0x7c, 0x03, 0xd7, 0xea, 0x61, 0x72, 0xe4, 0x39, 0x31, 0xac, 0x69, 0x0c,
-	0x62, 0x45, 0xb8, 0x54, 0xe9, 0x22, 0x4c, 0xef, 0x08, 0x93, 0x63, 0xdd, 0xf1, 0x46, 0xb1, 0x89,
-	0xec, 0xb3, 0xa0, 0xb9, 0x16, 0xea, 0x74, 0xbc, 0x51, 0x04, 0xce, 0x3f, 0x58, 0x82, 0x32, 0xf3,
-	0x38, 0x5e, 0xf9, 0xd1, 0x2d, 0x45, 0x90, 0x3e, 0xc4, 0xc7, 0x61, 0x76, 0x60, 0xcf, 0x34, 0x1c,
-	0x1e, 0x19, 0xce, 0x14, 0xfb, 0x95, 0x25, 0x46, 0x15, 0x23, 0x5a, 0x76, 0x58, 0x9e, 0x39, 0x1d,
-	0x63, 0x37, 0x10, 0x51, 0x34, 0x1a, 0x23, 0x0f, 0x56, 0x99, 0x8b, 0xeb, 0xa6, 0xe7, 0xee, 0xdb,
-	0x23, 0xbe, 0xcd, 0x7c, 0x8f, 0x6e, 0xf2, 0x3d, 0x9a, 0x7d, 0x31, 0x1f, 0xb6, 0x98, 0x30, 0x75,
-	0x43, 0xc5, 0x0d, 0xc8, 0x71, 0xf3, 0xf2, 0x0f, 0xbf, 0x79, 0x56, 0xe4, 0xdf, 0x8f, 0x75, 0xaa,
-	0x4d, 0x58, 0x5f, 0x64, 0x82, 0xe6, 0x73, 0x9a, 0x4d, 0x45, 0x3e, 0x3f, 0xc4, 0xc7, 0x34, 0x39,
-	0xb1, 0x05, 0x84, 0xf9, 0x9c, 0x0d, 0xee, 0x2d, 0xbd, 0x2f, 0xd5, 0x7e, 0x96, 0x86, 0x62, 0xcb,
-	0x98, 0x04, 0x53, 0x82, 0x19, 0x36, 0x5f, 0x83, 0x9c, 0xc9, 0x87, 0xc2, 0x4f, 0x8b, 0xa7, 0x27,
-	0x1b, 0x39, 0x21, 0xa1, 0x85, 0x3c, 0xf4, 0x1e, 0x94, 0xb0, 0x6b, 0x4d, 0x3c, 0x3b, 0x4c, 0x29,
-	0xd4, 0x70, 0xf9, 0x0e, 0xe2, 0xeb, 0x54, 0x04, 0x8b, 0x66, 0x16, 0x6d, 0x19, 0x27, 0x46, 0x68,
-	0x0b, 0x50, 0xa4, 0xe8, 0x4f, 0xb0, 0xf9, 0xfc, 0x60, 0x8c, 0xec, 0xb0, 0x79, 0xbe, 0x03, 0xf9,
-	0x3d, 0xdb, 0xa5, 0x82, 0x21, 0x0e, 0x2f, 0x09, 0x0f, 0x8a, 0x17, 0x53, 0x6f, 0x72, 0x09, 0x2d,
-	0x12, 0xa5, 0x01, 0xd9, 0x76, 0x03, 0x4c, 0x8e, 0x0c, 0x27, 0x2a, 0x31, 0x33, 0x2c, 0x19, 0xad,
-	0x84, 0xf4, 0xb0, 0xc2, 0x3c, 0x0b, 0xd8, 0xec, 0xff, 0x1c, 0xb0, 0xb9, 0x17, 0x05, 0x6c, 0xf5,
-	0x27, 0x12, 0xe4, 0xc4, 0x3a, 0xe8, 0xe6, 0x11, 0x1c, 0x16, 0xeb, 0xd1, 0xe6, 0x49, 0xcf, 0xb3,
-	0x79, 0xa1, 0x1e, 0xdb, 0xbc, 0xeb, 0x50, 0x8a, 0xec, 0x4c, 0x8c, 0xe0, 0x40, 0x80, 0x3c, 0x12,
-	0xea, 0x1b, 0xc1, 0xc1, 0x5c, 0x94, 0x4a, 0xbd, 0x48, 0x94, 0xaa, 0xfd, 0x7b, 0x06, 0xd6, 0x76,
-	0x8c, 0x00, 0x13, 0xdb, 0x70, 0xec, 0xef, 0xc7, 0x91, 0xef, 0x3e, 0xac, 0x8c, 0x67, 0xc9, 0x62,
-	0xf6, 0x6b, 0xa7, 0x27, 0x1b, 0x2b, 0x73, 0x1a, 0xda, 0xbc, 0xec, 0xff, 0x3d, 0xea, 0xee, 0x9f,
-	0x41, 0xdd, 0x35, 0xfe, 0xee, 0x05, 0x8b, 0x5d, 0x80, 0xbe, 0xff, 0x1f, 0x31, 0xb0, 0xfa, 0xf7,
-	0x4b, 0xbf, 0x78, 0x90, 0x42, 0x2d, 0x7a, 0x8d, 0xa4, 0xd1, 0xd5, 0x0f, 0xe3, 0xa7, 0xc8, 0xdc,
-	0xeb, 0x8b, 0x62, 0xab, 0x30, 0x50, 0xde, 0x9f, 0x0d, 0xf5, 0xd7, 0xa1, 0x64, 0x61, 0x27, 0x30,
-	0x74, 0x9e, 0xdb, 0xfd, 0xf0, 0xc6, 0xc1, 0x88, 0xbb, 0x9c, 0x96, 0x2c, 0x28, 0xb2, 0xe7, 0x17,
-	0x14, 0xb5, 0x3f, 0x4d, 0x41, 0x7e, 0x88, 0x7d, 0x0e, 0x10, 0x04, 0xe9, 0x00, 0xfb, 0x61, 0x6b,
-	0x84, 0x3d, 0xa3, 0x4d, 0xc8, 0xf8, 0x01, 0x9e, 0xf0, 0x54, 0x12, 0xe7, 0x4b, 0xa1, 0x52, 0x1f,
-	0x04, 0x78, 0x22, 0x6c, 0x72, 0xb9, 0xea, 0x3f, 0x2e, 0x41, 0x9a, 0x52, 0xd1, 0x3b, 0x50, 0xa0,
-	0x14, 0x8e, 0x75, 0x89, 0x61, 0xbd, 0xb2, 0x40, 0xbb, 0xce, 0x10, 0x9f, 0xa7, 0xa2, 0x0c, 0xed,
-	0xb4, 0x12, 0xa6, 0x6a, 0xb6, 0x6b, 0xe1, 0x27, 0xa2, 0xcb, 0xc4, 0x0c, 0xa9, 0x94, 0x30, 0x7f,
-	0x27, 0x49, 0x9d, 0xbd, 0x93, 0x84, 0x06, 0x7c, 0xd3, 0x9b, 0x84, 0x17, 0x1a, 0x66, 0x60, 0x40,
-	0x09, 0x73, 0x95, 0x79, 0xe6, 0xdc, 0xca, 0xfc, 0x75, 0x58, 0xb1, 0x3c, 0xd3, 0xe7, 0x15, 0xaa,
-	0x63, 0xbb, 0xd8, 0xe7, 0xb7, 0x1c, 0xad, 0x44, 0xc9, 0x34, 0x6f, 0x75, 0x28, 0x11, 0xdd, 0x07,
-	0x48, 0x34, 0x6c, 0x72, 0xcf, 0xd3, 0xb0, 0x49, 0x28, 0xd4, 0xae, 0x40, 0x9a, 0x2d, 0x1f, 0x20,
-	0xab, 0x76, 0x1f, 0x28, 0x83, 0xa1, 0x7c, 0x81, 0x3e, 0x7f, 0xac, 0x68, 0xea, 0xd6, 0x63, 0x59,
-	0xaa, 0xfd, 0xb1, 0x04, 0x05, 0xcd, 0x70, 0x47, 0x1c, 0xb0, 0xaf, 0x40, 0x81, 0x16, 0xf4, 0xbc,
-	0x3b, 0x47, 0xf7, 0x28, 0xa7, 0xd1, 0x0a, 0xbf, 0xc9, 0x1a, 0x74, 0x2f, 0x03, 0xad, 0xee, 0x75,
-	0xec, 0xf2, 0x3b, 0x6e, 0x4e, 0xcb, 0x1e, 0xe2, 0x63, 0xc5, 0xb5, 0x50, 0x0d, 0x4a, 0xa2, 0x8b,
-	0x21, 0x34, 0xd3, 0x8c, 0x5d, 0x24, 0xac, 0x89, 0xc1, 0x95, 0xaf, 0x40, 0x31, 0x94, 0xa1, 0x06,
-	0x32, 0x4c, 0xa2, 0xc0, 0x25, 0x14, 0xd7, 0xba, 0x27, 0x7f, 0xf1, 0xe5, 0xc6, 0x85, 0x99, 0x26,
-	0xdb, 0xef, 0x48, 0xb0, 0xcc, 0x4b, 0xb6, 0xe6, 0xd4, 0xb5, 0x1c, 0x8c, 0xde, 0x85, 0xec, 0x1e,
-	0x7b, 0x62, 0x45, 0x49, 0xf1, 0xce, 0x15, 0x81, 0xc0, 0x84, 0x4c, 0x9d, 0xff, 0x63, 0x19, 0x5f,
-	0x13, 0xd2, 0xd5, 0x0f, 0xa0, 0x98, 0x20, 0xbf, 0x50, 0x21, 0xf0, 0x57, 0x4b, 0x50, 0x16, 0x08,
-	0xd7, 0xf0, 0x6f, 0x4e, 0x29, 0x70, 0xef, 0xc6, 0x8e, 0x30, 0x53, 0xa4, 0xce, 0x76, 0x55, 0xe6,
-	0x0b, 0xec, 0xb7, 0x00, 0xa8, 0xd3, 0x3b, 0xd3, 0xa8, 0x67, 0x47, 0xab, 0xe4, 0xe8, 0x14, 0xb7,
-	0xb1, 0x61, 0x61, 0xa2, 0x25, 0x64, 0xd0, 0x6d, 0xc8, 0x10, 0x7a, 0x2e, 0x22, 0x22, 0x88, 0xeb,
-	0x7d, 0x74, 0x54, 0xa1, 0x73, 0x30, 0x19, 0xb4, 0x05, 0x59, 0x6f, 0x7f, 0xdf, 0xc7, 0xfc, 0xa2,
-	0x9a, 0x6a, 0xd6, 0x4f, 0x4f, 0x36, 0x6e, 0x3d, 0x4f, 0x0b, 0xa7, 0xc7, 0xb4, 0x34, 0xa1, 0x8d,
-	0x76, 0x00, 0xb0, 0x6b, 0xe9, 0xc2, 0x56, 0xe6, 0x5b, 0xd9, 0x2a, 0x60, 0xd7, 0xe2, 0x8f, 0xb5,
-	0xbf, 0x48, 0xc3, 0x4a, 0xb4, 0x7d, 0xfe, 0xc4, 0x73, 0x7d, 0x8c, 0x6e, 0x40, 0xd6, 0x0f, 0x8c,
-	0x60, 0xea, 0x0b, 0xdf, 0x95, 0x13, 0x61, 0x9e, 0xd1, 0x35, 0xc1, 0xa7, 0x92, 0x07, 0x6c, 0x5f,
-	0x9e, 0xba, 0x5f, 0x82, 0x8f, 0x5e, 0x83, 0x72, 0x80, 0xc9, 0xd8, 0x76, 0x0d, 0x47, 0xc7, 0x84,
-	0x78, 0x44, 0xf8, 0x6f, 0x29, 0xa4, 0x2a, 0x94, 0x88, 0x3e, 0x82, 0x65, 0xd6, 0x4c, 0x0b, 0x0e,
-	0x88, 0x37, 0x1d, 0x1d, 0x7c, 0xcb, 0xbd, 0x2a, 0x52, 0x1b, 0x43, 0x6e, 0x82, 0x6e, 0xd8, 0xe7,
-	0xc4, 0x0e, 0xb0, 0x4e, 0x67, 0xf2, 0x6d, 0x37, 0x8c, 0x59, 0xa0, 0x4b, 0x42, 0x1b, 0x90, 0x31,
-	0x08, 0x76, 0x0d, 0x16, 0x0a, 0x96, 0x9b, 0x85, 0xd3, 0x93, 0x8d, 0x4c, 0x83, 0x12, 0x34, 0x4e,
-	0x47, 0x75, 0x28, 0x44, 0x51, 0xa3, 0x92, 0x63, 0x6e, 0x50, 0x14, 0x6e, 0xe0, 0xd8, 0x51, 0xd3,
-	0x27, 0x1f, 0x86, 0x10, 0xb4, 0x0d, 0x39, 0x7e, 0x98, 0x7e, 0x25, 0x7f, 0x35, 0xf5, 0x2d, 0x26,
-	0x17, 0xaa, 0xa3, 0xbb, 0x00, 0xfc, 0x42, 0x6f, 0x90, 0xc0, 0xaf, 0x14, 0xd8, 0xab, 0x05, 0x28,
-	0xa3, 0xae, 0x7c, 0xd8, 0x73, 0x62, 0x37, 0x7d, 0xd6, 0xa6, 0x7f, 0x8b, 0x46, 0x2f, 0xf3, 0x10,
-	0x5b, 0xac, 0x95, 0x05, 0x4f, 0x9b, 0x70, 0x81, 0x0b, 0x3d, 0xc2, 0xc7, 0xb5, 0x1f, 0x49, 0x20,
-	0xb7, 0x89, 0x7d, 0x84, 0x49, 0xeb, 0x00, 0x9b, 0x87, 0xac, 0xcc, 0x40, 0x5d, 0x78, 0xc9, 0x62,
-	0x34, 0xdd, 0x8c, 0x88, 0x71, 0x52, 0x5e, 0x3e, 0x27, 0x29, 0xcb, 0xd6, 0xbc, 0x3d, 0x7a, 0x61,
-	0xdc, 0x37, 0xdf, 0x7b, 0xfb, 0x83, 0x77, 0xf5, 0x31, 0x26, 0x23, 0x96, 0x9d, 0xcd, 0x03, 0xd1,
-	0xef, 0x5a, 0x15, 0xac, 0x1d, 0xca, 0xe9, 0x53, 0x46, 0xcd, 0x83, 0x82, 0x68, 0xdf, 0xf6, 0xd5,
-	0xea, 0xaf, 0x00, 0x34, 0xa7, 0xb6, 0x13, 0xf0, 0xc4, 0x51, 0x87, 0x35, 0x71, 0xe5, 0x64, 0x99,
-	0x45, 0x1f, 0xe3, 0x71, 0xd8, 0x37, 0xcc, 0x6a, 0xab, 0x9c, 0xc5, 0x24, 0x77, 0x18, 0xa3, 0xf6,
-	0x2a, 0xa4, 0x5b, 0x9e, 0x85, 0x51, 0x11, 0x72, 0x6a, 0xf7, 0xe3, 0x46, 0x47, 0x6d, 0xcb, 0x17,
-	0xd0, 0x0a, 0x14, 0x9b, 0xbb, 0x6a, 0xa7, 0xad, 0xab, 0xdd, 0xb6, 0xf2, 0xa9, 0x2c, 0xd5, 0xfe,
-	0x53, 0x02, 0x50, 0x9e, 0x04, 0xc4, 0x30, 0x03, 0xfa, 0xca, 0x3f, 0x92, 0x20, 0xcb, 0x2f, 0x34,
-	0x33, 0x8d, 0x15, 0x69, 0xb6, 0xb1, 0x72, 0x4e, 0xb3, 0xe7, 0x29, 0x33, 0x4d, 0x3d, 0x65, 0xa6,
-	0xd4, 0x1c, 0x2f, 0x2e, 0x58, 0x7f, 0x27, 0xcd, 0x4a, 0x97, 0x02, 0xa3, 0xf4, 0x03, 0xe2, 0xd7,
-	0x1e, 0x2f, 0x5a, 0x48, 0x09, 0x0a, 0xad, 0x5e, 0x77, 0x4b, 0x7d, 0xb0, 0xab, 0x29, 0xb2, 0x44,
-	0x79, 0xca, 0xa7, 0x43, 0xad, 0xd1, 0x1a, 0xca, 0x4b, 0x08, 0x41, 0x59, 0x0c, 0x94, 0xb6, 0x4e,
-	0x31, 0x23, 0xa7, 0xd0, 0x3a, 0xc8, 0x31, 0x6d, 0x4b, 0x55, 0x3a, 0xed, 0x81, 0x9c, 0xae, 0xfd,
-	0x28, 0x0d, 0xd0, 0xf2, 0xc6, 0x7b, 0xb6, 0x8b, 0xe9, 0xea, 0xff, 0x36, 0x5e, 0xfd, 0x0b, 0xee,
-	0xf6, 0x79, 0x5b, 0x22, 0x52, 0x1a, 0xdf, 0x06, 0x76, 0xa5, 0xe5, 0xfd, 0xab, 0x73, 0xd6, 0x8e,
-	0xde, 0x82, 0x75, 0x7e, 0x08, 0x8e, 0x61, 0xe2, 0x03, 0xcf, 0xb1, 0x30, 0x11, 0x5f, 0x37, 0xe8,
-	0x0b, 0x10, 0x3b, 0x90, 0x98, 0xd5, 0x0f, 0x48, 0xf5, 0xb7, 0x21, 0x43, 0xc3, 0x9b, 0x8f, 0x5e,
-	0x87, 0xb4, 0x83, 0xf7, 0x03, 0x91, 0x3c, 0x44, 0x95, 0xde, 0xf6, 0x4c, 0xbf, 0xe1, 0x5a, 0xcd,
-	0xe3, 0x00, 0xfb, 0x1a, 0xe3, 0xa3, 0x1b, 0x90, 0x21, 0xf6, 0xe8, 0x20, 0x10, 0xc1, 0x6f, 0x91,
-	0x20, 0x17, 0x40, 0xaf, 0x42, 0xca, 0x9b, 0x06, 0x22, 0x4f, 0x2c, 0x92, 0xa3, 0xec, 0xda, 0x5f,
-	0x4b, 0xcf, 0x71, 0x5e, 0x2b, 0x50, 0xd4, 0x94, 0xf6, 0x6e, 0x4b, 0xd1, 0x3b, 0xca, 0x16, 0x3d,
-	0xb3, 0x55, 0x28, 0xb5, 0x7a, 0x3b, 0x4d, 0xb5, 0xab, 0xe8, 0x9a, 0xfa, 0x60, 0x7b, 0x28, 0xa7,
-	0x50, 0x01, 0x32, 0x6d, 0xad, 0xa1, 0x76, 0xe5, 0x34, 0xfa, 0x0e, 0x5c, 0x62, 0x8f, 0x4a, 0x5b,
-	0x17, 0x52, 0x6d, 0xbd, 0xdd, 0x6b, 0xed, 0xee, 0x28, 0xdd, 0xa1, 0x9c, 0x41, 0x97, 0xa1, 0x12,
-	0xb2, 0xb9, 0xd5, 0x04, 0x37, 0x4b, 0xdf, 0x15, 0x72, 0x1f, 0x29, 0x8f, 0xe5, 0x1c, 0xc5, 0x47,
-	0x48, 0x10, 0x48, 0xc8, 0xd3, 0x97, 0x0d, 0x86, 0x8d, 0xe1, 0x40, 0x2e, 0xd4, 0xfe, 0xa1, 0x08,
-	0x05, 0xd6, 0x33, 0x62, 0x98, 0xf8, 0x08, 0xd2, 0xbd, 0x09, 0x76, 0xd9, 0x07, 0x12, 0xcf, 0x3c,
-	0xf4, 0xad, 0x3d, 0x1d, 0xbb, 0x47, 0xb3, 0x78, 0x90, 0x05, 0x47, 0x71, 0x8f, 0x04, 0x1c, 0x5e,
-	0x81, 0x82, 0xe3, 0x99, 0x86, 0xa3, 0x5b, 0xb6, 0xf8, 0x3c, 0xa1, 0xe5, 0x19, 0xa1, 0x6d, 0x93,
-	0xaa, 0x1b, 0xa1, 0xec, 0xee, 0x99, 0xd6, 0x5c, 0x94, 0xe6, 0x67, 0xbb, 0x56, 0x33, 0x0d, 0xba,
-	0xa7, 0x60, 0x73, 0xe9, 0x29, 0xd8, 0xac, 0x3e, 0x81, 0x42, 0xdb, 0x33, 0x79, 0x2a, 0x43, 0xd7,
-	0x21, 0x4d, 0x51, 0x23, 0x5e, 0x36, 0x1f, 0x58, 0x35, 0xc6, 0xa4, 0xa8, 0x4c, 0x44, 0x53, 0x6a,
-	0x78, 0x39, 0x11, 0x3a, 0xd1, 0x1b, 0xb0, 0x12, 0xf5, 0x96, 0x44, 0x9d, 0x9b, 0x62, 0x75, 0x6e,
-	0x39, 0x22, 0xb3, 0xf7, 0x57, 0xff, 0x49, 0x82, 0xac, 0xea, 0x1e, 0x79, 0x87, 0x78, 0x91, 0x8e,
-	0xb4, 0x48, 0x87, 0xe6, 0x58, 0x7e, 0x69, 0xf1, 0x67, 0x17, 0x26, 0x3e, 0xf9, 0xf9, 0x62, 0x87,
-	0x13, 0x62, 0xa2, 0xad, 0x4d, 0xa7, 0x90, 0x8e, 0xc4, 0x78, 0x6b, 0x1b, 0xdd, 0x04, 0x39, 0x6c,
-	0xa3, 0x45, 0xf6, 0xd2, 0xcc, 0x5e, 0xd4, 0x5e, 0x0b, 0x2d, 0xce, 0x88, 0x0a, 0x9b, 0x19, 0x66,
-	0x33, 0x16, 0xe5, 0x56, 0xab, 0x0a, 0xe4, 0xfa, 0x04, 0x4f, 0x0c, 0x82, 0xd9, 0xad, 0x2a, 0x8a,
-	0xf7, 0xd1, 0x11, 0x46, 0xa5, 0x46, 0x9c, 0x0b, 0xa2, 0x5b, 0x55, 0x44, 0xa9, 0xfe, 0x57, 0x2a,
-	0x74, 0xd6, 0xf6, 0x4c, 0x6b, 0x8f, 0x97, 0x9d, 0xaf, 0x26, 0x80, 0x40, 0xa1, 0xc8, 0xca, 0x16,
-	0x3f, 0xd1, 0xea, 0xa3, 0xc3, 0x99, 0x2e, 0x5f, 0x03, 0x0a, 0xd1, 0x4c, 0x85, 0x3b, 0x5f, 0x5f,
-	0x6c, 0x44, 0x0b, 0x5b, 0x95, 0xcc, 0x46, 0xac, 0x85, 0x6e, 0x41, 0xd6, 0x9b, 0x06, 0x93, 0x67,
-	0xba, 0xb9, 0x90, 0xa8, 0xfe, 0x3a, 0x14, 0xf9, 0xe1, 0xf2, 0x35, 0xc4, 0xaa, 0xd2, 0x79, 0xaa,
-	0xf4, 0x2a, 0x18, 0x78, 0x41, 0xa2, 0x0d, 0x44, 0x67, 0x2b, 0x69, 0xcb, 0x8c, 0x28, 0x7a, 0x40,
-	0xd5, 0x9f, 0x48, 0x50, 0x9e, 0x5d, 0x2d, 0x0d, 0x56, 0xb6, 0xfb, 0xec, 0x57, 0x70, 0x01, 0xf4,
-	0x01, 0x64, 0xf9, 0x35, 0x53, 0x6c, 0xc4, 0xb5, 0xc5, 0x1b, 0x91, 0x58, 0x80, 0x26, 0x14, 0xd0,
-	0x87, 0x90, 0x13, 0x5d, 0x64, 0xb1, 0x09, 0xcf, 0xa1, 0x1b, 0x6a, 0x54, 0x6f, 0x42, 0x69, 0x66,
-	0x73, 0x51, 0x05, 0x72, 0x26, 0xc1, 0x46, 0x80, 0xb9, 0xcf, 0xa5, 0xb5, 0x70, 0x58, 0xfb, 0xd7,
-	0xa5, 0x45, 0x91, 0x32, 0x0f, 0xe9, 0x5e, 0x5f, 0xe9, 0xca, 0x12, 0x7a, 0x09, 0x90, 0xa6, 0x0c,
-	0x86, 0x3d, 0x4d, 0xd1, 0x5b, 0xdb, 0x4a, 0xeb, 0x51, 0xbf, 0xa7, 0x76, 0x69, 0xac, 0x9c, 0x89,
-	0xa5, 0x29, 0x74, 0x11, 0x56, 0x9b, 0xca, 0x03, 0xb5, 0xab, 0x0f, 0xb5, 0x46, 0x77, 0xd0, 0x68,
-	0x0d, 0xd5, 0xde, 0xff, 0x4e, 0xcc, 0xac, 0xc0, 0x7a, 0x57, 0xf9, 0x74, 0x18, 0xe9, 0xe9, 0xdb,
-	0x4a, 0xa3, 0xad, 0x68, 0x72, 0x81, 0xce, 0x7c, 0x96, 0xd3, 0xec, 0xb5, 0x1f, 0xcb, 0x80, 0xca,
-	0x00, 0x43, 0xad, 0xb1, 0xd3, 0xef, 0x75, 0xd4, 0xae, 0x22, 0x17, 0xe9, 0xd4, 0xe3, 0xb1, 0xae,
-	0x76, 0x3f, 0xee, 0x3d, 0x52, 0xe4, 0x65, 0x4a, 0xde, 0xea, 0xec, 0x0e, 0xb6, 0x67, 0x56, 0x54,
-	0xa2, 0xe4, 0xbe, 0xa6, 0xf4, 0x1b, 0x9a, 0xa2, 0x0f, 0x7b, 0x74, 0x51, 0x3b, 0xea, 0x50, 0x2e,
-	0xa3, 0x35, 0x58, 0x69, 0x75, 0x94, 0x86, 0xa6, 0x6b, 0xca, 0x03, 0x75, 0x30, 0x54, 0xb4, 0x81,
-	0xbc, 0x12, 0xc7, 0x73, 0xb9, 0x76, 0x9a, 0x86, 0x3c, 0xad, 0xa3, 0x2c, 0x1a, 0xce, 0x7f, 0xba,
-	0x94, 0x2c, 0x70, 0xa2, 0x0f, 0xb5, 0xd2, 0xcc, 0x87, 0x5a, 0x74, 0x19, 0x0a, 0x96, 0x4d, 0xd8,
-	0x55, 0xf7, 0x38, 0x4c, 0xe6, 0x11, 0x81, 0x7d, 0xf3, 0x63, 0x41, 0x46, 0x54, 0xff, 0x62, 0x84,
-	0xee, 0x40, 0x51, 0xf4, 0x60, 0x58, 0xcb, 0x20, 0xcd, 0xae, 0x1d, 0xab, 0x61, 0x87, 0x25, 0xfa,
-	0xda, 0xa7, 0x01, 0x97, 0x62, 0xd7, 0xe5, 0x4d, 0x58, 0x8b, 0x7f, 0xc1, 0xa1, 0x8f, 0xb0, 0x8b,
-	0x49, 0xd8, 0x99, 0xca, 0x6b, 0x28, 0x66, 0x3d, 0x10, 0x1c, 0xf4, 0x26, 0x24, 0xa8, 0xba, 0xe9,
-	0x8d, 0x27, 0xb6, 0x68, 0x95, 0xe4, 0xb5, 0xd5, 0x98, 0xd3, 0xe2, 0x8c, 0x39, 0x71, 0x1a, 0xc2,
-	0x8d, 0x11, 0x16, 0xdf, 0x2a, 0x13, 0xe2, 0x7d, 0xce, 0x40, 0xb7, 0x61, 0xd5, 0xf4, 0x5c, 0x97,
-	0x2d, 0x54, 0x77, 0x71, 0xf0, 0xb9, 0x47, 0x0e, 0xc5, 0xef, 0x17, 0xe4, 0x88, 0xd1, 0xe5, 0xf4,
-	0xea, 0x6f, 0x40, 0x66, 0x0b, 0x07, 0xe6, 0x01, 0xba, 0x06, 0x51, 0xa7, 0x49, 0x9f, 0x12, 0xf1,
-	0x79, 0x9f, 0xde, 0x5f, 0xc4, 0xaf, 0x34, 0x88, 0x83, 0xee, 0xce, 0x7d, 0x04, 0x5d, 0x7a, 0xda,
-	0xe6, 0x24, 0xbf, 0x8b, 0xd6, 0xfe, 0x26, 0xac, 0x25, 0x0a, 0x90, 0x61, 0x18, 0xe7, 0xfe, 0xd1,
-	0xef, 0x75, 0x3a, 0xb2, 0x34, 0x87, 0xa6, 0x25, 0x5a, 0xe3, 0x25, 0xd0, 0xb4, 0xa5, 0x0c, 0x5b,
-	0xdb, 0x72, 0x0a, 0x6d, 0xc0, 0x2b, 0x09, 0x2a, 0xf3, 0xb2, 0xc6, 0x50, 0xd1, 0x5b, 0x8d, 0xfe,
-	0x90, 0xfa, 0x4f, 0x1a, 0xbd, 0x01, 0xd7, 0x17, 0x09, 0xec, 0x34, 0x86, 0x8a, 0xa6, 0x36, 0x3a,
-	0xea, 0xf7, 0x1a, 0x0c, 0x7f, 0x19, 0xfa, 0xe6, 0x76, 0xaf, 0xab, 0xc8, 0x59, 0xfa, 0x26, 0xfa,
-	0xa4, 0x7f, 0xa2, 0x0e, 0xb7, 0x75, 0x45, 0xd3, 0x7a, 0xda, 0x80, 0xfb, 0x48, 0xab, 0x31, 0x6c,
-	0x74, 0x7a, 0x0f, 0xf4, 0x41, 0x6b, 0x5b, 0xd9, 0x69, 0xc8, 0x56, 0x6d, 0x0d, 0x56, 0x35, 0xec,
-	0xe3, 0x80, 0xc6, 0x82, 0xf0, 0x6a, 0x5f, 0x5b, 0x07, 0x94, 0x24, 0xf2, 0x0b, 0x6b, 0xed, 0x3e,
-	0xa0, 0x86, 0x75, 0x64, 0xb8, 0x26, 0x1e, 0xda, 0xe3, 0xa8, 0x0d, 0xf0, 0x06, 0xac, 0x18, 0x9c,
-	0x1a, 0xc5, 0x4a, 0x1e, 0x46, 0xca, 0x82, 0x2c, 0xa2, 0x65, 0xed, 0x22, 0xac, 0xcd, 0xa8, 0x0b,
-	0xab, 0xef, 0xc3, 0x72, 0x32, 0x3c, 0x22, 0x04, 0x69, 0x7a, 0x69, 0x13, 0x46, 0xd8, 0x33, 0xfb,
-	0x9d, 0x14, 0x65, 0xb2, 0x53, 0x49, 0x6b, 0x7c, 0x50, 0xfb, 0x81, 0x04, 0x25, 0xd5, 0x1d, 0x61,
-	0x3f, 0x08, 0xe7, 0xf2, 0xa2, 0x1f, 0x85, 0x93, 0x1e, 0xb6, 0x34, 0xeb, 0x61, 0x0b, 0xba, 0x52,
-	0xa9, 0x05, 0x5d, 0xa9, 0xda, 0xcf, 0x96, 0xa0, 0x1c, 0x4e, 0x42, 0x5c, 0xec, 0xff, 0x52, 0x82,
-	0x35, 0xf1, 0x43, 0x10, 0x3d, 0xbe, 0x13, 0x87, 0x59, 0xf3, 0x76, 0xf8, 0x4b, 0x81, 0xa4, 0x4e,
-	0xd8, 0x34, 0xf9, 0x24, 0xbc, 0x00, 0xfb, 0xfc, 0x73, 0xcf, 0xaf, 0xfd, 0xe0, 0x9b, 0x17, 0xfa,
-	0x05, 0xca, 0x0f, 0xbf, 0x79, 0xa1, 0x3b, 0xed, 0xea, 0x67, 0xf3, 0xaf, 0x45, 0xbf, 0x0a, 0xcb,
-	0xe1, 0xdc, 0x71, 0x60, 0x5a, 0x22, 0x39, 0x5d, 0x9c, 0xef, 0x38, 0xd4, 0x95, 0xc0, 0xb4, 0xc2,
-	0xcf, 0xcd, 0x42, 0x81, 0x92, 0xaa, 0x6d, 0x78, 0x69, 0xf1, 0x5a, 0xce, 0x6b, 0x37, 0xa5, 0x12,
-	0xed, 0xa6, 0x5b, 0x1f, 0xc3, 0x72, 0xb2, 0x5f, 0x3f, 0x9b, 0x80, 0x00, 0xb2, 0x83, 0x8f, 0x3a,
-	0xea, 0x90, 0xba, 0x54, 0xdc, 0xcd, 0x4b, 0x51, 0x78, 0x37, 0x54, 0xad, 0xf9, 0x78, 0xa8, 0xe8,
-	0x83, 0xde, 0xae, 0xd6, 0x52, 0xe4, 0x1c, 0x4d, 0x45, 0x5b, 0x9d, 0xde, 0x27, 0xfa, 0x40, 0xed,
-	0x3e, 0x92, 0xf3, 0xb7, 0xba, 0x50, 0xee, 0x78, 0xa3, 0x0e, 0x3e, 0xc2, 0xce, 0x16, 0xfb, 0x79,
-	0x11, 0xca, 0x41, 0xaa, 0xb7, 0xb5, 0x25, 0x5f, 0xa0, 0x1e, 0xcc, 0x1c, 0x45, 0x96, 0xa8, 0x1f,
-	0x7d, 0xd2, 0xd0, 0xba, 0xf2, 0x12, 0x7d, 0x52, 0xbb, 0x5b, 0x3d, 0x51, 0xec, 0x2b, 0xcd, 0xdd,
-	0x07, 0x72, 0x9a, 0x3e, 0xd2, 0x8b, 0x9a, 0x22, 0x67, 0x6e, 0xfd, 0x16, 0x14, 0x13, 0xb1, 0x01,
-	0xc9, 0xb0, 0x1c, 0x39, 0x58, 0x5f, 0x69, 0xf1, 0xfb, 0xec, 0xc3, 0x41, 0xaf, 0x1b, 0xfa, 0x9b,
-	0xc4, 0x32, 0xca, 0xe3, 0xbe, 0x32, 0x68, 0x69, 0x6a, 0x7f, 0xa8, 0xef, 0xf4, 0xda, 0xbb, 0x1d,
-	0xba, 0x8e, 0x15, 0x28, 0x76, 0xfb, 0x3b, 0x7a, 0xbf, 0xd1, 0x7a, 0xd4, 0x78, 0x40, 0x93, 0x26,
-	0x40, 0x96, 0xe7, 0x50, 0x39, 0x4d, 0x75, 0xc2, 0x44, 0x35, 0xd0, 0xb7, 0xd4, 0x4f, 0x59, 0x5c,
-	0xc8, 0xdc, 0x69, 0x43, 0x5e, 0xf4, 0x94, 0x08, 0x7a, 0x3f, 0xfe, 0xed, 0xdc, 0xfa, 0x4c, 0x3f,
-	0x5a, 0xb8, 0x46, 0xf5, 0xe2, 0x1c, 0x95, 0xe3, 0xee, 0x2d, 0xe9, 0xce, 0xdf, 0x49, 0x90, 0x1b,
-	0x62, 0x3f, 0xb0, 0xdd, 0x11, 0xfa, 0x2e, 0x40, 0xec, 0xf7, 0xe8, 0x65, 0xd1, 0x69, 0x9b, 0x0f,
-	0x0f, 0xd5, 0xca, 0x59, 0x86, 0x80, 0x7e, 0x13, 0x8a, 0x09, 0x1f, 0x47, 0x42, 0xf0, 0x6c, 0xd4,
-	0xa8, 0x5e, 0x5a, 0xc0, 0x11, 0x36, 0xde, 0xa6, 0x25, 0x39, 0x75, 0x0e, 0xb4, 0x36, 0xeb, 0x2a,
-	0x5c, 0x73, 0x7d, 0x91, 0xff, 0x34, 0x3f, 0xfc, 0xea, 0x9f, 0xaf, 0x5c, 0xf8, 0xea, 0xe7, 0x57,
-	0xa4, 0xaf, 0x7f, 0x7e, 0x45, 0xfa, 0xf2, 0x5f, 0xae, 0x48, 0xdf, 0xbb, 0x39, 0xb2, 0x83, 0x83,
-	0xe9, 0x5e, 0xdd, 0xf4, 0xc6, 0x9b, 0xd8, 0x0f, 0xa6, 0x06, 0x39, 0xe6, 0x3f, 0xf3, 0x3c, 0xf3,
-	0xc3, 0xcf, 0xbd, 0x2c, 0x1b, 0xbf, 0xfd, 0xdf, 0x01, 0x00, 0x00, 0xff, 0xff, 0x79, 0x77, 0xee,
-	0x83, 0x14, 0x2a, 0x00, 0x00,
+	// 4137 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd5, 0x5a, 0xcd, 0x6f, 0x23, 0x47,
+	0x76, 0x1f, 0x8a, 0xdf, 0x8f, 0x94, 0x44, 0xb5, 0x34, 0x63, 0x9a, 0xeb, 0x8c, 0x6c, 0xda, 0xbb,
+	0xb1, 0xc7, 0x31, 0xe5, 0x8c, 0x3f, 0xd6, 0x1e, 0x67, 0xb2, 0xe0, 0x47, 0x4b, 0xc3, 0x19, 0x8a,
+	0xa4, 0x9b, 0x94, 0xed, 0x59, 0x20, 0xe9, 0xb4, 0xd8, 0x25, 0xaa, 0x3d, 0x54, 0x37, 0xd3, 0xdd,
+	0x94, 0x47, 0x9b, 0x43, 0x90, 0x3d, 0x05, 0x9b, 0x04, 0x48, 0x72, 0x89, 0x8f, 0xde, 0x3d, 0xe4,
+	0x90, 0x43, 0x80, 0xe4, 0x18, 0x04, 0xc8, 0x21, 0x17, 0x2f, 0x82, 0x00, 0x06, 0x36, 0x41, 0x2e,
+	0x81, 0x8d, 0x7c, 0x1c, 0x36, 0xc8, 0x7f, 0xb0, 0xc9, 0x21, 0xef, 0x55, 0x55, 0x7f, 0x90, 0xa2,
+	0x46, 0x1a, 0x07, 0xc8, 0xc7, 0x61, 0x34, 0x5d, 0xaf, 0xde, 0x7b, 0x5d, 0xf5, 0xea, 0xf7, 0x3e,
+	0xea, 0x35, 0xe1, 0xb9, 0xb1, 0xb3, 0x33, 0x75, 0x1d, 0xdf, 0x19, 0x39, 0x13, 0x6f, 0xe7, 0x68,
+	0xe2, 0x7c, 0xc2, 0xff, 0xd4, 0x38, 0x4d, 0x49, 0xd1, 0x73, 0xe5, 0xe6, 0xa1, 0xeb, 0x3c, 0x62,
+	0x6e, 0xc8, 0x17, 0x3e, 0x08, 0xae, 0xca, 0xf3, 0x23, 0xc7, 0xf6, 0x66, 0x27, 0x4f, 0xe0, 0xd8,
+	0x1a, 0x3b, 0x63, 0x87, 0x3f, 0xee, 0xd0, 0x93, 0xa0, 0x56, 0xef, 0x42, 0x7a, 0x30, 0xb1, 0x46,
+	0x4c, 0xd9, 0x82, 0xf4, 0x21, 0x1b, 0x5b, 0x76, 0x39, 0xf1, 0x7c, 0xe2, 0xe5, 0x55, 0x4d, 0x0c,
+	0x94, 0x12, 0x24, 0x99, 0x6d, 0x96, 0x57, 0x38, 0x8d, 0x1e, 0xef, 0x14, 0xbf, 0xf8, 0xe1, 0xf6,
+	0xb5, 0xdf, 0xff, 0xd1, 0xf6, 0xb5, 0x4f, 0xf1, 0x5f, 0xf5, 0xb7, 0x13, 0x90, 0x3f, 0x38, 0x68,
+	0xb7, 0xfa, 0x86, 0xeb, 0x7b, 0xca, 0x2f, 0x80, 0x82, 0x5a, 0xcd, 0xd9, 0x88, 0xb9, 0xba, 0x61,
+	0x9b, 0xfa, 0xd1, 0xc4, 0x18, 0x7b, 0x5c, 0x61, 0x46, 0x2b, 0x05, 0x33, 0x75, 0xdb, 0xdc, 0x25,
+	0xba, 0xf2, 0x1e, 0xa4, 0x47, 0x13, 0x67, 0xf4, 0x88, 0x6b, 0xcf, 0x34, 0xbe, 0xf9, 0xb3, 0x2f,
+	0xb7, 0x5f, 0xc0, 0x75, 0x8d, 0x8d, 0xef, 0x31, 0xdf, 0x67, 0x35, 0x93, 0x9d, 0xee, 0x8c, 0x1c,
+	0x97, 0xed, 0x9c, 0x30, 0xcf, 0x33, 0xc6, 0xac, 0xd6, 0x24, 0x66, 0x4d, 0xc8, 0xdc, 0x29, 0xfd,
+	0xf4, 0xb3, 0xed, 0xc4, 0xdc, 0x52, 0xee, 0x03, 0x74, 0x8c, 0x93, 0x43, 0xd3, 0x18, 0x4c, 0xd9,
+	0x48, 0xb9, 0x09, 0xe0, 0x9f, 0x4d, 0x99, 0x37, 0x72, 0xad, 0xa9, 0xcf, 0x97, 0x90, 0xd7, 0x62,
+	0x14, 0xe5, 0x06, 0x64, 0x5c, 0x76, 0xe2, 0xf8, 0x8c, 0xbf, 0x3d, 0xaf, 0xc9, 0xd1, 0x9d, 0x14,
+	0xe9, 0xad, 0xfe, 0x7b, 0x0a, 0xb2, 0x83, 0xe3, 0xd9, 0xd1, 0xd1, 0x84, 0x29, 0x3f, 0x07, 0x30,
+	0x76, 0x9d, 0xd9, 0x54, 0xb7, 0x8d, 0x13, 0x26, 0x35, 0xe5, 0x39, 0xa5, 0x8b, 0x04, 0xdc, 0xc5,
+	0x86, 0xe7, 0xcc, 0xdc, 0x11, 0xd3, 0xd1, 0xd4, 0x13, 0x36, 0xf2, 0x2d, 0xc7, 0x16, 0x3a, 0x1b,
+	0x6b, 0xb8, 0x23, 0x68, 0x86, 0x54, 0xad, 0x24, 0x18, 0x23, 0x8a, 0x72, 0x3f, 0x14, 0x9e, 0xa2,
+	0x01, 0x2d, 0xa2, 0x79, 0xe5, 0x24, 0x0a, 0x17, 0x6e, 0x3f, 0x53, 0x0b, 0xcf, 0xaf, 0x63, 0x1c,
+	0xb2, 0xc9, 0x80, 0x91, 0x90, 0xe3, 0x36, 0x52, 0x9f, 0x7f, 0xb9, 0x7d, 0x2d, 0xd0, 0xd5, 0x0f,
+	0xc5, 0x94, 0x6f, 0xc1, 0xba, 0xd4, 0x35, 0x9b, 0x59, 0xa6, 0x3e, 0xf5, 0xdd, 0x72, 0x8a, 0x2f,
+	0x76, 0x55, 0x90, 0x0f, 0x90, 0xda, 0xf7, 0x5d, 0xce, 0x27, 0xb6, 0xa6, 0x3f, 0x62, 0x67, 0x9c,
+	0x2f, 0xfd, 0x7c, 0x92, 0xf3, 0x09, 0xf2, 0x03, 0x76, 0x26, 0xf9, 0x66, 0x1e, 0xf3, 0x74, 0xa9,
+	0x14, 0x79, 0xcb, 0x19, 0xd4, 0x97, 0xd3, 0x56, 0x89, 0x3c, 0xe0, 0x54, 0x64, 0x55, 0xbe, 0x0d,
+	0x6b, 0x81, 0xbe, 0x09, 0xb7, 0x7f, 0x39, 0xcb, 0x37, 0x50, 0xaa, 0x71, 0x10, 0x47, 0x67, 0x12,
+	0xbe, 0x40, 0x90, 0x94, 0x5b, 0xe1, 0xe6, 0xbd, 0xd1, 0x31, 0x3b, 0x31, 0xf4, 0x99, 0x6b, 0x95,
+	0x73, 0x7c, 0xc9, 0x72, 0x27, 0x03, 0x4e, 0x3f, 0x70, 0x2d, 0x42, 0x56, 0x7c, 0x31, 0x42, 0xa0,
+	0x9c, 0xe7, 0xeb, 0x29, 0x45, 0xeb, 0x11, 0x02, 0xca, 0x5b, 0xf0, 0xcc, 0xa9, 0x31, 0xb1, 0x4c,
+	0xc3, 0x0f, 0x75, 0x1b, 0xbe, 0xee, 0x32, 0xc3, 0x2c, 0x03, 0x17, 0xd9, 0x0a, 0xa6, 0x85, 0x40,
+	0xdd, 0xd7, 0x70, 0x8e, 0x76, 0x7c, 0x64, 0x4d, 0x7c, 0x04, 0xaf, 0xab, 0x73, 0x94, 0x79, 0xe5,
+	0x82, 0xd8, 0xb1, 0x20, 0x6b, 0x1c, 0x81, 0x1c, 0xe6, 0xa4, 0x4b, 0x37, 0xd9, 0xc4, 0x38, 0xd3,
+	0x3d, 0x86, 0xae, 0x67, 0x7a, 0xe5, 0x22, 0xf7, 0x91, 0x12, 0xcd, 0xb4, 0x68, 0x62, 0x20, 0xe8,
+	0x4a, 0x05, 0x72, 0x53, 0xd7, 0x72, 0x5c, 0xcb, 0x3f, 0x2b, 0xaf, 0x72, 0x9e, 0x70, 0x2c, 0xd1,
+	0xf6, 0xe9, 0x0a, 0xac, 0xdd, 0xc7, 0xf5, 0xdb, 0xc6, 0x24, 0x00, 0x5d, 0x1b, 0xb2, 0x1f, 0x0b,
+	0x8a, 0x40, 0x5c, 0x63, 0x07, 0xb1, 0xf4, 0xea, 0x32, 0xef, 0x58, 0x88, 0x0b, 0x35, 0xa9, 0x48,
+	0x0b, 0xe4, 0x95, 0x01, 0x14, 0x46, 0x8e, 0xe3, 0x9a, 0x96, 0x6d, 0x20, 0x7c, 0x24, 0x34, 0x7f,
+	0x11, 0xd5, 0xbd, 0xb6, 0x4c, 0xdd, 0xb9, 0x30, 0x52, 0x1b, 0x1c, 0x1b, 0xae, 0xd9, 0x6e, 0x69,
+	0x71, 0x2d, 0xca, 0x6b, 0x90, 0x95, 0x87, 0x29, 0xe1, 0xba, 0x2a, 0x4e, 0x5b, 0xae, 0xbf, 0x91,
+	0xfa, 0xe2, 0xcb, 0xed, 0x84, 0x16, 0xf0, 0x08, 0x6f, 0x9b, 0xa2, 0x51, 0x38, 0x24, 0x73, 0x9a,
+	0x1c, 0x29, 0xcf, 0x42, 0xee, 0x70, 0x66, 0x4d, 0x4c, 0xdd, 0x32, 0x11, 0x84, 0x74, 0xf2, 0x59,
+	0x3e, 0x6e, 0x9b, 0xd2, 0x34, 0x5f, 0x26, 0x00, 0xfa, 0xae, 0xf3, 0xb1, 0xf4, 0x17, 0x0c, 0x47,
+	0x84, 0x57, 0xe1, 0x84, 0xf4, 0x48, 0x61, 0xeb, 0xc8, 0x62, 0x13, 0x53, 0xba, 0xb1, 0x18, 0x28,
+	0x2f, 0x02, 0x81, 0xd4, 0xd5, 0x71, 0x13, 0xa7, 0x96, 0xc9, 0x4c, 0xbe, 0xc8, 0x9c, 0x56, 0x24,
+	0x62, 0x5f, 0xd2, 0x94, 0x97, 0xa1, 0x64, 0x79, 0x91, 0xe3, 0x71, 0x84, 0x8b, 0xe5, 0xad, 0x59,
+	0x5e, 0xe8, 0x58, 0x04, 0xf1, 0x97, 0x60, 0x8d, 0x38, 0x5d, 0xeb, 0xc4, 0x70, 0xcf, 0x38, 0x5f,
+	0x5a, 0xe8, 0x43, 0x3e, 0x41, 0x24, 0xae, 0x37, 0x20, 0x6f, 0xd9, 0x47, 0xcc, 0x65, 0xf6, 0x88,
+	0x71, 0x57, 0x29, 0xdc, 0x5e, 0x17, 0x56, 0x69, 0x07, 0x64, 0xe9, 0xbc, 0x11, 0x5f, 0xf5, 0x4f,
+	0x93, 0x90, 0x0f, 0xa7, 0x69, 0x37, 0x3c, 0x46, 0xe1, 0x0e, 0xc9, 0x23, 0xc5, 0x80, 0x22, 0xd0,
+	0xc9, 0xcc, 0xf3, 0x75, 0xf6, 0xd8, 0xf2, 0x7c, 0xbe, 0xd1, 0x9c, 0x96, 0x27, 0x8a, 0x4a, 0x04,
+	0xa5, 0x06, 0x19, 0xcf, 0x77, 0x2d, 0x7b, 0x2c, 0x8f, 0xe2, 0xc6, 0xc2, 0x4b, 0x6b, 0x03, 0x3e,
+	0xab, 0x49, 0x2e, 0xfe, 0x12, 0xcb, 0xc7, 0x93, 0x13, 0xe1, 0x41, 0x0c, 0x94, 0xe7, 0xa1, 0x60,
+	0xca, 0xe0, 0x48, 0x11, 0x4c, 0x9c, 0x46, 0x9c, 0xa4, 0x7c, 0x07, 0x8a, 0x26, 0x3b, 0x32, 0x66,
+	0x13, 0x5f, 0xff, 0xd8, 0x43, 0x96, 0x0c, 0x47, 0xd2, 0x73, 0x88, 0xa4, 0x32, 0xbe, 0xc6, 0x41,
+	0x68, 0x8c, 0x77, 0x68, 0xa2, 0xa6, 0x19, 0x9f, 0xec, 0x8b, 0x98, 0x4d, 0x0a, 0xb8, 0xc4, 0x7d,
+	0xa4, 0x13, 0x0a, 0xd0, 0x59, 0x5c, 0xe6, 0xf3, 0x08, 0x81, 0x28, 0x10, 0xa3, 0xca, 0x9f, 0x24,
+	0x20, 0x23, 0xd6, 0xa8, 0xbc, 0x00, 0x45, 0x44, 0xa0, 0xcf, 0x6c, 0x5f, 0xa7, 0xbd, 0xf3, 0x1d,
+	0xe5, 0x09, 0x7a, 0x9c, 0x36, 0x44, 0x12, 0x69, 0x39, 0x72, 0xdc, 0x13, 0xc3, 0x97, 0xeb, 0x97,
+	0x23, 0xe5, 0x15, 0x28, 0x05, 0xa2, 0xc1, 0x72, 0xf8, 0x7b, 0x30, 0x9a, 0x48, 0xba, 0x2a, 0xc9,
+	0xca, 0x37, 0xf0, 0xa4, 0x3c, 0xfd, 0xd0, 0xf0, 0xd8, 0xdb, 0x6f, 0xca, 0xa3, 0xcc, 0x59, 0x5e,
+	0x83, 0x8f, 0xb9, 0xb5, 0x8d, 0xc7, 0xfa, 0x84, 0xd9, 0x63, 0xff, 0x98, 0x6f, 0x72, 0x15, 0xad,
+	0x6d, 0x3c, 0xee, 0x70, 0x42, 0xf5, 0xc7, 0x49, 0x58, 0x8b, 0x22, 0x38, 0xcf, 0x35, 0x35, 0x80,
+	0x58, 0xec, 0x4f, 0x2c, 0x8d, 0xfd, 0x31, 0x0e, 0x7a, 0x43, 0x2c, 0xe2, 0x09, 0xe0, 0xe6, 0xbd,
+	0x30, 0xd6, 0xdd, 0x85, 0x82, 0x9c, 0xe6, 0x66, 0xce, 0x5d, 0xc1, 0xcc, 0x52, 0x1f, 0xb7, 0x32,
+	0xfa, 0x94, 0x8c, 0xeb, 0x94, 0x4a, 0x08, 0x46, 0xd9, 0x47, 0x3c, 0xa2, 0x7b, 0x34, 0xb5, 0x90,
+	0x1b, 0xb2, 0x33, 0x99, 0x15, 0xd0, 0x7a, 0x91, 0x27, 0x70, 0x27, 0xf2, 0x64, 0x5a, 0x58, 0x0f,
+	0xe9, 0xbb, 0x9c, 0xac, 0xbc, 0x03, 0x85, 0x69, 0xe8, 0x92, 0x1e, 0x5a, 0x28, 0x19, 0x45, 0xfb,
+	0xc8, 0x57, 0x25, 0xd4, 0xe3, 0xac, 0xca, 0x3d, 0xd8, 0x30, 0x46, 0x8f, 0xf8, 0xb6, 0x74, 0x9f,
+	0x9d, 0x60, 0x08, 0xc0, 0xfc, 0x9b, 0xbd, 0xc2, 0xfe, 0xd6, 0x51, 0x8c, 0x36, 0x37, 0x94, 0x42,
+	0x4a, 0x0b, 0x2b, 0x8d, 0x70, 0xb9, 0xa1, 0xaa, 0x3c, 0xc7, 0xff, 0xf5, 0xda, 0x62, 0x30, 0xe4,
+	0xd9, 0x67, 0x23, 0x14, 0x08, 0xb4, 0x54, 0x7f, 0x67, 0x05, 0x56, 0x87, 0xae, 0x61, 0x7b, 0x04,
+	0xa1, 0xe0, 0x28, 0x4d, 0xe6, 0x5a, 0xa7, 0xc6, 0x93, 0x8e, 0x32, 0xe2, 0x50, 0x5e, 0x85, 0xbc,
+	0x1f, 0x28, 0x90, 0xa1, 0x75, 0x15, 0xd9, 0xf3, 0xa1, 0x56, 0x2d, 0x9a, 0xbf, 0x3c, 0x68, 0x72,
+	0x8b, 0x85, 0x41, 0xf3, 0x2d, 0x0c, 0x62, 0x53, 0x9e, 0xc3, 0x64, 0x5e, 0x4d, 0x5d, 0x90, 0x57,
+	0x8b, 0x82, 0x4d, 0xa6, 0x55, 0xcc, 0xc7, 0xd3, 0xd9, 0xe1, 0xc4, 0xf2, 0x8e, 0x03, 0xb9, 0xf4,
+	0x45, 0xf9, 0x58, 0xf2, 0x09, 0x52, 0xf5, 0x77, 0x11, 0xd9, 0xad, 0x70, 0x6b, 0xdc, 0x1c, 0x77,
+	0xce, 0x21, 0xbb, 0x70, 0x7b, 0x4b, 0xe8, 0x99, 0xf7, 0x01, 0xb9, 0xf6, 0x38, 0xca, 0x6b, 0xb0,
+	0xe9, 0x62, 0x0d, 0xe9, 0x51, 0x3e, 0x3d, 0x07, 0xf7, 0x8d, 0x60, 0x2a, 0x4a, 0xf1, 0x1d, 0xb8,
+	0x1e, 0xf2, 0x5b, 0x36, 0x1e, 0x94, 0x31, 0x11, 0x0e, 0x90, 0xbc, 0x0a, 0x40, 0x02, 0xd1, 0xb6,
+	0x90, 0x54, 0xde, 0xc5, 0xfa, 0x2f, 0x30, 0xbc, 0x87, 0x96, 0x23, 0x8c, 0x6e, 0x8a, 0x95, 0xcf,
+	0x9d, 0x78, 0xb0, 0xf0, 0x88, 0x19, 0x37, 0x8d, 0x05, 0x0d, 0xe6, 0xbc, 0x08, 0x57, 0xc2, 0x80,
+	0x9b, 0xb5, 0x20, 0x39, 0x8a, 0x9c, 0x18, 0xd4, 0x34, 0xf8, 0x18, 0xe2, 0xb2, 0x4d, 0x9b, 0x18,
+	0x39, 0xa7, 0x0c, 0xf3, 0xc4, 0xc4, 0x19, 0x47, 0x2a, 0x32, 0x4f, 0x82, 0xe6, 0x66, 0x20, 0xd3,
+	0x71, 0xc6, 0x21, 0x38, 0x7f, 0x0f, 0xab, 0x02, 0xee, 0x71, 0xa2, 0xf2, 0x23, 0x93, 0x2a, 0x90,
+	0x42, 0x57, 0x0e, 0xb2, 0x03, 0x7f, 0xa6, 0x70, 0x88, 0xc5, 0xcc, 0x0c, 0x73, 0xc6, 0x0a, 0xa7,
+	0xca, 0x11, 0x95, 0x1d, 0xa6, 0x33, 0xc2, 0xd5, 0xda, 0xbe, 0x8c, 0xa2, 0xe1, 0x58, 0x71, 0x60,
+	0x83, 0xbb, 0x38, 0x96, 0xac, 0xf6, 0x91, 0x35, 0x16, 0x66, 0x16, 0x36, 0x7a, 0x45, 0xd8, 0x68,
+	0xfe, 0xc5, 0x62, 0xd8, 0xe4, 0xcc, 0xe4, 0x86, 0xaa, 0xed, 0xbb, 0x67, 0x8d, 0xe7, 0x7e, 0xf0,
+	0xd5, 0x93, 0x22, 0xff, 0x51, 0x24, 0x53, 0x69, 0xc0, 0xd6, 0x32, 0x15, 0x94, 0xcf, 0x29, 0x9b,
+	0xca, 0x7c, 0x8e, 0x8f, 0x94, 0x9c, 0xf8, 0x06, 0x82, 0x7c, 0xce, 0x07, 0x77, 0x56, 0xde, 0x49,
+	0x54, 0x7f, 0x92, 0x82, 0x42, 0xd3, 0x98, 0xfa, 0x33, 0x97, 0x71, 0x6c, 0x7e, 0x13, 0xb2, 0x23,
+	0x31, 0x94, 0x7e, 0x5a, 0x40, 0x84, 0x64, 0x25, 0x87, 0x16, 0xcc, 0xa1, 0x3b, 0xac, 0xe2, 0xb5,
+	0x65, 0xea, 0x58, 0x41, 0x4a, 0x21, 0xc5, 0x6b, 0xb7, 0x15, 0xb1, 0x4f, 0x55, 0x4e, 0x51, 0x66,
+	0xd1, 0x8a, 0x2c, 0x36, 0x52, 0x76, 0x41, 0x09, 0x05, 0x3d, 0x7c, 0xe1, 0xd5, 0xc1, 0x18, 0xea,
+	0xe1, 0xeb, 0x7c, 0x0b, 0x6b, 0x1c, 0xcb, 0x26, 0xc6, 0x00, 0x87, 0xcf, 0x4a, 0x0f, 0x8a, 0x36,
+	0x53, 0x6b, 0x08, 0x0e, 0x2d, 0x64, 0xa5, 0x80, 0x8c, 0x1a, 0x98, 0x8b, 0x06, 0x08, 0x4b, 0xcc,
+	0x34, 0x4f, 0x46, 0xeb, 0x01, 0x3d, 0xa8, 0x30, 0xcf, 0x03, 0x36, 0xf3, 0xdf, 0x07, 0x6c, 0xf6,
+	0x69, 0x01, 0x5b, 0xf9, 0xf3, 0x04, 0x64, 0xe5, 0x3e, 0xc8, 0x78, 0x2e, 0x0b, 0x8a, 0xf5, 0xd0,
+	0x78, 0x89, 0xab, 0x18, 0x2f, 0x90, 0xe3, 0xc6, 0xc3, 0x42, 0x2e, 0xd4, 0x33, 0x35, 0x30, 0x1f,
+	0x0b, 0x90, 0x87, 0x4c, 0x7d, 0xa4, 0x2d, 0x44, 0xa9, 0xe4, 0xd3, 0x44, 0xa9, 0xea, 0xbf, 0xa5,
+	0x61, 0x73, 0x1f, 0x57, 0xef, 0x62, 0xd0, 0xb0, 0xbe, 0x17, 0x45, 0xbe, 0xbb, 0xb0, 0x7e, 0x32,
+	0x4f, 0x96, 0xab, 0xdf, 0xc4, 0xd5, 0xaf, 0x2f, 0x48, 0x68, 0x8b, 0xbc, 0xff, 0xfb, 0xa8, 0xbb,
+	0x7b, 0x0e, 0x75, 0x2f, 0x88, 0x77, 0x2f, 0xd9, 0xec, 0x12, 0xf4, 0xfd, 0xdf, 0x88, 0x81, 0x95,
+	0xbf, 0x59, 0xf9, 0xff, 0x07, 0x29, 0xa5, 0x49, 0xd7, 0x48, 0x8a, 0xae, 0x5e, 0x10, 0x3f, 0x65,
+	0xe6, 0xde, 0x5a, 0x16, 0x5b, 0xa5, 0x82, 0xb5, 0xa3, 0xf9, 0x50, 0x8f, 0xab, 0xc4, 0xeb, 0xa5,
+	0x8f, 0x39, 0x93, 0xe7, 0x76, 0x2f, 0xb8, 0x71, 0x70, 0xe2, 0x81, 0xa0, 0xc5, 0x0b, 0x8a, 0xcc,
+	0xe5, 0x05, 0x45, 0xf5, 0x8f, 0x93, 0x90, 0x1b, 0x32, 0x4f, 0x00, 0x04, 0x73, 0x09, 0xaa, 0x08,
+	0x5a, 0x23, 0xfc, 0x59, 0xd9, 0x81, 0x34, 0xe6, 0xd0, 0xa9, 0x48, 0x25, 0x51, 0xbe, 0x94, 0x22,
+	0x78, 0x8f, 0x60, 0x53, 0xa9, 0x53, 0xf0, 0x55, 0xfe, 0x6e, 0x05, 0x52, 0x44, 0xc5, 0x20, 0x97,
+	0x27, 0x8a, 0xc0, 0x7a, 0x82, 0x63, 0xbd, 0xbc, 0x44, 0xba, 0xc6, 0x11, 0x9f, 0x23, 0x56, 0x8e,
+	0x76, 0xaa, 0x84, 0x49, 0x0c, 0x8f, 0x98, 0x3d, 0x96, 0x5d, 0x26, 0xae, 0xa8, 0x4d, 0x84, 0xc5,
+	0x3b, 0x49, 0xf2, 0xfc, 0x9d, 0x24, 0x50, 0xe0, 0x8d, 0x9c, 0x69, 0x70, 0xa1, 0xe1, 0x0a, 0x06,
+	0x44, 0x58, 0xa8, 0xcc, 0xd3, 0x97, 0x56, 0xe6, 0xdf, 0x82, 0x75, 0x4c, 0x92, 0x9e, 0xa8, 0x50,
+	0x27, 0x96, 0xcd, 0x3c, 0x71, 0xcb, 0xd1, 0x56, 0x89, 0x4c, 0x79, 0xab, 0x43, 0x44, 0xf4, 0x2e,
+	0x88, 0x35, 0x6c, 0xb2, 0x57, 0x69, 0xd8, 0xc4, 0x04, 0xaa, 0x37, 0x21, 0xc5, 0xb7, 0x0f, 0x90,
+	0x69, 0x77, 0xf7, 0xd4, 0xc1, 0xb0, 0x74, 0x8d, 0x9e, 0x3f, 0x50, 0xb5, 0xf6, 0xee, 0xc3, 0x52,
+	0xa2, 0xfa, 0x47, 0x09, 0xc8, 0x6b, 0x86, 0x3d, 0x16, 0x80, 0xc5, 0xdb, 0x0a, 0x15, 0xf4, 0xa2,
+	0x3b, 0x47, 0x36, 0xca, 0x6a, 0x54, 0xe1, 0x37, 0x78, 0x83, 0xee, 0x19, 0xa0, 0xea, 0x5e, 0xa7,
+	0x26, 0x5d, 0x92, 0x4f, 0x65, 0x70, 0x88, 0xf1, 0x45, 0xa9, 0x22, 0xcc, 0x45, 0x17, 0x43, 0x4a,
+	0xa6, 0xf8, 0x74, 0xc1, 0xe5, 0x4d, 0x0c, 0x21, 0x7c, 0x13, 0x0a, 0x01, 0x0f, 0x29, 0x48, 0x73,
+	0x8e, 0xbc, 0xe0, 0x40, 0x1d, 0x77, 0x4a, 0x9f, 0x7e, 0xb6, 0x7d, 0x6d, 0xae, 0xc9, 0xf6, 0x5b,
+	0x09, 0x28, 0x8a, 0x92, 0xad, 0x31, 0xb3, 0x4d, 0x2c, 0x52, 0xdf, 0x86, 0xcc, 0x21, 0x7f, 0xe2,
+	0x45, 0x49, 0xe1, 0xf6, 0x4d, 0x89, 0xc0, 0x18, 0x4f, 0x4d, 0xfc, 0xc7, 0x33, 0xbe, 0x26, 0xb9,
+	0x2b, 0xef, 0x42, 0x21, 0x46, 0x7e, 0xaa, 0x42, 0xe0, 0x2f, 0xb1, 0x30, 0x92, 0x08, 0xd7, 0xd8,
+	0xaf, 0xcf, 0x08, 0xb8, 0x6f, 0x46, 0x8e, 0x30, 0x57, 0xa4, 0xce, 0x77, 0x55, 0x16, 0x0b, 0xec,
+	0xd7, 0x01, 0xc8, 0xe9, 0x27, 0xb3, 0xb0, 0x67, 0x47, 0x55, 0x72, 0x78, 0x8a, 0xf7, 0x98, 0x81,
+	0x95, 0xbe, 0x16, 0xe3, 0xc1, 0x72, 0x3f, 0xed, 0xd2, 0xb9, 0xc8, 0x88, 0x20, 0xaf, 0xf7, 0xe1,
+	0x51, 0x05, 0xce, 0xc1, 0x79, 0x30, 0x60, 0x65, 0x9c, 0xa3, 0x23, 0x8f, 0x89, 0x8b, 0x6a, 0xb2,
+	0x51, 0x43, 0xe0, 0xdd, 0xba, 0x4a, 0x0b, 0xa7, 0xc7, 0xa5, 0x34, 0x29, 0xad, 0xec, 0x03, 0xe0,
+	0xe9, 0xe8, 0x52, 0x57, 0xfa, 0x6b, 0xe9, 0xca, 0xa3, 0x06, 0xf1, 0x58, 0xfd, 0xb3, 0x14, 0xac,
+	0x87, 0xe6, 0xf3, 0xa6, 0x88, 0x47, 0xa6, 0xbc, 0x4c, 0x2d, 0x04, 0xc3, 0x9f, 0x79, 0xd2, 0x77,
+	0x4b, 0xb1, 0x30, 0xcf, 0xe9, 0x9a, 0x9c, 0x27, 0xce, 0x63, 0x6e, 0x97, 0x0b, 0xed, 0x25, 0xe7,
+	0xb1, 0x3e, 0x5b, 0xc3, 0x4c, 0x73, 0x62, 0xa1, 0xfd, 0x75, 0xe6, 0xba, 0x8e, 0x2b, 0xfd, 0x77,
+	0x35, 0xa0, 0xaa, 0x44, 0x54, 0xde, 0x87, 0x22, 0x6f, 0xa6, 0xf9, 0xc7, 0xae, 0x33, 0x1b, 0x1f,
+	0x7f, 0x4d, 0x5b, 0x15, 0x48, 0xc7, 0x50, 0xa8, 0x20, 0x83, 0x7d, 0xe2, 0x5a, 0x78, 0x6f, 0xa2,
+	0x95, 0x7c, 0x5d, 0x83, 0x71, 0x0d, 0xb4, 0x25, 0x65, 0x1b, 0xd2, 0x86, 0xcb, 0x6c, 0x83, 0x87,
+	0x82, 0x62, 0x23, 0x8f, 0x9a, 0xd2, 0x75, 0x22, 0x68, 0x82, 0x8e, 0x51, 0x26, 0x1f, 0x46, 0x0d,
+	0x0c, 0x06, 0xe4, 0x06, 0x05, 0xe9, 0x06, 0xd4, 0x5a, 0x97, 0xa8, 0xc8, 0x05, 0x21, 0x04, 0xaf,
+	0xc1, 0x59, 0x71, 0x98, 0x1e, 0x5e, 0xee, 0x93, 0x5f, 0x63, 0x71, 0x81, 0x38, 0xe2, 0x1e, 0xc4,
+	0x85, 0x9e, 0xda, 0xef, 0x78, 0xfd, 0x4d, 0x46, 0xa0, 0x0c, 0xbb, 0xf2, 0x41, 0xcf, 0x89, 0xdf,
+	0xf4, 0x79, 0x9b, 0xfe, 0x75, 0x8a, 0x5e, 0xa3, 0x47, 0xcc, 0xe4, 0xad, 0x2c, 0xb8, 0x68, 0xc1,
+	0x79, 0xc1, 0xf4, 0x80, 0x9d, 0x55, 0xff, 0x30, 0x01, 0xa5, 0x16, 0xde, 0x0c, 0x99, 0xdb, 0x3c,
+	0x66, 0xa3, 0x47, 0xbc, 0xcc, 0x50, 0xba, 0x70, 0xc3, 0xe4, 0x34, 0x7d, 0x14, 0x12, 0xa3, 0xa4,
+	0x5c, 0xbc, 0x24, 0x29, 0x97, 0xcc, 0x45, 0x7d, 0x74, 0x61, 0x3c, 0x1a, 0x7d, 0xfb, 0x8d, 0x77,
+	0xdf, 0xd6, 0x11, 0x78, 0x63, 0x9e, 0x9d, 0x47, 0xc7, 0xb2, 0xdf, 0xb5, 0x21, 0xa7, 0xf6, 0x69,
+	0xa6, 0x4f, 0x13, 0x55, 0x07, 0xf2, 0xb2, 0x7d, 0xdb, 0x6f, 0x57, 0x7e, 0x09, 0xa0, 0x31, 0xb3,
+	0x26, 0xbe, 0x48, 0x1c, 0xa8, 0x4a, 0x5e, 0x39, 0x79, 0x66, 0x41, 0x7d, 0x27, 0x41, 0xdf, 0x30,
+	0xa3, 0x6d, 0x88, 0x29, 0xce, 0xb9, 0xcf, 0x27, 0xaa, 0x2f, 0x41, 0xaa, 0xe9, 0x98, 0x4c, 0x29,
+	0x40, 0xb6, 0xdd, 0xfd, 0xa0, 0xde, 0x69, 0xb7, 0x30, 0x22, 0xaf, 0x63, 0x88, 0x3a, 0x68, 0x77,
+	0x5a, 0x7a, 0xbb, 0xdb, 0x52, 0x3f, 0xc2, 0xb0, 0xfc, 0x1f, 0x09, 0x00, 0xf5, 0x31, 0xde, 0x14,
+	0x47, 0x3e, 0xbd, 0xf2, 0x0f, 0x12, 0x90, 0x11, 0x17, 0x9a, 0xb9, 0xc6, 0x4a, 0x62, 0xbe, 0xb1,
+	0x72, 0x49, 0xb3, 0xe7, 0x82, 0x95, 0x26, 0x2f, 0x58, 0x29, 0xa9, 0x13, 0xc5, 0x05, 0xef, 0xef,
+	0xa4, 0x78, 0xe9, 0x92, 0xe7, 0x14, 0xea, 0xf0, 0x54, 0x1f, 0x2e, 0xdb, 0xc8, 0x2a, 0xe4, 0x9b,
+	0xbd, 0xee, 0x6e, 0x7b, 0xef, 0x40, 0x53, 0x4b, 0x09, 0x9a, 0x53, 0x3f, 0x1a, 0x6a, 0xf5, 0xe6,
+	0xb0, 0xb4, 0x82, 0x65, 0xc0, 0x9a, 0x1c, 0xa8, 0x2d, 0x9d, 0x30, 0x53, 0x4a, 0x62, 0xe8, 0x2d,
+	0x45, 0xb4, 0xdd, 0xb6, 0xda, 0x69, 0x0d, 0x4a, 0xa9, 0xea, 0x3f, 0xa4, 0x00, 0xd3, 0xe6, 0x09,
+	0x96, 0x88, 0x8c, 0x76, 0xff, 0xd3, 0x68, 0xf7, 0x4f, 0x69, 0xed, 0xcb, 0x4c, 0x22, 0x53, 0x9a,
+	0x30, 0x03, 0xbf, 0xd2, 0x8a, 0xfe, 0xd5, 0x25, 0x7b, 0x47, 0x58, 0x6f, 0x89, 0x43, 0x98, 0x18,
+	0x23, 0x76, 0xec, 0x4c, 0x4c, 0x6a, 0x00, 0xf3, 0xaf, 0x1b, 0xf4, 0x02, 0x85, 0x1f, 0x48, 0x34,
+	0x45, 0x0a, 0x31, 0x44, 0x19, 0x9e, 0xc7, 0x5c, 0x5f, 0x17, 0x3d, 0x44, 0x2f, 0xf8, 0xc2, 0x21,
+	0xa8, 0xbb, 0x82, 0x58, 0xf9, 0x4d, 0x48, 0x53, 0x14, 0xa4, 0x4f, 0x2c, 0xa9, 0x09, 0x3b, 0xf2,
+	0x65, 0x8e, 0x91, 0xc5, 0x7c, 0x0b, 0xdd, 0xba, 0x6e, 0x9b, 0x8d, 0x33, 0x2c, 0xa1, 0x34, 0x3e,
+	0x8f, 0x41, 0x32, 0xed, 0x5a, 0xe3, 0x63, 0x5f, 0xc6, 0xc8, 0x65, 0x8c, 0x82, 0x41, 0x79, 0x09,
+	0x92, 0xce, 0xcc, 0x97, 0xe9, 0x64, 0x19, 0x1f, 0x4d, 0x57, 0xff, 0x31, 0x71, 0x85, 0x63, 0x45,
+	0xb8, 0x6a, 0x6a, 0xeb, 0xa0, 0xa9, 0xea, 0x1d, 0x75, 0x97, 0x8e, 0x76, 0x03, 0x56, 0x9b, 0xbd,
+	0xfd, 0x46, 0xbb, 0xab, 0xea, 0x5a, 0x7b, 0xef, 0xde, 0x10, 0x4f, 0x36, 0x0f, 0xe9, 0x96, 0x56,
+	0x6f, 0x77, 0x4b, 0x29, 0x34, 0xe6, 0xb3, 0xfc, 0x11, 0x8f, 0x58, 0x72, 0xb5, 0xf4, 0x56, 0xaf,
+	0x79, 0xb0, 0xaf, 0x76, 0x87, 0xa5, 0xb4, 0xf2, 0x1c, 0x94, 0x83, 0x69, 0xa1, 0x35, 0x36, 0x9b,
+	0xa1, 0x77, 0x05, 0xb3, 0x0f, 0xd4, 0x87, 0xa5, 0x2c, 0xc1, 0x28, 0x20, 0x48, 0xc0, 0xe4, 0xe8,
+	0x65, 0x83, 0x61, 0x7d, 0x38, 0x28, 0xe5, 0xe3, 0xda, 0x5a, 0x6a, 0x47, 0x1d, 0xc6, 0xb5, 0x41,
+	0xf5, 0x6f, 0x0b, 0x90, 0xe7, 0x8d, 0x27, 0x0e, 0xac, 0xf7, 0x21, 0xd5, 0x9b, 0x32, 0x9b, 0x7f,
+	0x65, 0xa1, 0xcf, 0x2d, 0xe6, 0x21, 0x16, 0x27, 0xa7, 0xf3, 0xa0, 0x2a, 0xc9, 0x19, 0xd5, 0x3e,
+	0x95, 0x98, 0xc2, 0x22, 0x09, 0xab, 0x16, 0x4c, 0x35, 0xa6, 0x25, 0xbf, 0x71, 0x68, 0x39, 0x4e,
+	0x68, 0x59, 0x6e, 0xc5, 0x0e, 0xa1, 0xfa, 0xe6, 0xb9, 0xfe, 0x5e, 0x58, 0x2b, 0xcc, 0xb7, 0xbe,
+	0xe6, 0xba, 0x7c, 0x17, 0x00, 0x7c, 0xe5, 0x02, 0x80, 0x57, 0x1e, 0xe3, 0x7e, 0x9c, 0x91, 0xc8,
+	0x87, 0x58, 0xc9, 0xa7, 0x08, 0x7a, 0xf2, 0x65, 0x8b, 0xd1, 0x59, 0xe3, 0x93, 0x04, 0xed, 0x58,
+	0x48, 0x26, 0xc5, 0xc5, 0x58, 0xfc, 0x55, 0x7e, 0x1e, 0xd6, 0xc3, 0x06, 0x95, 0x2c, 0x96, 0x93,
+	0xbc, 0x58, 0x5e, 0x0b, 0xc9, 0xfc, 0xfd, 0x95, 0xbf, 0x47, 0xaf, 0x6c, 0xdb, 0xa7, 0x98, 0x35,
+	0x96, 0xc9, 0x24, 0x96, 0xc9, 0x90, 0x17, 0x88, 0x9b, 0x8f, 0x37, 0xbf, 0x31, 0xf9, 0xdd, 0xd0,
+	0x93, 0x16, 0x8e, 0xb1, 0xc9, 0xde, 0x38, 0x2d, 0x21, 0x15, 0xb2, 0x89, 0xfe, 0x38, 0xf5, 0x2d,
+	0x82, 0x5e, 0x5c, 0xa8, 0x2f, 0xc5, 0xf5, 0x85, 0x3d, 0xba, 0x40, 0xe3, 0x1c, 0xab, 0xd4, 0x99,
+	0xe6, 0x3a, 0x23, 0x56, 0xa1, 0xb5, 0xa2, 0x42, 0xb6, 0xef, 0x32, 0x4c, 0x73, 0x8c, 0x5f, 0xcd,
+	0xc2, 0xa4, 0x11, 0x1e, 0x61, 0x58, 0xaf, 0x44, 0x09, 0x25, 0xbc, 0x9a, 0x85, 0x94, 0xca, 0x7f,
+	0x26, 0x03, 0x57, 0x6e, 0xcd, 0xf5, 0x07, 0x45, 0xed, 0xfa, 0x52, 0x0c, 0x08, 0x04, 0x45, 0x5e,
+	0xfb, 0x78, 0xb1, 0x7e, 0x21, 0x0d, 0xe7, 0x5a, 0x85, 0x75, 0xc8, 0x87, 0x2b, 0x95, 0xce, 0xfe,
+	0xe2, 0x72, 0x25, 0x5a, 0xd0, 0xef, 0xe4, 0x3a, 0x22, 0x29, 0xe5, 0x16, 0x56, 0x89, 0x33, 0x7f,
+	0xfa, 0xc4, 0x20, 0x20, 0x39, 0x2a, 0xbf, 0x0a, 0x05, 0x71, 0xb8, 0x62, 0x0f, 0x91, 0x68, 0xe2,
+	0x32, 0x51, 0xba, 0x4f, 0xfa, 0x8e, 0x1f, 0xeb, 0x25, 0xd1, 0x6a, 0x13, 0x5a, 0x91, 0x13, 0x65,
+	0x23, 0x89, 0x3a, 0x38, 0x6b, 0xf3, 0xbb, 0xa5, 0x50, 0x66, 0xd9, 0x4f, 0x7e, 0x85, 0x60, 0x50,
+	0xde, 0x85, 0x8c, 0xb8, 0xab, 0x4a, 0x43, 0xbc, 0xb0, 0xdc, 0x10, 0xb1, 0x0d, 0x68, 0x52, 0x40,
+	0x79, 0x0f, 0xb2, 0xb2, 0x15, 0x2d, 0x8d, 0x70, 0x05, 0xd9, 0x40, 0xa2, 0xf2, 0x0a, 0xac, 0xce,
+	0x19, 0x57, 0x29, 0x43, 0x76, 0x84, 0xe5, 0xa0, 0xcf, 0x84, 0xcf, 0xa5, 0xb4, 0x60, 0x58, 0xfd,
+	0xd7, 0x95, 0x65, 0x71, 0x34, 0x87, 0x01, 0xa7, 0xaf, 0x76, 0x31, 0x84, 0xde, 0x00, 0x45, 0xc3,
+	0xdb, 0x58, 0x4f, 0x53, 0xf5, 0xe6, 0x3d, 0xb5, 0xf9, 0xa0, 0xdf, 0x6b, 0x77, 0x29, 0x92, 0xce,
+	0x45, 0xda, 0xa4, 0x72, 0x1d, 0x36, 0x1a, 0xea, 0x5e, 0xbb, 0xab, 0x63, 0x8e, 0xec, 0x0e, 0x30,
+	0x4d, 0xb6, 0x7b, 0xff, 0x33, 0x11, 0xb5, 0x0c, 0x5b, 0x5d, 0xcc, 0xcc, 0xa1, 0x9c, 0x7e, 0x4f,
+	0xad, 0xb7, 0x54, 0x0d, 0x03, 0x2c, 0xae, 0x7c, 0x7e, 0xa6, 0xd1, 0x6b, 0x3d, 0x2c, 0x81, 0xb2,
+	0x06, 0x80, 0x8b, 0xdc, 0xef, 0xf7, 0x3a, 0xa8, 0xa9, 0x54, 0xa0, 0xa5, 0x47, 0x63, 0x2c, 0x6c,
+	0x3e, 0xe8, 0x3d, 0x50, 0x4b, 0x45, 0x22, 0xef, 0x76, 0x0e, 0x06, 0xf7, 0xe6, 0x76, 0xb4, 0x4a,
+	0xe4, 0xbe, 0xa6, 0xf6, 0xeb, 0x68, 0x8f, 0x61, 0x8f, 0x36, 0xb5, 0xdf, 0x1e, 0x96, 0xd6, 0x94,
+	0x4d, 0x58, 0x6f, 0x76, 0xd4, 0xba, 0x86, 0xfb, 0xd8, 0x6b, 0x0f, 0x86, 0xaa, 0x36, 0x28, 0xad,
+	0x47, 0xd1, 0xbe, 0x54, 0xfd, 0x59, 0x0a, 0x72, 0x54, 0x8c, 0x99, 0x14, 0xce, 0x7f, 0xbc, 0x12,
+	0xaf, 0x92, 0xc2, 0xaf, 0xbd, 0x89, 0xb9, 0xaf, 0xbd, 0x68, 0x9c, 0x3c, 0x06, 0x6e, 0x7e, 0x5f,
+	0x3e, 0x0b, 0x2a, 0x82, 0x90, 0xc0, 0x3f, 0x1c, 0xf2, 0x20, 0x23, 0xaf, 0x10, 0x72, 0xa4, 0xdc,
+	0x86, 0x82, 0x6c, 0xe4, 0xf0, 0xbe, 0x43, 0x8a, 0xdf, 0x5d, 0x36, 0x82, 0x36, 0x4d, 0xf8, 0xc9,
+	0x50, 0x03, 0xc1, 0xc5, 0xef, 0xdc, 0x3b, 0xb0, 0x19, 0xfd, 0x0c, 0x44, 0x1f, 0x33, 0x9b, 0xb9,
+	0x41, 0x7b, 0x2b, 0xa7, 0x29, 0xd1, 0xd4, 0x9e, 0x9c, 0x51, 0x5e, 0x83, 0x18, 0x55, 0x1f, 0x39,
+	0x27, 0x53, 0x4b, 0xf6, 0x5b, 0xb0, 0x2a, 0x8d, 0x66, 0x9a, 0x62, 0x62, 0x81, 0x9d, 0x42, 0x38,
+	0x56, 0xbb, 0xf2, 0x83, 0x67, 0x8c, 0xbd, 0x2f, 0x26, 0xf0, 0x46, 0xb9, 0x81, 0xbe, 0x67, 0xf3,
+	0x8d, 0xea, 0x36, 0xf3, 0x3f, 0x71, 0xdc, 0x47, 0xf2, 0x47, 0x10, 0xa5, 0x70, 0xa2, 0x2b, 0xe8,
+	0x95, 0x5f, 0x83, 0xf4, 0x2e, 0xc3, 0xd2, 0x97, 0x3e, 0x93, 0x86, 0x3d, 0xac, 0x99, 0x2b, 0x7f,
+	0x23, 0x40, 0x97, 0x20, 0xf9, 0x53, 0x0f, 0x77, 0x82, 0x99, 0x6e, 0xfe, 0x4b, 0xea, 0xca, 0x45,
+	0xc6, 0x89, 0x7f, 0x5c, 0xad, 0xfe, 0x55, 0x50, 0x69, 0xe0, 0x71, 0x72, 0x8c, 0x0b, 0xff, 0x40,
+	0xbc, 0x74, 0xd0, 0x3f, 0xe6, 0xd1, 0xb4, 0x42, 0x85, 0x62, 0x0c, 0x4d, 0xbb, 0xea, 0xb0, 0x79,
+	0x0f, 0xdd, 0x63, 0x1b, 0xbe, 0x11, 0xa3, 0x72, 0x2f, 0xab, 0x0f, 0xd1, 0xa3, 0xea, 0xfd, 0x21,
+	0xf9, 0x4f, 0x0a, 0x33, 0xd3, 0x8b, 0xcb, 0x18, 0xf6, 0xf1, 0x8f, 0xd6, 0xc6, 0xd1, 0x77, 0xeb,
+	0x1c, 0x7f, 0x69, 0x7a, 0x73, 0xab, 0x87, 0x6f, 0xca, 0xd0, 0x9b, 0xe8, 0x49, 0xff, 0xb0, 0x3d,
+	0xbc, 0xa7, 0xab, 0x9a, 0xd6, 0x43, 0xcc, 0x71, 0x1f, 0x69, 0xd6, 0x87, 0xf5, 0x4e, 0x6f, 0x4f,
+	0x1f, 0xa0, 0xc3, 0xee, 0xd7, 0x4b, 0x66, 0x75, 0x13, 0x36, 0xf0, 0x5a, 0xcb, 0x7c, 0x8a, 0x05,
+	0x41, 0x7f, 0xa0, 0xba, 0x85, 0x8e, 0x1d, 0x23, 0x8a, 0x5b, 0x6f, 0xf5, 0x2e, 0x28, 0x75, 0xf3,
+	0xd4, 0xb0, 0x11, 0x19, 0xd6, 0x49, 0xd8, 0x4b, 0xc0, 0xbc, 0x69, 0x08, 0x6a, 0x18, 0x2b, 0x45,
+	0x18, 0x59, 0x93, 0x64, 0x19, 0x2d, 0xab, 0xd7, 0x61, 0x73, 0x4e, 0x5c, 0x6a, 0x7d, 0x07, 0x8a,
+	0xf1, 0xf0, 0x48, 0x8d, 0x36, 0xba, 0xf9, 0x49, 0x25, 0xfc, 0x99, 0xff, 0xd8, 0x8a, 0x26, 0xf9,
+	0xa9, 0xa4, 0x34, 0x31, 0xa8, 0x7e, 0x3f, 0x01, 0xab, 0x6d, 0x7b, 0x8c, 0x8b, 0x08, 0xd6, 0xf2,
+	0xb4, 0x5f, 0x96, 0xe3, 0x1e, 0xb6, 0x32, 0xef, 0x61, 0x4b, 0x5a, 0x5b, 0xc9, 0x25, 0xad, 0xad,
+	0xea, 0x4f, 0x56, 0x60, 0x2d, 0x58, 0x84, 0xec, 0x0e, 0xfc, 0x45, 0x02, 0x36, 0xe5, 0xaf, 0x49,
+	0xf4, 0xe8, 0x62, 0x1d, 0x64, 0xcd, 0x57, 0x83, 0x9f, 0x1b, 0xc4, 0x65, 0x82, 0xce, 0xcb, 0x87,
+	0xc1, 0x2d, 0xda, 0x13, 0xdf, 0x8c, 0x7e, 0xe5, 0xfb, 0x5f, 0x3d, 0xd5, 0xcf, 0x58, 0x7e, 0xf0,
+	0xd5, 0x53, 0x5d, 0x8c, 0x37, 0x3e, 0x5e, 0x7c, 0xad, 0xf2, 0xcb, 0x50, 0x0c, 0xd6, 0x8e, 0xae,
+	0x63, 0xca, 0xe4, 0x74, 0x7d, 0xb1, 0x6d, 0x51, 0x53, 0x71, 0x32, 0xf8, 0x66, 0x2d, 0x05, 0x88,
+	0x54, 0x69, 0xc1, 0x8d, 0xe5, 0x7b, 0xb9, 0xac, 0x67, 0x95, 0x8c, 0xf5, 0xac, 0x6e, 0x7d, 0x00,
+	0xc5, 0x78, 0xd3, 0x7f, 0x3e, 0x01, 0x01, 0x64, 0x06, 0xef, 0x77, 0xda, 0x43, 0x72, 0xa9, 0xa8,
+	0x25, 0x98, 0x24, 0x78, 0xd7, 0xdb, 0x5a, 0xe3, 0x21, 0xfa, 0xc6, 0xa0, 0x77, 0xa0, 0x35, 0x55,
+	0x84, 0x3c, 0xa6, 0xa2, 0xdd, 0x4e, 0xef, 0x43, 0x7d, 0xd0, 0xee, 0x3e, 0x28, 0xe5, 0x6e, 0x75,
+	0x61, 0xad, 0xe3, 0x8c, 0x3b, 0xec, 0x94, 0x4d, 0x76, 0xf9, 0x6f, 0x94, 0x94, 0x2c, 0x24, 0x7b,
+	0xbb, 0xbb, 0xa8, 0x15, 0x3d, 0x98, 0x3b, 0x0a, 0xfa, 0x2d, 0xfa, 0xd1, 0x87, 0x75, 0xad, 0x8b,
+	0xea, 0xf1, 0xa9, 0xdd, 0xdd, 0xed, 0xc9, 0xab, 0x80, 0xda, 0x38, 0xd8, 0x43, 0x7f, 0xc4, 0x47,
+	0xba, 0xed, 0xa9, 0xa5, 0xf4, 0xad, 0xdf, 0x80, 0x42, 0x2c, 0x36, 0xe0, 0x16, 0x8b, 0xa1, 0x83,
+	0xf5, 0xd5, 0xa6, 0xb8, 0x14, 0xdf, 0x1f, 0xf4, 0xba, 0x81, 0xbf, 0x25, 0x78, 0x46, 0x79, 0xd8,
+	0x57, 0x07, 0x4d, 0xad, 0xdd, 0x1f, 0xea, 0xfb, 0xbd, 0xd6, 0x41, 0x87, 0xf6, 0x81, 0x7c, 0xdd,
+	0xfe, 0xbe, 0xde, 0xaf, 0x37, 0x1f, 0xd4, 0xf7, 0x28, 0x69, 0xe2, 0xc6, 0x44, 0x0e, 0xc5, 0x17,
+	0xa2, 0x4c, 0x90, 0xa8, 0x06, 0x98, 0xdd, 0x3e, 0xe2, 0x71, 0x21, 0x7d, 0xbb, 0x05, 0x39, 0xd9,
+	0x98, 0x72, 0x95, 0x77, 0xa2, 0x1f, 0xe0, 0x6d, 0xcd, 0x35, 0xb5, 0xa5, 0x6b, 0x54, 0xae, 0x2f,
+	0x50, 0x05, 0xee, 0x5e, 0x4f, 0xdc, 0xfe, 0xeb, 0x04, 0x64, 0xa9, 0xe9, 0x4c, 0xdf, 0x0c, 0xbe,
+	0x03, 0x10, 0xf9, 0xbd, 0xf2, 0x8c, 0x6c, 0xd7, 0x2d, 0x86, 0x87, 0x4a, 0xf9, 0xfc, 0x84, 0x84,
+	0x7e, 0x03, 0x0a, 0x31, 0x1f, 0x57, 0x24, 0xe3, 0xf9, 0xa8, 0x51, 0x79, 0x76, 0xc9, 0x8c, 0xd4,
+	0xf1, 0x06, 0x95, 0xe4, 0xe4, 0x1c, 0xca, 0xe6, 0xbc, 0xab, 0x08, 0xc9, 0xad, 0x65, 0xfe, 0xd3,
+	0x78, 0xef, 0xf3, 0x7f, 0xba, 0x79, 0xed, 0xf3, 0x7f, 0xbe, 0x99, 0xf8, 0x02, 0xff, 0x7d, 0xf6,
+	0x2f, 0x37, 0x13, 0xdf, 0x7d, 0x65, 0x6c, 0xf9, 0xc7, 0xb3, 0x43, 0xac, 0x74, 0x4f, 0x76, 0x90,
+	0x73, 0x66, 0xb8, 0x67, 0xe2, 0xb7, 0xa2, 0xe7, 0x7e, 0x3d, 0x7a, 0x98, 0xe1, 0xe3, 0x37, 0xfe,
+	0x0b, 0x74, 0x98, 0xb0, 0xb3, 0x59, 0x2a, 0x00, 0x00,
 }
 
 func (this *UUIDParts) Equal(that interface{}) bool {
@@ -5505,6 +5515,16 @@ func (m *CombineAPI_Config) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.AssertFormats {
+		i--
+		if m.AssertFormats {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x30
+	}
 	if len(m.UuidPlaceholderPtr) > 0 {
 		i -= len(m.UuidPlaceholderPtr)
 		copy(dAtA[i:], m.UuidPlaceholderPtr)
@@ -7326,6 +7346,9 @@ func (m *CombineAPI_Config) ProtoSize() (n int) {
 	if l > 0 {
 		n += 1 + l + sovFlow(uint64(l))
 	}
+	if m.AssertFormats {
+		n += 2
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -13100,6 +13123,26 @@ func (m *CombineAPI_Config) Unmarshal(dAtA []byte) error {
 			}
 			m.UuidPlaceholderPtr = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
+		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AssertFormats", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowFlow
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.AssertFormats = bool(v != 0)
 		default:
 			iNdEx = preIndex
 			skippy, err := skipFlow(dAtA[iNdEx:])
@@ -562,6 +562,7 @@ func (f *TxnClient) onCommitOps(ops CommitOps) error {
 
 // drainBinding drains the Combiner of the specified materialization
 // binding by sending Store requests for its reduced documents.
+// Reduced deletion tombstones are sent as deletions of their keys.
 func drainBinding(
 	flighted map[string]json.RawMessage,
 	combiner pf.Combiner,
@@ -574,7 +575,7 @@ func drainBinding(
 	var remaining = len(flighted)
 
 	// Drain the combiner into materialization Store requests.
-	var stats, err = combiner.Drain(func(full bool, docRaw json.RawMessage, packedKey, packedValues []byte) error {
+	var stats, err = combiner.Drain(func(full, deleted bool, docRaw json.RawMessage, packedKey, packedValues []byte) error {
 		// Inlined use of string(packedKey) clues compiler escape analysis to avoid allocation.
 		if _, ok := flighted[string(packedKey)]; !ok {
 			var key, _ = tuple.Unpack(packedKey)
@@ -593,14 +594,15 @@ func drainBinding(
 		// document was provided by Loaded or was retained from a previous
 		// transaction's Store.
 
-		if err := StageStore(driverTx, request, binding, packedKey, packedValues, docRaw, full); err != nil {
+		if err := StageStore(driverTx, request, binding, packedKey, packedValues, docRaw, full, deleted); err != nil {
 			return err
 		}
 
 		// We can retain a bounded number of documents from this transaction
 		// as a performance optimization, so that they may be directly available
 		// to the next transaction without issuing a Load.
-		if deltaUpdates || remaining >= cachedDocumentBound {
+		// Deleted documents won't exist in the store, and aren't retained.
+		if deltaUpdates || deleted || remaining >= cachedDocumentBound {
 			delete(flighted, string(packedKey)) // Don't retain.
 		} else {
 			// We cannot reference |rawDoc| beyond this callback, and must copy.
@@ -238,6 +238,8 @@ func (m *TransactionRequest_Store) Validate() error {
 		return pb.NewValidationError("expected PackedValues length to match DocsJson: %d vs %d", ll, lr)
 	} else if lr := len(m.Exists); ll != lr {
 		return pb.NewValidationError("expected Exists length to match DocsJson: %d vs %d", ll, lr)
+	} else if lr := len(m.Delete); lr != 0 && ll != lr {
+		return pb.NewValidationError("expected Delete length to match DocsJson: %d vs %d", ll, lr)
 	}
 	return nil
 }
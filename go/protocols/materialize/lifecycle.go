@@ -164,6 +164,7 @@ func StageStore(
 	packedValues []byte,
 	doc json.RawMessage,
 	exists bool,
+	deleted bool,
 ) error {
 	// Send current |request| if we would re-allocate.
 	if *request != nil {
@@ -198,6 +199,7 @@ func StageStore(
 	s.PackedValues = append(s.PackedValues, s.Arena.Add(packedValues))
 	s.DocsJson = append(s.DocsJson, s.Arena.Add(doc))
 	s.Exists = append(s.Exists, exists)
+	s.Delete = append(s.Delete, deleted)
 
 	return nil
 }
@@ -405,6 +407,7 @@ type StoreIterator struct {
 	Values  tuple.Tuple     // Values of the next document to store.
 	RawJSON json.RawMessage // Document to store.
 	Exists  bool            // Does this document exist in the store already?
+	Delete  bool            // Is this document a tombstone, to be deleted from the store?
 
 	stream interface {
 		Context() context.Context
@@ -477,6 +480,7 @@ func (it *StoreIterator) Next() bool {
 	}
 	it.RawJSON = s.Arena.Bytes(s.DocsJson[it.index])
 	it.Exists = s.Exists[it.index]
+	it.Delete = len(s.Delete) != 0 && s.Delete[it.index]
 
 	it.index++
 	it.total++
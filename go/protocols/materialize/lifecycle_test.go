@@ -58,11 +58,11 @@ func TestStreamLifecycle(t *testing.T) {
 
 	// Runtime sends Store, then Commit.
 	require.NoError(t, StageStore(sendFn, &staged,
-		0, tuple.Tuple{"key-1"}.Pack(), tuple.Tuple{false}.Pack(), []byte(`doc-1`), true))
+		0, tuple.Tuple{"key-1"}.Pack(), tuple.Tuple{false}.Pack(), []byte(`doc-1`), true, false))
 	require.NoError(t, StageStore(sendFn, &staged,
-		0, tuple.Tuple{"key", 2}.Pack(), tuple.Tuple{"two"}.Pack(), []byte(`doc-2`), false))
+		0, tuple.Tuple{"key", 2}.Pack(), tuple.Tuple{"two"}.Pack(), []byte(`doc-2`), false, false))
 	require.NoError(t, StageStore(sendFn, &staged,
-		1, tuple.Tuple{"three"}.Pack(), tuple.Tuple{true}.Pack(), []byte(`doc-3`), true))
+		1, tuple.Tuple{"three"}.Pack(), tuple.Tuple{true}.Pack(), []byte(`doc-3`), true, true))
 	require.NoError(t, WriteCommit(sendFn, &staged))
 
 	// Driver reads stores.
@@ -73,6 +73,7 @@ func TestStreamLifecycle(t *testing.T) {
 	require.Equal(t, tuple.Tuple{false}, sit.Values)
 	require.Equal(t, []byte(`doc-1`), []byte(sit.RawJSON))
 	require.Equal(t, true, sit.Exists)
+	require.Equal(t, false, sit.Delete)
 
 	require.True(t, sit.Next())
 	require.Equal(t, 0, sit.Binding)
@@ -80,6 +81,7 @@ func TestStreamLifecycle(t *testing.T) {
 	require.Equal(t, tuple.Tuple{"two"}, sit.Values)
 	require.Equal(t, []byte(`doc-2`), []byte(sit.RawJSON))
 	require.Equal(t, false, sit.Exists)
+	require.Equal(t, false, sit.Delete)
 
 	require.True(t, sit.Next())
 	require.Equal(t, 1, sit.Binding)
@@ -87,6 +89,7 @@ func TestStreamLifecycle(t *testing.T) {
 	require.Equal(t, tuple.Tuple{true}, sit.Values)
 	require.Equal(t, []byte(`doc-3`), []byte(sit.RawJSON))
 	require.Equal(t, true, sit.Exists)
+	require.Equal(t, true, sit.Delete)
 
 	require.False(t, sit.Next())
 	require.Nil(t, sit.Err())
@@ -728,7 +728,11 @@ type TransactionRequest_Store struct {
 	// JSON documents.
 	DocsJson []flow.Slice `protobuf:"bytes,5,rep,name=docs_json,json=docsJson,proto3" json:"docs_json"`
 	// Exists is true if this document as previously been loaded or stored.
-	Exists               []bool   `protobuf:"varint,6,rep,packed,name=exists,proto3" json:"exists,omitempty"`
+	Exists []bool `protobuf:"varint,6,rep,packed,name=exists,proto3" json:"exists,omitempty"`
+	// Delete is true if this document is a deletion tombstone of its key,
+	// which should be removed from the store rather than updated.
+	// If empty, no documents are deletions.
+	Delete               []bool   `protobuf:"varint,7,rep,packed,name=delete,proto3" json:"delete,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -1120,97 +1124,97 @@ func init() {
 }
 
 var fileDescriptor_3e8b62b327f34bc6 = []byte{
-	// 1440 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcc, 0x57, 0xcd, 0x6f, 0x1b, 0x45,
-	0x1b, 0xef, 0xda, 0x8e, 0x63, 0x3f, 0x6b, 0x27, 0xce, 0x34, 0x6f, 0xeb, 0xee, 0x9b, 0x37, 0x89,
-	0xfc, 0x52, 0x1a, 0x51, 0xd5, 0x09, 0xae, 0x54, 0xfa, 0x21, 0x0a, 0xfe, 0x0a, 0x32, 0x24, 0x71,
-	0x3b, 0x4e, 0x0a, 0x82, 0x83, 0xb5, 0xd9, 0x9d, 0x38, 0x8b, 0x37, 0xbb, 0xcb, 0xce, 0xba, 0xc5,
-	0x5c, 0xb8, 0x15, 0x89, 0x03, 0x07, 0x2e, 0x9c, 0x10, 0xfd, 0x73, 0x7a, 0x44, 0xfc, 0x01, 0x01,
-	0x8a, 0x38, 0x71, 0xea, 0xb5, 0x27, 0x34, 0x33, 0xbb, 0xf6, 0x38, 0x71, 0x63, 0x03, 0x02, 0x71,
-	0x89, 0x3c, 0xcf, 0xfc, 0x7e, 0xbf, 0x9d, 0xe7, 0x73, 0x26, 0xf0, 0x5a, 0xc7, 0x5d, 0xf7, 0x7c,
-	0x37, 0x70, 0x0d, 0xd7, 0xa6, 0xeb, 0x47, 0x7a, 0x40, 0x7c, 0x4b, 0xb7, 0xad, 0xcf, 0x88, 0xfc,
-	0xbb, 0xc8, 0x11, 0x48, 0x95, 0x4c, 0xda, 0xd2, 0x08, 0xf1, 0xc0, 0x76, 0x1f, 0xf1, 0x3f, 0x02,
-	0xaa, 0x2d, 0x76, 0xdc, 0x8e, 0xcb, 0x7f, 0xae, 0xb3, 0x5f, 0xc2, 0x5a, 0xf8, 0x49, 0x01, 0xa8,
-	0xba, 0x0e, 0x0d, 0x7c, 0xdd, 0x72, 0x02, 0xb4, 0x01, 0x89, 0xa0, 0xef, 0x91, 0x7c, 0x6c, 0x55,
-	0x59, 0x9b, 0x2b, 0x2d, 0x15, 0xe5, 0x2f, 0x0e, 0x61, 0xc5, 0xdd, 0xbe, 0x47, 0x30, 0x47, 0xa2,
-	0x0b, 0x90, 0xf4, 0x89, 0x4e, 0x5d, 0x27, 0x1f, 0x5f, 0x55, 0xd6, 0xd2, 0x38, 0x5c, 0x15, 0xbe,
-	0x50, 0x20, 0xc1, 0x60, 0x08, 0xc1, 0xdc, 0x66, 0xa3, 0xbe, 0x55, 0x6b, 0xe3, 0xfa, 0xfd, 0xbd,
-	0x06, 0xae, 0xd7, 0x72, 0xe7, 0xd0, 0x7f, 0x60, 0x61, 0xab, 0x59, 0x2d, 0xef, 0x36, 0x9a, 0x3b,
-	0x43, 0xb3, 0x82, 0xf2, 0xb0, 0x28, 0x99, 0xab, 0xcd, 0xed, 0xed, 0xfa, 0x4e, 0xad, 0x5e, 0xcb,
-	0xc5, 0x86, 0x22, 0xcd, 0x7b, 0x6c, 0xb7, 0xbc, 0x95, 0x8b, 0xa3, 0xf3, 0x30, 0x2f, 0x6c, 0x9b,
-	0x4d, 0x5c, 0x69, 0xd4, 0x6a, 0xf5, 0x9d, 0x5c, 0x02, 0x2d, 0x40, 0x76, 0x6f, 0xa7, 0x55, 0xde,
-	0x6d, 0xb4, 0x36, 0x1b, 0xe5, 0xca, 0x56, 0x3d, 0x37, 0x53, 0xf8, 0x4a, 0x01, 0xb5, 0xe5, 0x11,
-	0x03, 0x93, 0x4f, 0x7a, 0x84, 0x06, 0xe8, 0x0d, 0xc8, 0x12, 0xc7, 0xf4, 0x5c, 0xcb, 0x09, 0xda,
-	0xdc, 0x59, 0x85, 0x3b, 0x8b, 0x8a, 0x3c, 0x58, 0xf5, 0x70, 0x8b, 0xbb, 0x98, 0x21, 0xd2, 0x0a,
-	0x6d, 0x02, 0x1a, 0x10, 0xa9, 0x47, 0x8c, 0xf6, 0xc7, 0xcc, 0x6d, 0x16, 0xaa, 0x74, 0x65, 0xe9,
-	0xc5, 0xf1, 0x4a, 0x9e, 0x38, 0x86, 0x6b, 0x5a, 0x4e, 0x67, 0x9d, 0x6d, 0x14, 0xb1, 0xfe, 0x68,
-	0x9b, 0x50, 0xaa, 0x77, 0x24, 0x1d, 0x76, 0x8e, 0xc2, 0xaf, 0x0a, 0x64, 0xc4, 0x81, 0xa8, 0xe7,
-	0x3a, 0x94, 0xa0, 0x16, 0x5c, 0x1a, 0x15, 0xa6, 0xc6, 0x21, 0x39, 0xd2, 0x85, 0xbe, 0x32, 0x85,
-	0x3e, 0x92, 0xf5, 0x5b, 0x9c, 0xcc, 0x44, 0x7d, 0x42, 0xdd, 0x9e, 0x6f, 0x90, 0xd3, 0xa2, 0xd3,
-	0x1c, 0x1a, 0x45, 0x74, 0x49, 0xf4, 0x2a, 0x2c, 0x98, 0xae, 0xd1, 0x3b, 0x22, 0x4e, 0xa0, 0x07,
-	0x96, 0xeb, 0xb4, 0x7b, 0xbe, 0x1d, 0x26, 0x3e, 0x37, 0xb2, 0xb1, 0xe7, 0xdb, 0x85, 0xaf, 0x67,
-	0x60, 0xfe, 0x81, 0x6e, 0x5b, 0xa6, 0x1e, 0x90, 0x28, 0xf8, 0x1d, 0x98, 0x1f, 0xd6, 0x14, 0x47,
-	0x86, 0x0e, 0xbe, 0xf9, 0xe2, 0x78, 0xe5, 0x56, 0xc7, 0x0a, 0x0e, 0x7b, 0xfb, 0x45, 0xc3, 0x3d,
-	0x5a, 0x27, 0x34, 0xe8, 0xe9, 0x7e, 0x5f, 0x94, 0xf1, 0xa9, 0xc2, 0x2e, 0x6e, 0x8f, 0x8a, 0xe0,
-	0x93, 0xaa, 0xa7, 0xb3, 0x1c, 0xfb, 0x4b, 0x59, 0x8e, 0xff, 0xd1, 0x2c, 0xa3, 0xb7, 0x21, 0xb5,
-	0x6f, 0x39, 0x0c, 0x48, 0xf3, 0x89, 0xd5, 0xf8, 0x9a, 0x5a, 0x7a, 0x65, 0xa4, 0x9d, 0x4e, 0x44,
-	0xa6, 0x58, 0x11, 0x60, 0x3c, 0x60, 0x69, 0x3f, 0xc4, 0x60, 0x36, 0xb4, 0xb2, 0x53, 0x8d, 0x66,
-	0x73, 0xea, 0xda, 0xc8, 0xc8, 0x69, 0x44, 0xb7, 0x01, 0x0c, 0xd7, 0xb6, 0x89, 0xc1, 0x43, 0xcf,
-	0x62, 0xa2, 0x96, 0x16, 0x45, 0x4c, 0xaa, 0x03, 0x3b, 0x43, 0x56, 0x12, 0x4f, 0x8f, 0x57, 0xce,
-	0x61, 0x09, 0x8d, 0x3e, 0x87, 0x85, 0x03, 0x8b, 0xd8, 0x66, 0xdb, 0x70, 0x9d, 0x03, 0xab, 0x13,
-	0x05, 0x86, 0xb9, 0x76, 0x6b, 0x1a, 0xd7, 0x8a, 0x9b, 0x8c, 0x5d, 0xe5, 0xe4, 0x77, 0xa9, 0xeb,
-	0xd4, 0x9d, 0xc0, 0xef, 0x57, 0x96, 0xbe, 0xfc, 0xf1, 0x8c, 0xd3, 0xab, 0x07, 0x43, 0x8e, 0x56,
-	0x81, 0xc5, 0x71, 0x12, 0x28, 0x07, 0xf1, 0x2e, 0xe9, 0x8b, 0x68, 0x60, 0xf6, 0x13, 0x2d, 0xc2,
-	0xcc, 0x43, 0xdd, 0xee, 0x89, 0xac, 0xa7, 0xb1, 0x58, 0xdc, 0x8e, 0xdd, 0x54, 0x0a, 0xc7, 0x31,
-	0xc8, 0x0d, 0xcf, 0x17, 0x36, 0x60, 0x59, 0xca, 0x95, 0xc2, 0x1d, 0xba, 0xfc, 0x12, 0x87, 0x04,
-	0x61, 0x4c, 0xb2, 0x1e, 0x4b, 0xc9, 0xfa, 0x00, 0x54, 0x63, 0x30, 0x2c, 0x23, 0xc5, 0x1b, 0x53,
-	0x29, 0x4a, 0x53, 0x96, 0x72, 0xe7, 0xb0, 0x2c, 0x85, 0xfe, 0x0f, 0xd9, 0x41, 0x19, 0x78, 0x7a,
-	0x70, 0x98, 0x8f, 0xad, 0xc6, 0xd7, 0xd2, 0xc3, 0x1c, 0xdf, 0xd3, 0x83, 0x43, 0x06, 0x32, 0x89,
-	0x1d, 0xe8, 0xed, 0x9e, 0xc7, 0x3e, 0x41, 0x79, 0xf1, 0xa6, 0x70, 0x86, 0x1b, 0xf7, 0x84, 0x4d,
-	0x7b, 0x1f, 0x72, 0x27, 0x3f, 0x35, 0x26, 0x8e, 0xd7, 0xe4, 0x38, 0xaa, 0xa5, 0x8b, 0x2f, 0xb9,
-	0x10, 0xe4, 0x00, 0x3f, 0x56, 0x20, 0x53, 0xf6, 0x3c, 0xbb, 0x1f, 0xb5, 0x7c, 0x75, 0x7c, 0xcb,
-	0xab, 0xa5, 0x4b, 0xc5, 0x71, 0xad, 0xcc, 0x27, 0xe3, 0xa9, 0x76, 0xce, 0xc3, 0xec, 0x43, 0xe2,
-	0xd3, 0xa8, 0x68, 0xd3, 0x38, 0x5a, 0xa2, 0x8b, 0x30, 0x6b, 0xfa, 0xfd, 0xb6, 0xdf, 0x73, 0x42,
-	0x3f, 0x93, 0xa6, 0xdf, 0xc7, 0x3d, 0xa7, 0x70, 0x17, 0xb2, 0xe1, 0x39, 0xc2, 0x2c, 0x5f, 0x03,
-	0xa4, 0xf3, 0x4a, 0x6e, 0x9b, 0x84, 0x1a, 0xbe, 0xe5, 0x0d, 0xc7, 0x0f, 0x5e, 0x10, 0x3b, 0xb5,
-	0xe1, 0x46, 0xe1, 0x9b, 0x34, 0xa0, 0x5d, 0x5f, 0x77, 0xa8, 0xd8, 0x8a, 0xdc, 0xb9, 0x09, 0x09,
-	0xd7, 0x23, 0x91, 0x0f, 0xa3, 0x3d, 0x7d, 0x1a, 0x5e, 0x6c, 0x7a, 0xc4, 0xc1, 0x9c, 0xc1, 0x98,
-	0xb6, 0xab, 0x9b, 0x61, 0x2c, 0x27, 0x32, 0xb7, 0x5c, 0xdd, 0xc4, 0x9c, 0x81, 0xca, 0x30, 0xeb,
-	0xf9, 0xc4, 0xd3, 0x7d, 0xc2, 0x7d, 0x54, 0x4b, 0x57, 0x26, 0x91, 0xef, 0x09, 0x38, 0x8e, 0x78,
-	0xe8, 0x0e, 0xcc, 0xd0, 0xc0, 0xf5, 0x49, 0x3e, 0xc1, 0x05, 0x2e, 0x4f, 0x12, 0x68, 0x31, 0x30,
-	0x16, 0x1c, 0x74, 0x17, 0x92, 0x86, 0x7b, 0x74, 0x64, 0x05, 0xf9, 0x19, 0xce, 0x7e, 0x75, 0x12,
-	0xbb, 0xca, 0xd1, 0x38, 0x64, 0xa1, 0x6d, 0x50, 0x75, 0xa3, 0xeb, 0xb8, 0x8f, 0x6c, 0x62, 0x76,
-	0x48, 0x3e, 0xc9, 0x45, 0xae, 0x4e, 0x12, 0x29, 0x0f, 0x29, 0x58, 0xe6, 0x6b, 0xbf, 0x29, 0x90,
-	0x60, 0x71, 0xfd, 0xbb, 0x4b, 0xeb, 0xbf, 0x90, 0xee, 0x92, 0x7e, 0x7b, 0x9f, 0x74, 0x2c, 0x51,
-	0x5c, 0xb3, 0x38, 0xd5, 0x25, 0xfd, 0x0a, 0x5b, 0xb3, 0xba, 0x63, 0x9b, 0xc4, 0x31, 0x79, 0x48,
-	0x67, 0x71, 0xb2, 0x4b, 0xfa, 0x75, 0xc7, 0x44, 0x3b, 0x70, 0xc1, 0xf4, 0xad, 0x87, 0xc4, 0x6f,
-	0x1b, 0x87, 0xc4, 0xe8, 0x8a, 0x9b, 0x84, 0xcf, 0x4a, 0x16, 0xbc, 0xcc, 0x84, 0x71, 0x9d, 0x13,
-	0xdc, 0xea, 0x80, 0xaa, 0x7d, 0xa7, 0x40, 0x82, 0xd5, 0x02, 0x3b, 0x68, 0x38, 0x6e, 0xb8, 0x97,
-	0x59, 0x1c, 0x2d, 0x51, 0x03, 0x66, 0x74, 0x9f, 0x38, 0x3a, 0x77, 0x20, 0x53, 0xb9, 0xfe, 0xe2,
-	0x78, 0x65, 0x7d, 0xfa, 0xbb, 0xb4, 0xcc, 0xa8, 0x58, 0x28, 0xa0, 0x12, 0xa8, 0x9e, 0x6e, 0x74,
-	0x89, 0xd9, 0xee, 0x92, 0x3e, 0x0d, 0xc7, 0xbb, 0x2a, 0xc2, 0xd9, 0xb2, 0x2d, 0x83, 0x44, 0x17,
-	0x83, 0x40, 0xbd, 0x47, 0xfa, 0x54, 0x2b, 0xc1, 0x6c, 0x58, 0x6f, 0xe8, 0x0a, 0xcc, 0x33, 0xa8,
-	0xe4, 0x3a, 0x3f, 0x6b, 0x06, 0xcf, 0x31, 0xb3, 0xe4, 0xd5, 0x93, 0x18, 0xcc, 0xf0, 0x1a, 0xfb,
-	0xd7, 0xba, 0x85, 0x6e, 0x40, 0x36, 0xe4, 0xf0, 0xe9, 0x16, 0x5d, 0xe3, 0x63, 0x58, 0x19, 0x81,
-	0x7b, 0xc0, 0x61, 0xa8, 0x08, 0x69, 0xd3, 0x35, 0x68, 0x94, 0xf3, 0x97, 0x70, 0x52, 0x0c, 0xc3,
-	0xee, 0x30, 0xf6, 0x84, 0x26, 0x9f, 0x5a, 0x34, 0xa0, 0xf9, 0xe4, 0x6a, 0x9c, 0x0d, 0x30, 0xb1,
-	0xd2, 0x52, 0x90, 0x14, 0x7d, 0xa4, 0x65, 0x41, 0x95, 0x9a, 0xa1, 0xf0, 0x3c, 0x01, 0xe7, 0x47,
-	0xfa, 0x25, 0x1c, 0x70, 0x6f, 0x41, 0x92, 0x0d, 0x1a, 0x62, 0x86, 0x5d, 0x70, 0xc6, 0x94, 0x08,
-	0x6f, 0x9d, 0x26, 0x87, 0xe3, 0x90, 0xc6, 0x04, 0xd8, 0xbc, 0x21, 0xd1, 0x8c, 0x9a, 0x2c, 0xb0,
-	0xc5, 0xe1, 0x38, 0xa4, 0xa1, 0x12, 0xa4, 0xc2, 0x81, 0x63, 0x86, 0x93, 0xea, 0x82, 0xf0, 0xbc,
-	0x76, 0xa2, 0xaa, 0xf1, 0x00, 0x87, 0x3e, 0x82, 0x5c, 0xd4, 0x2f, 0xdc, 0xdb, 0x80, 0x98, 0xe1,
-	0x90, 0xda, 0x98, 0xf8, 0xf9, 0x50, 0x36, 0xe2, 0xe1, 0x79, 0x73, 0xd4, 0x80, 0xee, 0x43, 0x46,
-	0x9a, 0x1c, 0x66, 0x38, 0xbf, 0xae, 0x4d, 0x14, 0x96, 0xc2, 0x6d, 0xe2, 0x11, 0x09, 0xed, 0x75,
-	0x48, 0x8a, 0xb0, 0x4d, 0x5f, 0xec, 0xdf, 0x2a, 0x90, 0x14, 0x91, 0xfa, 0x67, 0xaa, 0x7d, 0xa4,
-	0x02, 0xe3, 0x13, 0x2b, 0x50, 0x5b, 0x80, 0xf9, 0x13, 0x91, 0xd4, 0xe6, 0x20, 0x23, 0xc7, 0xa0,
-	0xf4, 0x3c, 0x06, 0x49, 0x81, 0x41, 0x77, 0x20, 0xc1, 0xdf, 0x92, 0xf9, 0x91, 0x28, 0x4a, 0xff,
-	0x62, 0x69, 0x97, 0xc6, 0xec, 0x84, 0x35, 0xfa, 0x0e, 0xa4, 0xa2, 0xb7, 0x0f, 0x5a, 0x3a, 0xeb,
-	0xd5, 0xa8, 0xfd, 0xef, 0xcc, 0x07, 0x13, 0xaa, 0x81, 0xca, 0xaf, 0xf7, 0x3d, 0x8f, 0x12, 0x3f,
-	0x40, 0xa3, 0x9f, 0x94, 0x1f, 0x20, 0x9a, 0x36, 0x6e, 0xeb, 0x84, 0x4a, 0x8d, 0xd8, 0x24, 0x20,
-	0x7f, 0x56, 0x65, 0x0f, 0x32, 0x52, 0x11, 0x51, 0xb4, 0x32, 0xe1, 0x6a, 0xd3, 0x56, 0x27, 0x15,
-	0xe0, 0x9a, 0xb2, 0xa1, 0x54, 0x2a, 0x4f, 0x7f, 0x5e, 0x3e, 0xf7, 0xf4, 0xd9, 0xb2, 0xf2, 0xfd,
-	0xb3, 0x65, 0xe5, 0xc9, 0x2f, 0xcb, 0xca, 0x87, 0x1b, 0x53, 0x15, 0x84, 0xa4, 0xbd, 0x9f, 0xe4,
-	0xe6, 0xeb, 0xbf, 0x07, 0x00, 0x00, 0xff, 0xff, 0xd3, 0xa1, 0x3a, 0x20, 0x56, 0x10, 0x00, 0x00,
+	// 1432 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcd, 0x57, 0x4d, 0x73, 0xdb, 0x44,
+	0x18, 0xae, 0xfc, 0xed, 0xd7, 0x76, 0xe2, 0x6c, 0x43, 0xeb, 0x8a, 0xd0, 0x76, 0x0c, 0xa5, 0x19,
+	0x3a, 0x71, 0x82, 0x3b, 0x53, 0xda, 0x32, 0x14, 0xfc, 0x15, 0x26, 0x90, 0xc4, 0xed, 0xba, 0x29,
+	0x0c, 0x1c, 0x3c, 0x8a, 0xb4, 0x71, 0x45, 0x15, 0x49, 0x48, 0x72, 0x4b, 0xb8, 0x70, 0x2b, 0x33,
+	0x1c, 0x38, 0x70, 0x67, 0xe0, 0x1f, 0x70, 0xe5, 0x27, 0xf4, 0xc8, 0xf0, 0x03, 0x4a, 0x81, 0xe1,
+	0xc4, 0xa9, 0x57, 0x4e, 0xec, 0x87, 0x64, 0xaf, 0x12, 0x37, 0x36, 0x30, 0x30, 0x1c, 0xda, 0xf1,
+	0xbe, 0xfb, 0x3c, 0x8f, 0xf6, 0x7d, 0xf7, 0xfd, 0xd8, 0xc0, 0x2b, 0x03, 0x67, 0xd5, 0xf5, 0x9c,
+	0xc0, 0xd1, 0x1d, 0xcb, 0x5f, 0xdd, 0xd7, 0x02, 0xe2, 0x99, 0x9a, 0x65, 0x7e, 0x4a, 0xe4, 0xdf,
+	0x35, 0x8e, 0x40, 0x05, 0xc9, 0xa4, 0x2e, 0xc5, 0x88, 0x7b, 0x96, 0xf3, 0x80, 0xff, 0x27, 0xa0,
+	0xea, 0xe2, 0xc0, 0x19, 0x38, 0xfc, 0xe7, 0x2a, 0xfb, 0x25, 0xac, 0xd5, 0x27, 0x0a, 0x40, 0xcb,
+	0xb1, 0xfd, 0xc0, 0xd3, 0x4c, 0x3b, 0x40, 0x6b, 0x90, 0x0a, 0x0e, 0x5c, 0x52, 0x49, 0x9c, 0x57,
+	0x96, 0xe7, 0xea, 0x4b, 0x35, 0xf9, 0x8b, 0x63, 0x58, 0xed, 0x36, 0xc5, 0x60, 0x8e, 0x44, 0xa7,
+	0x20, 0xe3, 0x11, 0xcd, 0x77, 0xec, 0x4a, 0x92, 0x72, 0xf2, 0x38, 0x5c, 0x55, 0x3f, 0x57, 0x20,
+	0xc5, 0x60, 0x08, 0xc1, 0xdc, 0xfa, 0x46, 0x67, 0xb3, 0xdd, 0xc7, 0x9d, 0x5b, 0x3b, 0x1b, 0xb8,
+	0xd3, 0x2e, 0x9f, 0x40, 0xcf, 0xc1, 0xc2, 0x66, 0xb7, 0xd5, 0xb8, 0xbd, 0xd1, 0xdd, 0x1e, 0x9b,
+	0x15, 0x54, 0x81, 0x45, 0xc9, 0xdc, 0xea, 0x6e, 0x6d, 0x75, 0xb6, 0xdb, 0x74, 0x27, 0x31, 0x16,
+	0xe9, 0xde, 0x64, 0xbb, 0x8d, 0xcd, 0x72, 0x12, 0x9d, 0x84, 0x79, 0x61, 0x5b, 0xef, 0xe2, 0xe6,
+	0x46, 0xbb, 0xdd, 0xd9, 0x2e, 0xa7, 0xd0, 0x02, 0x94, 0x76, 0xb6, 0x7b, 0x54, 0xa2, 0xb7, 0xbe,
+	0xd1, 0x68, 0x6e, 0x76, 0xca, 0xe9, 0xea, 0x97, 0x0a, 0x14, 0x7a, 0x2e, 0xd1, 0x31, 0xf9, 0x78,
+	0x48, 0xfc, 0x00, 0xbd, 0x06, 0x25, 0x62, 0x1b, 0xae, 0x43, 0x1d, 0xe9, 0x73, 0x67, 0x15, 0xee,
+	0x2c, 0xaa, 0xf1, 0x60, 0x75, 0xc2, 0x2d, 0xee, 0x62, 0x91, 0x48, 0x2b, 0xb4, 0x0e, 0x68, 0x44,
+	0xf4, 0xa9, 0x60, 0xff, 0x23, 0xe6, 0x36, 0x0b, 0x55, 0xbe, 0xb9, 0xf4, 0xc7, 0xe3, 0x73, 0x15,
+	0x62, 0xeb, 0x8e, 0x61, 0xda, 0x83, 0x55, 0xb6, 0x51, 0xc3, 0xda, 0x83, 0x2d, 0xe2, 0xfb, 0xda,
+	0x40, 0xd2, 0x61, 0xe7, 0xa8, 0xfe, 0xa6, 0x40, 0x51, 0x1c, 0xc8, 0x77, 0x69, 0x4c, 0x09, 0xea,
+	0xc1, 0x99, 0xb8, 0xb0, 0xaf, 0xdf, 0x25, 0xfb, 0x9a, 0xd0, 0x57, 0x66, 0xd0, 0x47, 0xb2, 0x7e,
+	0x8f, 0x93, 0x99, 0xa8, 0x47, 0x7c, 0x67, 0xe8, 0xe9, 0xe4, 0xa8, 0xe8, 0x2c, 0x87, 0x46, 0x11,
+	0x5d, 0x12, 0xbd, 0x04, 0x0b, 0x86, 0xa3, 0x0f, 0xf7, 0x89, 0x1d, 0x68, 0x81, 0xe9, 0xd8, 0xfd,
+	0xa1, 0x67, 0x85, 0x17, 0x5f, 0x8e, 0x6d, 0xec, 0x78, 0x56, 0xf5, 0xab, 0x34, 0xcc, 0xdf, 0xa1,
+	0xb9, 0x63, 0xd0, 0x24, 0x8a, 0x82, 0x3f, 0x80, 0xf9, 0x71, 0x4e, 0x71, 0x64, 0xe8, 0xe0, 0x1b,
+	0xf4, 0x2c, 0xd7, 0x06, 0x66, 0x70, 0x77, 0xb8, 0x5b, 0xd3, 0x9d, 0xfd, 0x55, 0x0a, 0x1e, 0x6a,
+	0xde, 0x81, 0x48, 0xe3, 0x23, 0x89, 0x5d, 0xdb, 0x8a, 0x8b, 0xe0, 0xc3, 0xaa, 0x47, 0x6f, 0x39,
+	0xf1, 0x8f, 0x6e, 0x39, 0xf9, 0x57, 0x6f, 0x19, 0xbd, 0x05, 0xb9, 0x5d, 0xd3, 0x66, 0x40, 0xbf,
+	0x92, 0x3a, 0x9f, 0x5c, 0x2e, 0xd4, 0x5f, 0x8a, 0x95, 0xd3, 0xa1, 0xc8, 0xd4, 0x9a, 0x02, 0x8c,
+	0x47, 0x2c, 0xf5, 0xc7, 0x04, 0x64, 0x43, 0x2b, 0x3b, 0x55, 0xfc, 0x36, 0x67, 0xce, 0x8d, 0xa2,
+	0x7c, 0x8d, 0xe8, 0x3a, 0x00, 0x0d, 0xa2, 0x45, 0x74, 0x1e, 0x7a, 0x16, 0x93, 0x42, 0x7d, 0x51,
+	0xc4, 0xa4, 0x35, 0xb2, 0x33, 0x64, 0x33, 0xf5, 0xe8, 0xf1, 0xb9, 0x13, 0x58, 0x42, 0xa3, 0xcf,
+	0x60, 0x61, 0xcf, 0x24, 0x96, 0xd1, 0xd7, 0x1d, 0x7b, 0xcf, 0x1c, 0x44, 0x81, 0x61, 0xae, 0x5d,
+	0x9b, 0xc5, 0xb5, 0xda, 0x3a, 0x63, 0xb7, 0x38, 0xf9, 0x1d, 0xca, 0xed, 0xd8, 0x81, 0x77, 0xd0,
+	0x5c, 0xfa, 0xe2, 0xa7, 0x63, 0x4e, 0x5f, 0xd8, 0x1b, 0x73, 0xd4, 0x26, 0x2c, 0x4e, 0x92, 0x40,
+	0x65, 0x48, 0xde, 0x23, 0x07, 0x22, 0x1a, 0x98, 0xfd, 0x44, 0x8b, 0x90, 0xbe, 0xaf, 0x59, 0x43,
+	0x71, 0xeb, 0x79, 0x2c, 0x16, 0xd7, 0x13, 0x57, 0x95, 0xea, 0xe3, 0x04, 0x94, 0xc7, 0xe7, 0x0b,
+	0x0b, 0xb0, 0x21, 0xdd, 0x95, 0xc2, 0x1d, 0xba, 0xf0, 0x0c, 0x87, 0x04, 0x61, 0xc2, 0x65, 0x3d,
+	0x94, 0x2e, 0xeb, 0x7d, 0x28, 0xe8, 0xa3, 0x66, 0x19, 0x29, 0x5e, 0x99, 0x49, 0x51, 0xea, 0xb2,
+	0x3e, 0x77, 0x0e, 0xcb, 0x52, 0xe8, 0x45, 0x28, 0x8d, 0xd2, 0xc0, 0xd5, 0x82, 0xbb, 0xd4, 0xbf,
+	0x24, 0xf5, 0x6f, 0x74, 0xc7, 0x37, 0xa9, 0x8d, 0x81, 0x0c, 0x62, 0x05, 0x5a, 0x7f, 0xe8, 0xb2,
+	0x4f, 0xf8, 0x3c, 0x79, 0x73, 0xb8, 0xc8, 0x8d, 0x3b, 0xc2, 0xa6, 0xbe, 0x07, 0xe5, 0xc3, 0x9f,
+	0x9a, 0x10, 0xc7, 0x15, 0x39, 0x8e, 0x85, 0xfa, 0xe9, 0x67, 0x0c, 0x04, 0x39, 0xc0, 0x0f, 0x69,
+	0x77, 0x6b, 0xb8, 0xae, 0x75, 0x10, 0x95, 0x7c, 0x6b, 0x72, 0xc9, 0x17, 0xea, 0x67, 0x6a, 0x93,
+	0x4a, 0x99, 0x77, 0xc6, 0x23, 0xe5, 0x5c, 0x81, 0xec, 0x7d, 0xe2, 0xf9, 0x51, 0xd2, 0xe6, 0x71,
+	0xb4, 0x44, 0xa7, 0x21, 0x6b, 0x78, 0x07, 0x7d, 0x6f, 0x68, 0x87, 0x7e, 0x66, 0xe8, 0x12, 0x0f,
+	0xed, 0xea, 0x0d, 0x28, 0x85, 0xe7, 0x08, 0x6f, 0x79, 0x05, 0x90, 0xc6, 0x33, 0xb9, 0x6f, 0x10,
+	0x5f, 0xf7, 0x4c, 0x77, 0xdc, 0x7e, 0xf0, 0x82, 0xd8, 0x69, 0x8f, 0x37, 0xaa, 0xdf, 0xe5, 0x01,
+	0xdd, 0xf6, 0x34, 0xdb, 0x17, 0x5b, 0x91, 0x3b, 0x57, 0x21, 0xe5, 0xb8, 0x24, 0xf2, 0x21, 0x5e,
+	0xd3, 0x47, 0xe1, 0xb5, 0x2e, 0xc5, 0x62, 0xce, 0x60, 0x4c, 0xcb, 0xd1, 0x8c, 0x30, 0x96, 0x53,
+	0x99, 0x9b, 0x14, 0x8b, 0x39, 0x83, 0xe6, 0x67, 0xd6, 0xf5, 0x88, 0xab, 0x79, 0x84, 0xfb, 0x58,
+	0xa8, 0x5f, 0x9c, 0x46, 0xbe, 0x29, 0xe0, 0x38, 0xe2, 0xa1, 0xd7, 0x21, 0xed, 0x07, 0x0e, 0x15,
+	0x48, 0x71, 0x81, 0x0b, 0xd3, 0x04, 0x7a, 0x0c, 0x8c, 0x05, 0x07, 0xdd, 0x80, 0x0c, 0xed, 0xc9,
+	0xfb, 0x66, 0x50, 0x49, 0x73, 0xf6, 0xcb, 0xd3, 0xd8, 0x2d, 0x8e, 0xc6, 0x21, 0x0b, 0x6d, 0x41,
+	0x41, 0xd3, 0xef, 0xd9, 0xce, 0x03, 0x8b, 0x18, 0x03, 0x52, 0xc9, 0x70, 0x91, 0x4b, 0xd3, 0x44,
+	0x1a, 0x63, 0x0a, 0x96, 0xf9, 0xea, 0xef, 0xf4, 0x6d, 0xc1, 0xe2, 0xfa, 0x6f, 0xa7, 0xd6, 0xf3,
+	0x90, 0xa7, 0x45, 0xd0, 0xdf, 0x25, 0x03, 0x53, 0x24, 0x57, 0x16, 0xe7, 0xa8, 0xa1, 0xc9, 0xd6,
+	0x2c, 0xef, 0xd8, 0x26, 0xed, 0xf9, 0x3c, 0xa4, 0x59, 0x9c, 0xa1, 0x4b, 0x3a, 0x57, 0xd0, 0x36,
+	0x9c, 0x32, 0x3c, 0x93, 0x6a, 0xf4, 0xe9, 0xcc, 0xd4, 0xef, 0x89, 0x49, 0xc2, 0x7b, 0x25, 0x0b,
+	0x5e, 0x71, 0x4a, 0xbb, 0x2e, 0x0b, 0x6e, 0x6b, 0x44, 0x55, 0xbf, 0xa1, 0xde, 0xb2, 0x5c, 0x60,
+	0x07, 0x0d, 0xdb, 0x0d, 0xf7, 0xb2, 0x84, 0xa3, 0x25, 0xda, 0x80, 0x34, 0xbd, 0x63, 0x5b, 0xe3,
+	0x0e, 0x14, 0x9b, 0x97, 0xe9, 0x17, 0x56, 0x67, 0x9f, 0xa5, 0x0d, 0x46, 0xc5, 0x42, 0x01, 0xd5,
+	0xa1, 0xe0, 0xd2, 0x58, 0x13, 0xa3, 0x4f, 0xdd, 0xf1, 0xc3, 0xf6, 0x5e, 0x10, 0xe1, 0xec, 0x59,
+	0xa6, 0x4e, 0xa2, 0xc1, 0x20, 0x50, 0xef, 0x52, 0x90, 0x5a, 0x87, 0x6c, 0x98, 0x6f, 0xe8, 0x22,
+	0xcc, 0x33, 0xa8, 0xe4, 0x3a, 0x3f, 0x6b, 0x11, 0xcf, 0x31, 0xb3, 0xe4, 0xd5, 0xf7, 0x09, 0x48,
+	0xf3, 0x1c, 0xfb, 0xdf, 0xba, 0x85, 0xae, 0x40, 0x29, 0xe4, 0xf0, 0xee, 0x16, 0x8d, 0xf1, 0x09,
+	0xac, 0xa2, 0xc0, 0xdd, 0xe1, 0x30, 0x54, 0x83, 0x3c, 0x7d, 0x0b, 0xf9, 0xd1, 0x9d, 0x3f, 0x83,
+	0x93, 0x63, 0x18, 0x36, 0xc3, 0xd8, 0x13, 0x9a, 0x7c, 0x62, 0xfa, 0x74, 0x52, 0x64, 0x28, 0x98,
+	0x36, 0x30, 0xb1, 0x62, 0x76, 0xda, 0xb2, 0x49, 0x40, 0x2a, 0x59, 0x61, 0x17, 0x2b, 0x35, 0x07,
+	0x19, 0x51, 0x5f, 0x6a, 0x09, 0x0a, 0x52, 0x91, 0x54, 0x9f, 0xa6, 0xe0, 0x64, 0xac, 0x8e, 0xc2,
+	0xc6, 0xf7, 0x26, 0x64, 0x58, 0x03, 0x22, 0x46, 0x58, 0x1d, 0xc7, 0x74, 0x8f, 0x70, 0x1a, 0x75,
+	0x39, 0x1c, 0x87, 0x34, 0x26, 0xc0, 0xfa, 0x10, 0x89, 0x7a, 0xd7, 0x74, 0x81, 0x4d, 0x0e, 0xc7,
+	0x21, 0x8d, 0x86, 0x3f, 0x17, 0x36, 0x22, 0x23, 0xec, 0x60, 0xa7, 0x44, 0x44, 0xda, 0x87, 0xb2,
+	0x1d, 0x8f, 0x70, 0xe8, 0x43, 0x28, 0x47, 0x75, 0xc4, 0xbd, 0x0d, 0x88, 0x11, 0x36, 0xaf, 0xb5,
+	0xa9, 0x9f, 0x0f, 0x65, 0x23, 0x1e, 0x9e, 0x37, 0xe2, 0x06, 0x74, 0x0b, 0x8a, 0x52, 0x47, 0x31,
+	0xc2, 0xbe, 0xb6, 0x32, 0x55, 0x58, 0x0a, 0xb7, 0x81, 0x63, 0x12, 0xea, 0xab, 0x90, 0x11, 0x61,
+	0x9b, 0xbd, 0x08, 0xbe, 0x56, 0x20, 0x23, 0x22, 0xf5, 0xdf, 0x54, 0x41, 0x2c, 0x33, 0x93, 0x53,
+	0x33, 0x53, 0x5d, 0x80, 0xf9, 0x43, 0x91, 0x54, 0xe7, 0xe8, 0x74, 0x97, 0xbc, 0xae, 0x3f, 0x4d,
+	0x40, 0x46, 0x60, 0xe8, 0x88, 0x49, 0xf1, 0x37, 0x66, 0x25, 0x16, 0x45, 0xe9, 0x4f, 0x2f, 0xf5,
+	0xcc, 0x84, 0x9d, 0x30, 0x47, 0xdf, 0x86, 0x5c, 0xf4, 0x26, 0x42, 0x4b, 0xc7, 0xbd, 0x26, 0xd5,
+	0x17, 0x8e, 0x7d, 0x48, 0xa1, 0x36, 0xad, 0x09, 0x36, 0xf6, 0x77, 0x5c, 0x9f, 0x78, 0x01, 0x8a,
+	0x7f, 0x52, 0x7e, 0x98, 0xa8, 0xea, 0xa4, 0xad, 0x43, 0x2a, 0x6d, 0x5e, 0x72, 0x7f, 0x57, 0x65,
+	0x07, 0x8a, 0x52, 0x12, 0xf9, 0xe8, 0xdc, 0x94, 0x91, 0xa7, 0x9e, 0x9f, 0x96, 0x80, 0xcb, 0xca,
+	0x9a, 0xd2, 0x6c, 0x3e, 0xfa, 0xf9, 0xec, 0x89, 0x47, 0xbf, 0x9c, 0x55, 0x7e, 0xa0, 0xff, 0xbe,
+	0xfd, 0xf5, 0xac, 0xf2, 0xc1, 0xda, 0x4c, 0x09, 0x21, 0x69, 0xef, 0x66, 0xb8, 0xf9, 0xf2, 0x9f,
+	0xba, 0xa1, 0x29, 0xd7, 0x6e, 0x10, 0x00, 0x00,
 }
 
 // Reference imports to suppress errors if they are not otherwise used.
@@ -2729,6 +2733,19 @@ func (m *TransactionRequest_Store) MarshalToSizedBuffer(dAtA []byte) (int, error
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.Delete) > 0 {
+		for iNdEx := len(m.Delete) - 1; iNdEx >= 0; iNdEx-- {
+			i--
+			if m.Delete[iNdEx] {
+				dAtA[i] = 1
+			} else {
+				dAtA[i] = 0
+			}
+		}
+		i = encodeVarintMaterialize(dAtA, i, uint64(len(m.Delete)))
+		i--
+		dAtA[i] = 0x3a
+	}
 	if len(m.Exists) > 0 {
 		for iNdEx := len(m.Exists) - 1; iNdEx >= 0; iNdEx-- {
 			i--
@@ -3441,6 +3458,9 @@ func (m *TransactionRequest_Store) ProtoSize() (n int) {
 	if len(m.Exists) > 0 {
 		n += 1 + sovMaterialize(uint64(len(m.Exists))) + len(m.Exists)*1
 	}
+	if len(m.Delete) > 0 {
+		n += 1 + sovMaterialize(uint64(len(m.Delete))) + len(m.Delete)*1
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -5792,6 +5812,76 @@ func (m *TransactionRequest_Store) Unmarshal(dAtA []byte) error {
 			} else {
 				return fmt.Errorf("proto: wrong wireType = %d for field Exists", wireType)
 			}
+		case 7:
+			if wireType == 0 {
+				var v int
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowMaterialize
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					v |= int(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				m.Delete = append(m.Delete, bool(v != 0))
+			} else if wireType == 2 {
+				var packedLen int
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowMaterialize
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					packedLen |= int(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				if packedLen < 0 {
+					return ErrInvalidLengthMaterialize
+				}
+				postIndex := iNdEx + packedLen
+				if postIndex < 0 {
+					return ErrInvalidLengthMaterialize
+				}
+				if postIndex > l {
+					return io.ErrUnexpectedEOF
+				}
+				var elementCount int
+				elementCount = packedLen
+				if elementCount != 0 && len(m.Delete) == 0 {
+					m.Delete = make([]bool, 0, elementCount)
+				}
+				for iNdEx < postIndex {
+					var v int
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowMaterialize
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						v |= int(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					m.Delete = append(m.Delete, bool(v != 0))
+				}
+			} else {
+				return fmt.Errorf("proto: wrong wireType = %d for field Delete", wireType)
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipMaterialize(dAtA[iNdEx:])
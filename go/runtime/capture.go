@@ -116,6 +116,7 @@ func (c *Capture) RestoreCheckpoint(shard consumer.Shard) (cp pf.Checkpoint, err
 			binding.Collection.UuidPtr,
 			binding.Collection.KeyPtrs,
 			flow.PartitionPointers(&binding.Collection),
+			c.host.Config.Flow.AssertFormats,
 		)
 	}
 
@@ -304,7 +305,7 @@ func (c *Capture) ConsumeMessage(shard consumer.Shard, env message.Envelope, pub
 		var binding = c.spec.Bindings[b]
 		_ = binding.Collection // Elide nil check.
 
-		var stats, err = combiner.Drain(func(full bool, doc json.RawMessage, packedKey, packedPartitions []byte) error {
+		var stats, err = combiner.Drain(func(full, _ bool, doc json.RawMessage, packedKey, packedPartitions []byte) error {
 			if full {
 				panic("capture produces only partially combined documents")
 			}
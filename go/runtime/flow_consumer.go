@@ -27,11 +27,12 @@ import (
 type FlowConsumerConfig struct {
 	runconsumer.BaseConfig
 	Flow struct {
-		BuildsRoot string `long:"builds-root" required:"true" env:"BUILDS_ROOT" description:"Base URL for fetching Flow catalog builds"`
-		BrokerRoot string `long:"broker-root" required:"true" env:"BROKER_ROOT" default:"/gazette/cluster" description:"Broker Etcd base prefix"`
-		Network    string `long:"network" default:"host" description:"The Docker network that connector containers are given access to."`
-		TestAPIs   bool   `long:"test-apis" description:"Enable APIs exclusively used while running catalog tests"`
-		Poll       bool   `long:"poll" description:"Poll connectors, rather than running them continuously"`
+		BuildsRoot    string `long:"builds-root" required:"true" env:"BUILDS_ROOT" description:"Base URL for fetching Flow catalog builds"`
+		BrokerRoot    string `long:"broker-root" required:"true" env:"BROKER_ROOT" default:"/gazette/cluster" description:"Broker Etcd base prefix"`
+		Network       string `long:"network" default:"host" description:"The Docker network that connector containers are given access to."`
+		TestAPIs      bool   `long:"test-apis" description:"Enable APIs exclusively used while running catalog tests"`
+		Poll          bool   `long:"poll" description:"Poll connectors, rather than running them continuously"`
+		AssertFormats bool   `long:"assert-formats" env:"ASSERT_FORMATS" description:"Validate, rather than only annotate, known JSON schema formats of documents combined by captures and materializations"`
 	} `group:"flow" namespace:"flow" env-namespace:"FLOW"`
 }
 
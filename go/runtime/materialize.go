@@ -141,6 +141,7 @@ func (m *Materialize) RestoreCheckpoint(shard consumer.Shard) (cp pf.Checkpoint,
 			"", // Don't generate UUID placeholders.
 			binding.Collection.KeyPtrs,
 			binding.FieldValuePtrs(),
+			m.host.Config.Flow.AssertFormats,
 		)
 	}
 
@@ -135,6 +135,7 @@ func (f *FlowTesting) Ingest(ctx context.Context, req *pf.IngestRequest) (*pf.In
 		collection.UuidPtr,
 		collection.KeyPtrs,
 		flow.PartitionPointers(collection),
+		f.Config.Flow.AssertFormats,
 	); err != nil {
 		return nil, fmt.Errorf("configuring combiner: %w", err)
 	}
@@ -156,7 +157,7 @@ func (f *FlowTesting) Ingest(ctx context.Context, req *pf.IngestRequest) (*pf.In
 	// them as uncommitted messages.
 	var mapper = flow.NewMapper(ctx, f.Service.Etcd, f.Journals, f.Service.State.LocalKey)
 	// Ignore combine stats for testing
-	if _, err = combiner.Drain(func(full bool, doc json.RawMessage, packedKey, packedPartitions []byte) error {
+	if _, err = combiner.Drain(func(full, _ bool, doc json.RawMessage, packedKey, packedPartitions []byte) error {
 		if full {
 			panic("ingest produces only partially combined documents")
 		}
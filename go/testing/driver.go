@@ -396,7 +396,8 @@ func CombineDocuments(
 		collection.SchemaUri,
 		collection.UuidPtr,
 		collection.KeyPtrs,
-		nil, // Don't extract additional fields.
+		nil,   // Don't extract additional fields.
+		false, // Don't assert formats.
 	); err != nil {
 		return nil, fmt.Errorf("configuring combiner: %w", err)
 	}
@@ -414,7 +415,7 @@ func CombineDocuments(
 
 	// Drain actual documents from the combiner.
 	var actual []json.RawMessage
-	_, err = combiner.Drain(func(_ bool, doc json.RawMessage, _, _ []byte) error {
+	_, err = combiner.Drain(func(_, _ bool, doc json.RawMessage, _, _ []byte) error {
 		// Replace document UUID placeholder with a more friendly value.
 		doc = bytes.Replace(doc, pf.DocumentUUIDPlaceholder, []byte("flow-uuid"), -1)
 		actual = append(actual, doc)